@@ -0,0 +1,91 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+pub use merlo_model as model;
+pub use merlo_presentation as presentation;
+pub use merlo_simulation as simulation;
+
+use bevy::app::{PluginGroup, PluginGroupBuilder};
+use bevy_egui::EguiPlugin;
+use bevy_rapier3d::render::RapierDebugRenderPlugin;
+use clap::Parser;
+
+use crate::{
+    presentation::{PresentationPluginGroup, window::WindowArgs},
+    simulation::{
+        SimulationPlugin,
+        controller::CharacterControllerPlugin,
+        network::Cli,
+        physics::{PhysicsArgs, PhysicsPlugin},
+    },
+};
+
+/// What games built on merlo import, along with the bevy prelude.
+pub mod prelude {
+    pub use clap::Parser;
+    pub use merlo_model::*;
+
+    pub use crate::{
+        Args, MerloPlugins,
+        presentation::{PresentationPluginGroup, window::WindowArgs},
+        simulation::{
+            SimulationPlugin, WorldSpawner,
+            config::ConfigFile,
+            controller::CharacterControllerPlugin,
+            network::Cli,
+            physics::{PhysicsArgs, PhysicsPlugin},
+        },
+    };
+}
+
+/// The command line of the game, taken as it is by games without options of their own.
+/// Parsed with [`ConfigFile::parse_with`](simulation::config::ConfigFile::parse_with), it
+/// also reads the configuration file, which games insert as a resource before the
+/// `DefaultPlugins` it `configure`s.
+#[derive(Parser)]
+pub struct Args {
+    #[command(flatten)]
+    pub window: WindowArgs,
+
+    #[command(flatten)]
+    pub physics: PhysicsArgs,
+
+    #[command(subcommand)]
+    pub mode: Cli,
+}
+
+/// The simulation with its physics and character controllers, and the presentation,
+/// for games run in a window. Added after the `DefaultPlugins`, with the [`Cli`] inserted
+/// as a resource.
+pub struct MerloPlugins {
+    simulation: SimulationPlugin,
+    physics: PhysicsArgs,
+}
+
+impl MerloPlugins {
+    pub fn new(physics: PhysicsArgs) -> Self {
+        Self {
+            simulation: SimulationPlugin::default(),
+            physics,
+        }
+    }
+
+    /// Replaces the default [`SimulationPlugin`], such as one spawning the world of the game.
+    pub fn with_simulation(mut self, simulation: SimulationPlugin) -> Self {
+        self.simulation = simulation;
+        self
+    }
+}
+
+impl PluginGroup for MerloPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(self.simulation)
+            .add(PhysicsPlugin::new(self.physics))
+            .add(RapierDebugRenderPlugin::default().disabled())
+            .add(CharacterControllerPlugin)
+            .add(EguiPlugin::default())
+            .add_group(PresentationPluginGroup)
+    }
+}
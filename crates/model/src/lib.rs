@@ -1,7 +1,7 @@
 use bevy::{asset::uuid::Uuid, prelude::*};
 use serde::{Deserialize, Serialize};
 
-#[derive(Component, Serialize, Deserialize)]
+#[derive(Component, Serialize, Deserialize, Clone)]
 pub struct Player(u128);
 
 impl Default for Player {
@@ -12,5 +12,402 @@ impl Default for Player {
     }
 }
 
-#[derive(Component, Serialize, Deserialize)]
-pub struct Doodad;
+impl Player {
+    pub fn id(&self) -> PlayerId {
+        PlayerId(self.0)
+    }
+}
+
+/// Identifies a [`Player`] across sessions, unlike its entity.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PlayerId(pub u128);
+
+/// The model of a character, whose scene and animation clips are named after its prefix.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CharacterModel {
+    pub prefix: String,
+}
+
+impl Default for CharacterModel {
+    fn default() -> Self {
+        Self {
+            prefix: "character-large-male".into(),
+        }
+    }
+}
+
+impl CharacterModel {
+    pub fn scene_path(&self) -> String {
+        format!("{}.glb", self.prefix)
+    }
+
+    /// Path of the file holding the animation clip with the given name, such as `walk`.
+    pub fn clip_path(&self, name: &str) -> String {
+        format!("{}-{name}.glb", self.prefix)
+    }
+}
+
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Doodad {
+    #[default]
+    Cube,
+    Sphere,
+}
+
+/// A doodad held in front of a character, replicated so that everyone sees it held.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Carried {
+    #[entities]
+    pub by: Entity,
+}
+
+/// A replicated point light placed in the level.
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Lamp;
+
+/// Geometry of the level which never moves, such as the ground.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum StaticGeometry {
+    /// An upright cylinder centered on its transform.
+    Cylinder {
+        radius: f32,
+        height: f32,
+    },
+    Cuboid {
+        size: Vec3,
+    },
+    /// A glTF scene under the assets, colliding with the triangles of its meshes.
+    Scene(String),
+    /// Terrain centered on its transform, raised by the brightness of a heightmap image
+    /// under the assets, up to `size.y` where white.
+    Heightfield {
+        heightmap: String,
+        size: Vec3,
+    },
+}
+
+/// How a platform goes on once it reaches the last waypoint of its path.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Back and forth along the waypoints.
+    #[default]
+    PingPong,
+    /// From the last waypoint to the first one, and around again.
+    Loop,
+    /// Stops at the last waypoint.
+    Once,
+}
+
+/// Waypoints followed by [`StaticGeometry`] moved by the server, such as a moving platform
+/// or an elevator.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlatformPath {
+    /// Points the platform moves through, in world space, starting from the first one.
+    pub waypoints: Vec<Vec3>,
+    /// Meters per second.
+    pub speed: f32,
+    #[serde(default)]
+    pub loop_mode: LoopMode,
+}
+
+/// A box of the level reporting the characters of players entering and leaving it,
+/// such as a checkpoint, a hazard, or an area to capture.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TriggerZone {
+    /// Identifies the zone to the logic of the game.
+    pub name: String,
+    pub size: Vec3,
+}
+
+/// What a [`Pickup`] gives to the character collecting it.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PickupKind {
+    /// Restores some of the health of a damaged character.
+    #[default]
+    Health,
+}
+
+/// A place of the level where the server keeps a [`Pickup`], putting it back a while after
+/// it is collected.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct PickupSpawner {
+    pub kind: PickupKind,
+    /// Seconds from a collection to the next pickup.
+    #[serde(default = "PickupSpawner::default_respawn_delay")]
+    pub respawn_delay: f32,
+}
+
+impl PickupSpawner {
+    pub const DEFAULT_RESPAWN_DELAY: f32 = 15.0;
+
+    pub fn new(kind: PickupKind) -> Self {
+        Self {
+            kind,
+            respawn_delay: Self::DEFAULT_RESPAWN_DELAY,
+        }
+    }
+
+    fn default_respawn_delay() -> f32 {
+        Self::DEFAULT_RESPAWN_DELAY
+    }
+}
+
+/// An item waiting at a [`PickupSpawner`] for a character to walk into it.
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Pickup {
+    pub kind: PickupKind,
+}
+
+/// Marks a [`PickupSpawner`] whose pickup is about to come back, so that clients can
+/// announce it.
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PickupIncoming;
+
+/// A location where players can be (re)spawned.
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SpawnPoint;
+
+/// Describes the level being played, replicated so that all clients render it the same way.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Level {
+    pub name: String,
+    /// Name of the sky under `skies/`, used for the skybox and environment lighting.
+    pub sky: String,
+    /// Height below which characters die and doodads return to where they were placed.
+    pub kill_height: f32,
+}
+
+impl Level {
+    pub const DEFAULT_KILL_HEIGHT: f32 = -20.0;
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self {
+            name: "default".into(),
+            sky: "default".into(),
+            kill_height: Self::DEFAULT_KILL_HEIGHT,
+        }
+    }
+}
+
+/// Acceleration of gravity set by the server, replicated so that clients simulate falling
+/// bodies the same way.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Gravity(pub Vec3);
+
+/// Time of day advanced by the server and replicated alongside the [`Level`].
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfDay {
+    /// Fraction of the day in `[0, 1)`, where `0` is midnight and `0.5` is noon.
+    pub fraction: f32,
+    /// Length of a full day in seconds.
+    pub day_length: f32,
+}
+
+impl TimeOfDay {
+    pub fn new(day_length: f32) -> Self {
+        Self {
+            // Start in the morning.
+            fraction: 0.3,
+            day_length,
+        }
+    }
+
+    pub fn advance(&mut self, seconds: f32) {
+        if self.day_length > 0.0 {
+            self.fraction = (self.fraction + seconds / self.day_length).rem_euclid(1.0);
+        }
+    }
+
+    /// Angle of the sun above the horizon in radians: zero at sunrise, `PI / 2` at noon.
+    pub fn sun_angle(&self) -> f32 {
+        (self.fraction - 0.25) * std::f32::consts::TAU
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeatherKind {
+    #[default]
+    Clear,
+    Rain,
+    Fog,
+}
+
+/// Weather advanced by the server and replicated alongside the [`Level`].
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct Weather {
+    pub kind: WeatherKind,
+    /// How strong the weather is, from `0` while it comes or goes to `1`.
+    pub intensity: f32,
+}
+
+impl Weather {
+    pub fn new(kind: WeatherKind) -> Self {
+        Self {
+            kind,
+            intensity: 1.0,
+        }
+    }
+
+    /// Intensity of the weather if it is of the given kind, or zero.
+    pub fn intensity_of(&self, kind: WeatherKind) -> f32 {
+        if self.kind == kind {
+            self.intensity
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Team {
+    #[default]
+    None,
+    Red,
+    Blue,
+}
+
+/// A row of the scoreboard, one for each connected player.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScoreEntry {
+    pub name: String,
+    pub ping_ms: u32,
+    pub score: i32,
+    pub team: Team,
+}
+
+/// Rules of the match, chosen when starting the server.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameModeKind {
+    /// Free play, with neither scores nor rounds.
+    #[default]
+    Sandbox,
+    /// Whoever is [`It`] tags others by hitting them, the others score by staying away.
+    Tag,
+    /// Players score by killing each other.
+    Deathmatch,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundPhase {
+    /// Characters wait at their spawn points for the round to start.
+    #[default]
+    Warmup,
+    Active,
+    /// The round is over and the level is about to be reset for the next one.
+    PostRound,
+}
+
+/// Progress of the match, replicated so that clients can show the UI of its game mode.
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub mode: GameModeKind,
+    /// Rounds started so far, counting the current one.
+    pub round: u32,
+    pub phase: RoundPhase,
+    /// Whole seconds left in the phase, or `None` when it lasts until someone wins.
+    pub seconds_left: Option<u32>,
+    /// Name of the player who won the last round, if it was not a draw.
+    pub winner: Option<String>,
+}
+
+impl Match {
+    pub fn new(mode: GameModeKind) -> Self {
+        Self {
+            mode,
+            round: 1,
+            ..default()
+        }
+    }
+}
+
+/// Marks the character chasing the others in a game of tag.
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct It;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageKind {
+    #[default]
+    Physical,
+    Fire,
+    Fall,
+}
+
+/// A short gesture a player can perform, seen by every other player.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Emote {
+    Wave,
+    Cheer,
+    Point,
+}
+
+/// A point of a [`CameraPath`], where the camera passes looking at a target.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct CameraWaypoint {
+    pub position: Vec3,
+    pub look_at: Vec3,
+}
+
+impl CameraWaypoint {
+    pub fn new(position: Vec3, look_at: Vec3) -> Self {
+        Self { position, look_at }
+    }
+}
+
+/// An authored path the camera can fly along, e.g. for trailers and kill-cams.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CameraPath {
+    /// Smoothly interpolated, so the camera passes through every waypoint.
+    pub waypoints: Vec<CameraWaypoint>,
+    /// Seconds to fly from the first to the last waypoint.
+    pub duration: f32,
+    /// Easing of the progress along the whole path.
+    pub easing: EaseFunction,
+}
+
+impl CameraPath {
+    pub fn new(waypoints: Vec<CameraWaypoint>, duration: f32) -> Self {
+        Self {
+            waypoints,
+            duration,
+            easing: EaseFunction::SmoothStep,
+        }
+    }
+
+    pub fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Hit points of a damageable entity, replicated so clients can display them.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Health {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Health {
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Remaining health in `[0, 1]`.
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 {
+            (self.current / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.current < self.max
+    }
+}
+
+/// Marks a character whose health ran out, until the server respawns it.
+#[derive(Component, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Dead;
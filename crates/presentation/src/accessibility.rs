@@ -0,0 +1,114 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::{
+    EguiContextSettings, EguiContexts, EguiPrimaryContextPass, PrimaryEguiContext, egui,
+};
+use merlo_simulation::colors::ColorScheme;
+
+use crate::locale::Localization;
+
+#[derive(Default)]
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AccessibilitySettings>().add_systems(
+            EguiPrimaryContextPass,
+            apply_accessibility_settings.run_if(resource_changed::<AccessibilitySettings>),
+        );
+    }
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AccessibilitySettings {
+    /// Scale factor applied to the whole egui interface.
+    pub ui_scale: f32,
+    pub high_contrast: bool,
+    pub colorblind_safe: bool,
+    /// Disables camera motion effects such as head bob.
+    pub reduce_motion: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            ui_scale: 1.0,
+            high_contrast: false,
+            colorblind_safe: false,
+            reduce_motion: false,
+        }
+    }
+}
+
+fn high_contrast_visuals() -> egui::Visuals {
+    let mut visuals = egui::Visuals::dark();
+    visuals.override_text_color = Some(egui::Color32::WHITE);
+    visuals.panel_fill = egui::Color32::BLACK;
+    visuals.window_fill = egui::Color32::BLACK;
+    visuals.extreme_bg_color = egui::Color32::BLACK;
+    visuals.selection.bg_fill = egui::Color32::from_rgb(0, 90, 200);
+    visuals.selection.stroke = egui::Stroke::new(2.0, egui::Color32::WHITE);
+    for widget in [
+        &mut visuals.widgets.noninteractive,
+        &mut visuals.widgets.inactive,
+        &mut visuals.widgets.hovered,
+        &mut visuals.widgets.active,
+    ] {
+        widget.bg_stroke = egui::Stroke::new(1.5, egui::Color32::WHITE);
+        widget.fg_stroke.color = egui::Color32::WHITE;
+    }
+    visuals.widgets.hovered.bg_fill = egui::Color32::from_gray(60);
+    visuals
+}
+
+fn apply_accessibility_settings(
+    settings: Res<AccessibilitySettings>,
+    mut contexts: EguiContexts,
+    mut egui_settings: Query<&mut EguiContextSettings, With<PrimaryEguiContext>>,
+    mut scheme: ResMut<ColorScheme>,
+) -> Result<()> {
+    for mut egui_settings in &mut egui_settings {
+        egui_settings.scale_factor = settings.ui_scale;
+    }
+
+    let visuals = if settings.high_contrast {
+        high_contrast_visuals()
+    } else {
+        egui::Visuals::dark()
+    };
+    contexts.ctx_mut()?.set_visuals(visuals);
+
+    let next = if settings.colorblind_safe {
+        ColorScheme::COLORBLIND_SAFE
+    } else {
+        ColorScheme::STANDARD
+    };
+    scheme.set_if_neq(next);
+
+    Ok(())
+}
+
+/// Shows the accessibility settings.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let labels = [
+        "accessibility.ui_scale",
+        "accessibility.high_contrast",
+        "accessibility.colorblind",
+        "accessibility.reduce_motion",
+    ]
+    .map(|key| localization.tr(key).to_owned());
+
+    let mut settings = *world.resource::<AccessibilitySettings>();
+    ui.add(egui::Slider::new(&mut settings.ui_scale, 0.5..=2.0).text(&labels[0]));
+    ui.checkbox(&mut settings.high_contrast, &labels[1]);
+    ui.checkbox(&mut settings.colorblind_safe, &labels[2]);
+    ui.checkbox(&mut settings.reduce_motion, &labels[3]);
+
+    world
+        .resource_mut::<AccessibilitySettings>()
+        .set_if_neq(settings);
+}
@@ -0,0 +1,153 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use bevy_replicon::{prelude::*, shared::backend::connected_client::NetworkId};
+use merlo_model::ScoreEntry;
+use merlo_simulation::{
+    admin::{AdminCommand, ControlledCharacter, Muted},
+    controller::CharacterController,
+    scoreboard::ScoreOwner,
+};
+
+use crate::locale::Localization;
+
+/// A connected client as listed in the admin tab.
+struct ClientRow {
+    client: Entity,
+    network_id: Option<NetworkId>,
+    name: String,
+    ping_ms: u32,
+    character: Option<Entity>,
+    muted: bool,
+}
+
+fn client_rows(world: &mut World) -> Vec<ClientRow> {
+    let names: Vec<_> = world
+        .query::<(&ScoreOwner, &ScoreEntry)>()
+        .iter(world)
+        .filter_map(|(owner, entry)| match owner.0 {
+            ClientId::Client(client) => Some((client, entry.name.clone())),
+            ClientId::Server => None,
+        })
+        .collect();
+
+    let mut rows: Vec<_> = world
+        .query_filtered::<(
+            Entity,
+            Option<&NetworkId>,
+            Option<&ClientStats>,
+            Option<&ControlledCharacter>,
+            Has<Muted>,
+        ), With<ConnectedClient>>()
+        .iter(world)
+        .map(|(client, network_id, stats, character, muted)| ClientRow {
+            client,
+            network_id: network_id.copied(),
+            name: names
+                .iter()
+                .find_map(|(owner, name)| (*owner == client).then(|| name.clone()))
+                .unwrap_or_default(),
+            ping_ms: stats.map_or(0, |stats| (stats.rtt * 1000.0) as u32),
+            character: character.map(|character| character.0),
+            muted,
+        })
+        .collect();
+    rows.sort_by_key(|row| row.network_id);
+    rows
+}
+
+/// Lists the connected clients with moderation buttons, only available while hosting.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let [
+        not_hosting,
+        no_clients,
+        id,
+        name,
+        ping,
+        entity,
+        kick,
+        mute,
+        unmute,
+        teleport,
+    ] = [
+        "admin.not_hosting",
+        "admin.no_clients",
+        "admin.id",
+        "admin.name",
+        "admin.ping",
+        "admin.entity",
+        "admin.kick",
+        "admin.mute",
+        "admin.unmute",
+        "admin.teleport",
+    ]
+    .map(|key| localization.tr(key).to_owned());
+
+    if **world.resource::<State<ServerState>>() != ServerState::Running {
+        ui.label(not_hosting);
+        return;
+    }
+
+    let rows = client_rows(world);
+    if rows.is_empty() {
+        ui.label(no_clients);
+        return;
+    }
+
+    let host_character = world
+        .query_filtered::<&ChildOf, With<CharacterController>>()
+        .single(world)
+        .ok()
+        .map(ChildOf::parent);
+
+    let mut admin_commands = Vec::new();
+    egui::Grid::new("admin_grid").striped(true).show(ui, |ui| {
+        ui.strong(&id);
+        ui.strong(&name);
+        ui.strong(&ping);
+        ui.strong(&entity);
+        ui.end_row();
+
+        for row in &rows {
+            let network_id = row
+                .network_id
+                .map_or_else(|| "-".to_owned(), |id| id.get().to_string());
+            ui.label(network_id);
+            ui.label(&row.name);
+            ui.label(format!("{} ms", row.ping_ms));
+            let character = row
+                .character
+                .map_or_else(|| "-".to_owned(), |character| character.to_string());
+            ui.label(character);
+
+            if ui.button(&kick).clicked() {
+                admin_commands.push(AdminCommand::Kick { client: row.client });
+            }
+            let mute_label = if row.muted { &unmute } else { &mute };
+            if ui.button(mute_label).clicked() {
+                admin_commands.push(AdminCommand::Mute {
+                    client: row.client,
+                    muted: !row.muted,
+                });
+            }
+            let can_teleport = row.character.is_some() && host_character.is_some();
+            if ui
+                .add_enabled(can_teleport, egui::Button::new(&teleport))
+                .clicked()
+                && let Some(character) = host_character
+            {
+                admin_commands.push(AdminCommand::TeleportTo {
+                    client: row.client,
+                    character,
+                });
+            }
+            ui.end_row();
+        }
+    });
+
+    world.write_message_batch(admin_commands);
+}
@@ -2,150 +2,945 @@
 // Author: Antonio Caggiano <info@antoniocaggiano.eu>
 // SPDX-License-Identifier: MIT
 
-use std::time::Duration;
+use bevy::{
+    animation::{ActiveAnimation, AnimationTarget},
+    app::AnimationSystems,
+    platform::collections::HashMap,
+    prelude::*,
+};
+use bevy_rapier3d::prelude::Velocity;
+use merlo_model::{CharacterModel, Dead, Emote};
+use serde::Deserialize;
 
-use bevy::prelude::*;
-
-use crate::simulation::controller::CharacterMovementState;
+use crate::{
+    animation_set::{AnimationSet, AnimationSetLoader, FootIkBones, LeanSettings, LookAtSettings},
+    animation_state::{AnimationStateMachine, TransitionContext},
+    simulation::{
+        attack::AttackStarted, controller::CharacterMovementState, emote::EmotePlayed,
+        manifest::AssetManifest,
+    },
+};
 
 #[derive(Default)]
 pub struct CharacterAnimationPlugin;
 
 impl Plugin for CharacterAnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup)
-            .add_systems(Update, (play_animation_when_ready, update_animation));
+        app.init_asset::<AnimationSet>()
+            .init_asset_loader::<AnimationSetLoader>()
+            .init_resource::<AnimationSets>()
+            .add_message::<PlayUpperBodyAction>()
+            .add_message::<ClipMarkerCrossed>()
+            .add_message::<AnimationMarkerReached>()
+            .add_systems(
+                Update,
+                (
+                    add_clip_markers,
+                    reload_animation_sets,
+                    rebuild_reloaded_graphs,
+                    play_animation_when_ready,
+                    assign_mask_groups,
+                    play_emotes.before(update_animation),
+                    play_attacks.before(update_animation),
+                    update_animation,
+                ),
+            )
+            .add_systems(PostUpdate, reach_animation_markers.after(AnimationSystems));
+        // Games may have inserted their own machine already.
+        if !app
+            .world()
+            .contains_resource::<AnimationStateMachine<CharacterAnimation>>()
+        {
+            app.insert_resource(AnimationStateMachine::character());
+        }
     }
 }
 
-// A component that stores a reference to an animation we want to play. This is
-// created when we start loading the mesh (see `setup_mesh_and_animation`) and
-// read when the mesh has spawned (see `play_animation_once_loaded`).
-#[derive(Resource)]
+/// Time to fade from one animation state to another, unless set otherwise by the
+/// [`AnimationSet`] of the character model.
+const TRANSITION_TIME: f32 = 0.25;
+/// How quickly the blend space follows changes of speed.
+const SPEED_SMOOTHING: f32 = 10.0;
+
+/// Range of the playback rate of the clips moving the character, so that its legs keep
+/// moving slowly against a wall and do not spin when flung around.
+const MIN_PLAYBACK_RATE: f32 = 0.25;
+const MAX_PLAYBACK_RATE: f32 = 2.0;
+
+/// Speeds at which the idle, walk and run clips play on their own in the locomotion
+/// blend space, matching the speeds set by the character controller.
+const IDLE_SPEED: f32 = 0.0;
+const WALK_SPEED: f32 = 0.05;
+const RUN_SPEED: f32 = 0.15;
+
+/// Mask group of the bones which keep playing locomotion during an upper body action.
+pub const LOWER_BODY_GROUP: u32 = 0;
+/// Mask group of the bones playing upper body actions.
+pub const UPPER_BODY_GROUP: u32 = 1;
+/// Bones which, along with all of their descendants, belong to [`UPPER_BODY_GROUP`].
+/// Every other bone of a character belongs to [`LOWER_BODY_GROUP`].
+pub const UPPER_BODY_BONES: [&str; 4] = ["torso", "head", "arm-left", "arm-right"];
+
+/// Minimum weight of a clip for its markers to be reached, so that clips fading in or out
+/// of a blend do not duplicate the markers of the dominant one.
+const MARKER_WEIGHT: f32 = 0.5;
+
+/// Markers authored on the clips, at a time relative to their duration.
+const CLIP_MARKERS: [(CharacterClip, &[(f32, AnimationMarker)]); 4] = [
+    (
+        CharacterClip::Walk,
+        &[
+            (0.0, AnimationMarker::FootstepLeft),
+            (0.5, AnimationMarker::FootstepRight),
+        ],
+    ),
+    (
+        CharacterClip::Run,
+        &[
+            (0.0, AnimationMarker::FootstepLeft),
+            (0.5, AnimationMarker::FootstepRight),
+        ],
+    ),
+    (
+        CharacterClip::WalkBack,
+        &[
+            (0.0, AnimationMarker::FootstepLeft),
+            (0.5, AnimationMarker::FootstepRight),
+        ],
+    ),
+    (CharacterClip::Land, &[(0.0, AnimationMarker::Land)]),
+];
+
+/// A point in time of a clip that other systems can sync to, such as a foot contact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimationMarker {
+    FootstepLeft,
+    FootstepRight,
+    Land,
+}
+
+impl AnimationMarker {
+    pub fn is_footstep(self) -> bool {
+        matches!(
+            self,
+            AnimationMarker::FootstepLeft | AnimationMarker::FootstepRight
+        )
+    }
+}
+
+/// A [`Message`] written when the animation of a character crosses a marker.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct AnimationMarkerReached {
+    pub character: Entity,
+    pub marker: AnimationMarker,
+}
+
+/// A marker crossed by one node of the graph, which may hold any weight in the blend.
+#[derive(Message, Debug, Clone, Copy)]
+struct ClipMarkerCrossed {
+    player: Entity,
+    clip: CharacterClip,
+    marker: AnimationMarker,
+    weight: f32,
+}
+
+/// The animation graph of a character model, and where its clips are in the graph.
+/// Added to every animation player of the model, sharing the same graph.
+#[derive(Component, Clone)]
 pub struct Animations {
+    set: AssetId<AnimationSet>,
+    root_bone: String,
+    foot_ik: Option<FootIkBones>,
+    look_at: Option<LookAtSettings>,
+    lean: Option<LeanSettings>,
+    /// Seconds to fade into each state, indexed by [`CharacterAnimation`].
+    transitions: [f32; 6],
+    /// Seconds to fade from one state into another, by pair of states.
+    blends: HashMap<(CharacterAnimation, CharacterAnimation), f32>,
+    /// Authored ground speed of every clip, indexed by [`CharacterClip`], zero for clips
+    /// which do not move the character.
+    ground_speeds: [f32; 8],
     graph_handle: Handle<AnimationGraph>,
+    /// Clips indexed by [`CharacterClip`].
+    clips: Vec<Handle<AnimationClip>>,
+    /// Clips indexed by [`CharacterAction`].
+    action_clips: Vec<Handle<AnimationClip>>,
+    /// Full body clips, indexed by [`CharacterClip`].
     indices: Vec<AnimationNodeIndex>,
+    /// The same clips masked to the lower body, used while an action is playing.
+    lower_body_indices: Vec<AnimationNodeIndex>,
+    /// Upper body clips, indexed by [`CharacterAction`].
+    action_indices: Vec<AnimationNodeIndex>,
 }
 
-#[derive(Component)]
-struct CurrentAnimation(CharacterAnimation);
+impl Animations {
+    /// Loads the clips of a character model listed by its set, and builds its graph.
+    fn load(
+        model: &CharacterModel,
+        set_id: AssetId<AnimationSet>,
+        set: &AnimationSet,
+        asset_server: &AssetServer,
+        graphs: &mut Assets<AnimationGraph>,
+    ) -> Self {
+        let load_clip =
+            |path: String| asset_server.load(GltfAssetLabel::Animation(0).from_asset(path));
+        let body_clips = CharacterClip::ALL.map(|clip| load_clip(set.clip_path(model, clip)));
+        let action_clips =
+            CharacterAction::ALL.map(|action| load_clip(set.action_path(model, action)));
+        let transitions = CharacterAnimation::ALL.map(|state| {
+            set.transitions
+                .get(&state)
+                .copied()
+                .unwrap_or(TRANSITION_TIME)
+        });
+
+        let mut animations = Self {
+            set: set_id,
+            root_bone: set.root_bone.clone(),
+            foot_ik: set.foot_ik.clone(),
+            look_at: set.look_at.clone(),
+            lean: set.lean.clone(),
+            transitions,
+            blends: set.blends.clone(),
+            ground_speeds: CharacterClip::ALL
+                .map(|clip| set.ground_speeds.get(&clip).copied().unwrap_or_default()),
+            graph_handle: Handle::default(),
+            clips: body_clips.to_vec(),
+            action_clips: action_clips.to_vec(),
+            indices: Vec::new(),
+            lower_body_indices: Vec::new(),
+            action_indices: Vec::new(),
+        };
+        animations.build_graph(graphs);
+        animations
+    }
+
+    /// Builds a new graph of the clips, keeping the mask groups of the previous one.
+    /// Nodes are always added in the same order, so their indices do not change.
+    fn build_graph(&mut self, graphs: &mut Assets<AnimationGraph>) {
+        let mut graph = AnimationGraph::new();
+        let full_body = graph.add_blend(1.0, graph.root);
+        self.indices = add_body_clips(&mut graph, &self.clips, full_body);
+        // While an action is playing, the body clips move the lower body only.
+        let lower_body = graph.add_blend_with_mask(1 << UPPER_BODY_GROUP, 1.0, graph.root);
+        self.lower_body_indices = add_body_clips(&mut graph, &self.clips, lower_body);
+        let upper_body = graph.add_blend_with_mask(1 << LOWER_BODY_GROUP, 1.0, graph.root);
+        self.action_indices = graph
+            .add_clips(self.action_clips.iter().cloned(), 1.0, upper_body)
+            .collect();
+
+        if let Some(previous) = graphs.get(&self.graph_handle) {
+            graph.mask_groups = previous.mask_groups.clone();
+        }
+        self.graph_handle = graphs.add(graph);
+    }
+
+    /// Whether any clip of the graph is `id`.
+    fn has_clip(&self, id: AssetId<AnimationClip>) -> bool {
+        self.clips
+            .iter()
+            .chain(&self.action_clips)
+            .any(|clip| clip.id() == id)
+    }
+
+    /// Graph blending the clips of the model.
+    pub fn graph_handle(&self) -> &Handle<AnimationGraph> {
+        &self.graph_handle
+    }
+
+    /// Name of the bone carrying the root motion of the clips.
+    pub fn root_bone(&self) -> &str {
+        &self.root_bone
+    }
+
+    /// Bones adjusted by foot IK, if enabled for the model.
+    pub fn foot_ik(&self) -> Option<&FootIkBones> {
+        self.foot_ik.as_ref()
+    }
+
+    /// How the head and chest turn toward a target, if enabled for the model.
+    pub fn look_at(&self) -> Option<&LookAtSettings> {
+        self.look_at.as_ref()
+    }
+
+    /// How the body leans into turns, if enabled for the model.
+    pub fn lean(&self) -> Option<&LeanSettings> {
+        self.lean.as_ref()
+    }
+
+    /// Seconds to fade from `from` into `to`: the time of the set for this pair of
+    /// states, otherwise the time of the `transition` taken, otherwise the time of `to`.
+    fn transition_time(
+        &self,
+        from: CharacterAnimation,
+        to: CharacterAnimation,
+        transition: Option<f32>,
+    ) -> f32 {
+        self.blends
+            .get(&(from, to))
+            .copied()
+            .or(transition)
+            .unwrap_or(self.transitions[to as usize])
+    }
+
+    /// Both the full body and the lower body nodes of a clip.
+    fn clip_nodes(&self, clip: CharacterClip) -> [AnimationNodeIndex; 2] {
+        [
+            self.indices[clip as usize],
+            self.lower_body_indices[clip as usize],
+        ]
+    }
+}
+
+/// An action played by the upper body of a character, while its lower body keeps
+/// playing locomotion.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharacterAction {
+    Wave,
+    Cheer,
+    Point,
+    Attack,
+    Carry,
+}
+
+impl CharacterAction {
+    pub const ALL: [CharacterAction; 5] = [
+        CharacterAction::Wave,
+        CharacterAction::Cheer,
+        CharacterAction::Point,
+        CharacterAction::Attack,
+        CharacterAction::Carry,
+    ];
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum CharacterAnimation {
+    /// Carrying lasts until stopped, the other actions end with their clip.
+    fn repeats(self) -> bool {
+        self == CharacterAction::Carry
+    }
+
+    /// Name of the clip in the files named after the prefix of a character model.
+    pub fn clip_name(self) -> &'static str {
+        match self {
+            CharacterAction::Wave => "wave",
+            CharacterAction::Cheer => "cheer",
+            CharacterAction::Point => "point",
+            CharacterAction::Attack => "attack",
+            CharacterAction::Carry => "carry",
+        }
+    }
+}
+
+impl From<Emote> for CharacterAction {
+    fn from(emote: Emote) -> Self {
+        match emote {
+            Emote::Wave => CharacterAction::Wave,
+            Emote::Cheer => CharacterAction::Cheer,
+            Emote::Point => CharacterAction::Point,
+        }
+    }
+}
+
+/// A [`Message`] starting an upper body action on a character, or stopping the current
+/// one when `action` is `None`.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PlayUpperBodyAction {
+    pub character: Entity,
+    pub action: Option<CharacterAction>,
+}
+
+/// Animation state of a character, fading from one to another.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharacterAnimation {
+    /// Blends idle, walk and run according to the speed.
+    Locomotion,
+    WalkBack,
+    /// Played once when leaving the ground upward.
+    Jump,
+    /// Looping while airborne and not going up.
+    Fall,
+    /// Played once when touching the ground again.
+    Land,
+    /// Played once when dying, holding its last frame until respawning.
+    Death,
+}
+
+impl CharacterAnimation {
+    const ALL: [CharacterAnimation; 6] = [
+        CharacterAnimation::Locomotion,
+        CharacterAnimation::WalkBack,
+        CharacterAnimation::Jump,
+        CharacterAnimation::Fall,
+        CharacterAnimation::Land,
+        CharacterAnimation::Death,
+    ];
+}
+
+/// Clips of the animation graph, in the order they are added to it.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharacterClip {
     Idle,
     Walk,
-    WalkBack,
     Run,
+    WalkBack,
+    Jump,
     Fall,
+    Land,
+    Death,
+}
+
+impl CharacterClip {
+    pub const ALL: [CharacterClip; 8] = [
+        CharacterClip::Idle,
+        CharacterClip::Walk,
+        CharacterClip::Run,
+        CharacterClip::WalkBack,
+        CharacterClip::Jump,
+        CharacterClip::Fall,
+        CharacterClip::Land,
+        CharacterClip::Death,
+    ];
+
+    /// Name of the clip in the files named after the prefix of a character model.
+    pub fn clip_name(self) -> &'static str {
+        match self {
+            CharacterClip::Idle => "idle",
+            CharacterClip::Walk => "walk",
+            CharacterClip::Run => "run",
+            CharacterClip::WalkBack => "walk-back",
+            CharacterClip::Jump => "jump",
+            CharacterClip::Fall => "fall",
+            CharacterClip::Land => "land",
+            CharacterClip::Death => "death",
+        }
+    }
+
+    /// Whether the clip loops, or plays once from the start when its state is entered.
+    fn repeats(self) -> bool {
+        !matches!(
+            self,
+            CharacterClip::Jump | CharacterClip::Land | CharacterClip::Death
+        )
+    }
+}
+
+/// Per character blending state, driving the weights of every clip of the graph.
+#[derive(Component)]
+struct CurrentAnimation {
+    state: CharacterAnimation,
+    /// Weight of every state, indexed by [`CharacterAnimation`].
+    weights: [f32; 6],
+    /// Parameter of the locomotion blend space.
+    speed: f32,
+    /// Seconds since the current state was entered.
+    time_in_state: f32,
+    /// Seconds to fade into the current state.
+    blend_time: f32,
+    /// Current upper body action, kept while fading out after it stops.
+    action: Option<CharacterAction>,
+    action_playing: bool,
+    /// Blend from the full body clips to the lower body clips and the action.
+    action_weight: f32,
+}
+
+impl Default for CurrentAnimation {
+    fn default() -> Self {
+        Self {
+            state: CharacterAnimation::Locomotion,
+            weights: [1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            speed: IDLE_SPEED,
+            time_in_state: 0.0,
+            blend_time: TRANSITION_TIME,
+            action: None,
+            action_playing: false,
+            action_weight: 0.0,
+        }
+    }
+}
+
+impl CurrentAnimation {
+    /// Weights of idle, walk and run, interpolating linearly between their speeds.
+    fn locomotion_weights(&self) -> [f32; 3] {
+        let speed = self.speed.clamp(IDLE_SPEED, RUN_SPEED);
+        if speed < WALK_SPEED {
+            let t = (speed - IDLE_SPEED) / (WALK_SPEED - IDLE_SPEED);
+            [1.0 - t, t, 0.0]
+        } else {
+            let t = (speed - WALK_SPEED) / (RUN_SPEED - WALK_SPEED);
+            [0.0, 1.0 - t, t]
+        }
+    }
+
+    /// Weights of the full body and lower body nodes of a clip.
+    fn clip_weights(&self, clip: CharacterClip) -> [f32; 2] {
+        let weight = self.clip_weight(clip);
+        [
+            weight * (1.0 - self.action_weight),
+            weight * self.action_weight,
+        ]
+    }
+
+    fn action_clip_weight(&self, action: CharacterAction) -> f32 {
+        if self.action == Some(action) {
+            self.action_weight
+        } else {
+            0.0
+        }
+    }
+
+    fn clip_weight(&self, clip: CharacterClip) -> f32 {
+        let [idle, walk, run] = self.locomotion_weights();
+        let locomotion = self.weights[CharacterAnimation::Locomotion as usize];
+        match clip {
+            CharacterClip::Idle => locomotion * idle,
+            CharacterClip::Walk => locomotion * walk,
+            CharacterClip::Run => locomotion * run,
+            CharacterClip::WalkBack => self.weights[CharacterAnimation::WalkBack as usize],
+            CharacterClip::Jump => self.weights[CharacterAnimation::Jump as usize],
+            CharacterClip::Fall => self.weights[CharacterAnimation::Fall as usize],
+            CharacterClip::Land => self.weights[CharacterAnimation::Land as usize],
+            CharacterClip::Death => self.weights[CharacterAnimation::Death as usize],
+        }
+    }
+}
+
+/// Animations of every character model loaded so far, by prefix, so that characters of
+/// the same model share their graph.
+#[derive(Resource, Default)]
+struct AnimationSets(HashMap<String, ModelAnimations>);
+
+struct ModelAnimations {
+    set: Handle<AnimationSet>,
+    /// Built once the set has loaded, or failed to load.
+    animations: Option<Animations>,
+}
+
+/// Adds the clips indexed by [`CharacterClip`] under `parent`, where idle, walk and run
+/// share a 1D blend node, weighted per character by its speed.
+fn add_body_clips(
+    graph: &mut AnimationGraph,
+    clips: &[Handle<AnimationClip>],
+    parent: AnimationNodeIndex,
+) -> Vec<AnimationNodeIndex> {
+    let locomotion = graph.add_blend(1.0, parent);
+    let mut indices: Vec<_> = graph
+        .add_clips(clips[..3].iter().cloned(), 1.0, locomotion)
+        .collect();
+    indices.extend(graph.add_clips(clips[3..].iter().cloned(), 1.0, parent));
+    indices
 }
 
-fn setup(
+/// Authors the markers of a clip once it has loaded, including after being reloaded, as
+/// they depend on its duration.
+fn add_clip_markers(
+    mut asset_events: MessageReader<AssetEvent<AnimationClip>>,
+    sets: Res<AnimationSets>,
+    mut clips: ResMut<Assets<AnimationClip>>,
+) {
+    for event in asset_events.read() {
+        let AssetEvent::LoadedWithDependencies { id } = *event else {
+            continue;
+        };
+        for (clip, markers) in CLIP_MARKERS {
+            let is_clip = |model: &ModelAnimations| {
+                model
+                    .animations
+                    .as_ref()
+                    .is_some_and(|animations| animations.clips[clip as usize].id() == id)
+            };
+            if !sets.0.values().any(is_clip) {
+                continue;
+            }
+            // Without notifying a change, which would rebuild the graph of the model.
+            let Some(animation_clip) = clips.get_mut_untracked(id) else {
+                continue;
+            };
+            let duration = animation_clip.duration();
+            for &(time, marker) in markers {
+                animation_clip.add_event_fn(
+                    time * duration,
+                    move |commands: &mut Commands, player: Entity, _time: f32, weight: f32| {
+                        commands.write_message(ClipMarkerCrossed {
+                            player,
+                            clip,
+                            marker,
+                            weight,
+                        });
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Rebuilds the animations of a model when its set changes on disk, and restarts the
+/// animation players using them.
+fn reload_animation_sets(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    mut asset_events: MessageReader<AssetEvent<AnimationSet>>,
+    mut sets: ResMut<AnimationSets>,
+    mut players: Query<(Entity, &mut AnimationPlayer, &Animations)>,
+) {
+    for event in asset_events.read() {
+        let AssetEvent::Modified { id } = *event else {
+            continue;
+        };
+        for model in sets.0.values_mut() {
+            if model.set.id() == id {
+                model.animations = None;
+            }
+        }
+        for (entity, mut player, animations) in &mut players {
+            if animations.set == id {
+                player.stop_all();
+                commands
+                    .entity(entity)
+                    .remove::<(Animations, CurrentAnimation)>();
+            }
+        }
+    }
+}
+
+/// Rebuilds the graph of a model when some of its clips change on disk, such as when
+/// they are exported again, and hands it to the players of the model. They keep their
+/// state and the time of every clip, so that animators can iterate while playing.
+fn rebuild_reloaded_graphs(
+    mut commands: Commands,
+    mut asset_events: MessageReader<AssetEvent<AnimationClip>>,
+    mut sets: ResMut<AnimationSets>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
+    players: Query<(Entity, &Animations)>,
 ) {
-    let character_prefix = "character-large-male";
-    let running_animation = asset_server
-        .load(GltfAssetLabel::Animation(0).from_asset(format!("{character_prefix}-run.glb")));
-    let idle_animation = asset_server
-        .load(GltfAssetLabel::Animation(0).from_asset(format!("{character_prefix}-idle.glb")));
-    let walk_animation = asset_server
-        .load(GltfAssetLabel::Animation(0).from_asset(format!("{character_prefix}-walk.glb")));
-    let walk_back_animation = asset_server
-        .load(GltfAssetLabel::Animation(0).from_asset(format!("{character_prefix}-walk-back.glb")));
-    let fall_animation = asset_server
-        .load(GltfAssetLabel::Animation(0).from_asset(format!("{character_prefix}-fall.glb")));
-
-    let (graph, indices) = AnimationGraph::from_clips([
-        idle_animation,
-        walk_animation,
-        walk_back_animation,
-        running_animation,
-        fall_animation,
-    ]);
-    let graph_handle = graphs.add(graph);
-    let animations = Animations {
-        graph_handle,
-        indices,
-    };
-    commands.insert_resource(animations);
+    let modified: Vec<_> = asset_events
+        .read()
+        .filter_map(|event| match *event {
+            AssetEvent::Modified { id } => Some(id),
+            _ => None,
+        })
+        .collect();
+    if modified.is_empty() {
+        return;
+    }
+    for model in sets.0.values_mut() {
+        let Some(animations) = &mut model.animations else {
+            continue;
+        };
+        if !modified.iter().any(|&id| animations.has_clip(id)) {
+            continue;
+        }
+        let previous = animations.graph_handle.clone();
+        animations.build_graph(&mut graphs);
+        for (entity, player_animations) in &players {
+            if player_animations.graph_handle == previous {
+                commands.entity(entity).insert((
+                    AnimationGraphHandle(animations.graph_handle.clone()),
+                    animations.clone(),
+                ));
+            }
+        }
+    }
 }
 
+/// Starts every clip of the model of the character at once, as they are all blended
+/// together by their weights, once its animation set has loaded. Clips which do not
+/// repeat are replayed whenever their state is entered.
+#[allow(clippy::too_many_arguments)]
 fn play_animation_when_ready(
     mut commands: Commands,
-    animations: Res<Animations>,
-    mut players: Query<(Entity, &mut AnimationPlayer), Added<AnimationPlayer>>,
+    asset_server: Res<AssetServer>,
+    animation_sets: Res<Assets<AnimationSet>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+    mut sets: ResMut<AnimationSets>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    manifest: Res<AssetManifest>,
+    models: Query<&CharacterModel>,
+    parents: Query<&ChildOf>,
+    mut players: Query<(Entity, &mut AnimationPlayer), Without<Animations>>,
 ) {
     for (entity, mut player) in &mut players {
-        let mut transitions = AnimationTransitions::new();
+        let model = manifest.character_model(
+            find_character(entity, &parents, &characters)
+                .and_then(|(character, _, _)| models.get(character).ok()),
+        );
+        let model_animations =
+            sets.0
+                .entry(model.prefix.clone())
+                .or_insert_with(|| ModelAnimations {
+                    set: asset_server.load(AnimationSet::path(&model)),
+                    animations: None,
+                });
 
-        // Make sure to start the animation via the `AnimationTransitions`
-        // component. The `AnimationTransitions` component wants to manage all
-        // the animations and will get confused if the animations are started
-        // directly via the `AnimationPlayer`.
-        transitions
-            .play(&mut player, animations.indices[0], Duration::ZERO)
-            .repeat();
+        if model_animations.animations.is_none() {
+            let set_id = model_animations.set.id();
+            // Without a set file, the clips are found by their conventional names.
+            let set = match animation_sets.get(set_id) {
+                Some(set) => set.clone(),
+                None if asset_server.load_state(set_id).is_failed() => AnimationSet::default(),
+                None => continue,
+            };
+            model_animations.animations = Some(Animations::load(
+                &model,
+                set_id,
+                &set,
+                &asset_server,
+                &mut graphs,
+            ));
+        }
+        let Some(animations) = &model_animations.animations else {
+            continue;
+        };
+
+        let current_animation = CurrentAnimation::default();
+        for clip in CharacterClip::ALL {
+            let weights = current_animation.clip_weights(clip);
+            for (node, weight) in animations.clip_nodes(clip).into_iter().zip(weights) {
+                let active = player.play(node).set_weight(weight);
+                if clip.repeats() {
+                    active.repeat();
+                }
+            }
+        }
+        for action in CharacterAction::ALL {
+            let active = player
+                .play(animations.action_indices[action as usize])
+                .set_weight(0.0);
+            if action.repeats() {
+                active.repeat();
+            }
+        }
 
         commands
             .entity(entity)
             .insert(AnimationGraphHandle(animations.graph_handle.clone()))
-            .insert(transitions)
-            .insert(CurrentAnimation(CharacterAnimation::Idle));
+            .insert(animations.clone())
+            .insert(current_animation);
+    }
+}
+
+/// Puts every bone of a character in the lower or upper body mask group, as soon as
+/// its animations are set up. Bones of all characters of the same model share the same
+/// ids, so this happens only once per model.
+fn assign_mask_groups(
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+    players: Query<&Animations, Added<Animations>>,
+    targets: Query<(Entity, &AnimationTarget)>,
+    names: Query<&Name>,
+    parents: Query<&ChildOf>,
+) {
+    if players.is_empty() {
+        return;
+    }
+    for (entity, target) in &targets {
+        let Ok(animations) = players.get(target.player) else {
+            continue;
+        };
+        if graphs
+            .get(&animations.graph_handle)
+            .is_none_or(|graph| graph.mask_groups.contains_key(&target.id))
+        {
+            continue;
+        }
+        let Some(graph) = graphs.get_mut(&animations.graph_handle) else {
+            continue;
+        };
+
+        let upper_body = std::iter::once(entity)
+            .chain(parents.iter_ancestors(entity))
+            .take_while(|&ancestor| ancestor != target.player)
+            .filter_map(|ancestor| names.get(ancestor).ok())
+            .any(|name| UPPER_BODY_BONES.contains(&name.as_str()));
+        let group = if upper_body {
+            UPPER_BODY_GROUP
+        } else {
+            LOWER_BODY_GROUP
+        };
+        graph.add_target_to_mask_group(target.id, group);
+    }
+}
+
+/// Plays the emotes of every character, including the local one, once the server
+/// has accepted them.
+fn play_emotes(
+    mut emote_reader: MessageReader<EmotePlayed>,
+    mut action_writer: MessageWriter<PlayUpperBodyAction>,
+) {
+    for emote in emote_reader.read() {
+        action_writer.write(PlayUpperBodyAction {
+            character: emote.character,
+            action: Some(emote.emote.into()),
+        });
+    }
+}
+
+/// Plays the swing of every attack accepted by the server, on the attacker as well as on
+/// remote characters.
+fn play_attacks(
+    mut attack_reader: MessageReader<AttackStarted>,
+    mut action_writer: MessageWriter<PlayUpperBodyAction>,
+) {
+    for attack in attack_reader.read() {
+        action_writer.write(PlayUpperBodyAction {
+            character: attack.character,
+            action: Some(CharacterAction::Attack),
+        });
     }
 }
 
 fn update_animation(
-    movement_states: Query<&CharacterMovementState>,
+    time: Res<Time>,
+    mut action_reader: MessageReader<PlayUpperBodyAction>,
+    state_machine: Res<AnimationStateMachine<CharacterAnimation>>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    dead: Query<(), With<Dead>>,
     parents: Query<&ChildOf>,
     mut animation_players: Query<(
         Entity,
         &mut AnimationPlayer,
-        &mut AnimationTransitions,
+        &Animations,
         &mut CurrentAnimation,
     )>,
-    animations: Res<Animations>,
 ) {
-    for (entity, mut player, mut transition, mut current_animation) in &mut animation_players {
-        let Some(movement_state) = find_movement_state(entity, &parents, &movement_states) else {
+    let dt = time.delta_secs();
+    let actions: Vec<_> = action_reader.read().copied().collect();
+    for (entity, mut player, animations, mut current_animation) in &mut animation_players {
+        let Some((character, movement_state, velocity)) =
+            find_character(entity, &parents, &characters)
+        else {
             continue;
         };
 
-        let next_animation = if !movement_state.grounded {
-            CharacterAnimation::Fall
-        } else if !movement_state.is_moving() {
-            CharacterAnimation::Idle
-        } else if movement_state.is_moving_backwards() {
-            CharacterAnimation::WalkBack
-        } else if movement_state.is_running() {
-            CharacterAnimation::Run
+        for play in actions.iter().filter(|play| play.character == character) {
+            current_animation.action_playing = play.action.is_some();
+            if let Some(action) = play.action {
+                current_animation.action = Some(action);
+                if let Some(active) =
+                    player.animation_mut(animations.action_indices[action as usize])
+                {
+                    active.replay();
+                }
+            }
+        }
+        if let Some(action) = current_animation.action
+            && !action.repeats()
+            && player
+                .animation(animations.action_indices[action as usize])
+                .is_some_and(ActiveAnimation::is_finished)
+        {
+            current_animation.action_playing = false;
+        }
+
+        current_animation.time_in_state += dt;
+        let context = TransitionContext {
+            movement: *movement_state,
+            vertical_velocity: velocity.map_or(0.0, |velocity| velocity.linvel.y),
+            time_in_state: current_animation.time_in_state,
+            dead: dead.contains(character),
+        };
+        if let Some(transition) = state_machine.next(current_animation.state, &context) {
+            let next_state = transition.to;
+            let replayed = match next_state {
+                CharacterAnimation::Jump => Some(CharacterClip::Jump),
+                CharacterAnimation::Land => Some(CharacterClip::Land),
+                CharacterAnimation::Death => Some(CharacterClip::Death),
+                _ => None,
+            };
+            if let Some(clip) = replayed {
+                for node in animations.clip_nodes(clip) {
+                    if let Some(active) = player.animation_mut(node) {
+                        active.replay();
+                    }
+                }
+            }
+            current_animation.blend_time = animations.transition_time(
+                current_animation.state,
+                next_state,
+                transition.blend_time,
+            );
+            current_animation.state = next_state;
+            current_animation.time_in_state = 0.0;
+        }
+
+        // The replicated speed jumps between walking and running, so the blend space
+        // follows it smoothly instead.
+        let target_speed = if movement_state.is_moving() {
+            movement_state.speed
         } else {
-            CharacterAnimation::Walk
+            IDLE_SPEED
         };
+        let blend = 1.0 - (-SPEED_SMOOTHING * dt).exp();
+        current_animation.speed = current_animation.speed.lerp(target_speed, blend);
 
-        if current_animation.0 == next_animation {
-            continue;
+        let current_state = current_animation.state;
+        // Instant transitions switch the weights all at once.
+        let step = if current_animation.blend_time > 0.0 {
+            dt / current_animation.blend_time
+        } else {
+            1.0
+        };
+        for state in CharacterAnimation::ALL {
+            let weight = &mut current_animation.weights[state as usize];
+            *weight = if state == current_state {
+                (*weight + step).min(1.0)
+            } else {
+                (*weight - step).max(0.0)
+            };
         }
 
-        current_animation.0 = next_animation;
-        transition
-            .play(
-                &mut player,
-                animations.indices[current_animation.0 as usize],
-                Duration::from_millis(250),
-            )
-            .repeat();
+        let action_step = dt / TRANSITION_TIME;
+        current_animation.action_weight = if current_animation.action_playing {
+            (current_animation.action_weight + action_step).min(1.0)
+        } else {
+            (current_animation.action_weight - action_step).max(0.0)
+        };
+
+        for clip in CharacterClip::ALL {
+            let weights = current_animation.clip_weights(clip);
+            for (node, weight) in animations.clip_nodes(clip).into_iter().zip(weights) {
+                if let Some(active) = player.animation_mut(node) {
+                    active.set_weight(weight);
+                }
+            }
+        }
+        for action in CharacterAction::ALL {
+            if let Some(active) = player.animation_mut(animations.action_indices[action as usize]) {
+                active.set_weight(current_animation.action_clip_weight(action));
+            }
+        }
+
+        // Feet slide whenever the character moves slower or faster than the clips expect,
+        // such as when pushing against a wall, so they play at the speed of the ground.
+        let ground_speed = velocity.map_or(0.0, |velocity| velocity.linvel.xz().length());
+        let [_, walk, run] = current_animation.locomotion_weights();
+        let speed = |clip: CharacterClip| animations.ground_speeds[clip as usize];
+        let rates: [(&[CharacterClip], f32); 2] = [
+            (
+                &[CharacterClip::Walk, CharacterClip::Run],
+                walk * speed(CharacterClip::Walk) + run * speed(CharacterClip::Run),
+            ),
+            (&[CharacterClip::WalkBack], speed(CharacterClip::WalkBack)),
+        ];
+        for (clips, authored_speed) in rates {
+            let rate = if authored_speed > 0.0 {
+                (ground_speed / authored_speed).clamp(MIN_PLAYBACK_RATE, MAX_PLAYBACK_RATE)
+            } else {
+                1.0
+            };
+            for node in clips.iter().flat_map(|&clip| animations.clip_nodes(clip)) {
+                if let Some(active) = player.animation_mut(node) {
+                    active.set_speed(rate);
+                }
+            }
+        }
     }
 }
 
-fn find_movement_state(
+#[allow(clippy::type_complexity)]
+pub fn find_character<'a>(
     mut entity: Entity,
     parents: &Query<&ChildOf>,
-    movement_states: &Query<&CharacterMovementState>,
-) -> Option<CharacterMovementState> {
+    characters: &'a Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+) -> Option<(Entity, &'a CharacterMovementState, Option<&'a Velocity>)> {
     loop {
-        if let Ok(state) = movement_states.get(entity) {
-            return Some(*state);
+        if let Ok(character) = characters.get(entity) {
+            return Some(character);
         }
 
         let Ok(parent) = parents.get(entity) else {
@@ -154,3 +949,34 @@ fn find_movement_state(
         entity = parent.parent();
     }
 }
+
+/// Sums the weights of the full body and lower body nodes of the same clip, and reports
+/// the markers of the clips dominating the blend.
+fn reach_animation_markers(
+    mut crossed_reader: MessageReader<ClipMarkerCrossed>,
+    mut reached_writer: MessageWriter<AnimationMarkerReached>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    parents: Query<&ChildOf>,
+) {
+    let mut crossed: Vec<ClipMarkerCrossed> = Vec::new();
+    for marker in crossed_reader.read() {
+        match crossed.iter_mut().find(|other| {
+            (other.player, other.clip, other.marker) == (marker.player, marker.clip, marker.marker)
+        }) {
+            Some(other) => other.weight += marker.weight,
+            None => crossed.push(*marker),
+        }
+    }
+
+    for marker in crossed {
+        if marker.weight < MARKER_WEIGHT {
+            continue;
+        }
+        if let Some((character, _, _)) = find_character(marker.player, &parents, &characters) {
+            reached_writer.write(AnimationMarkerReached {
+                character,
+                marker: marker.marker,
+            });
+        }
+    }
+}
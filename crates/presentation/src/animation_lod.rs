@@ -0,0 +1,192 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    app::AnimationSystems,
+    camera::primitives::{Frustum, Sphere},
+    prelude::*,
+};
+use bevy_rapier3d::prelude::Velocity;
+use merlo_simulation::controller::{CharacterController, CharacterMovementState, RootMotion};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    animation::{Animations, find_character},
+    config::Config,
+};
+
+#[derive(Default)]
+pub struct AnimationLodPlugin;
+
+impl Plugin for AnimationLodPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, add_animation_lod).add_systems(
+            PostUpdate,
+            (
+                skip_animation_frames.before(AnimationSystems),
+                restore_animation_graphs.after(AnimationSystems),
+            ),
+        );
+    }
+}
+
+/// Radius of the sphere around a character tested against the view of the camera.
+const CHARACTER_RADIUS: f32 = 1.5;
+
+/// Distances from the camera where characters animate less often, or not at all.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct AnimationLodSettings {
+    /// Beyond this distance, characters animate at `reduced_rate`.
+    pub reduced_distance: f32,
+    /// Beyond this distance, characters stop animating.
+    pub paused_distance: f32,
+    /// Animation updates per second of characters in the reduced level.
+    pub reduced_rate: f32,
+    /// Whether characters outside the view of the camera stop animating.
+    pub pause_offscreen: bool,
+}
+
+impl Default for AnimationLodSettings {
+    fn default() -> Self {
+        Self {
+            reduced_distance: 20.0,
+            paused_distance: 60.0,
+            reduced_rate: 10.0,
+            pause_offscreen: true,
+        }
+    }
+}
+
+/// How often the [`AnimationPlayer`] of a character is evaluated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationLodLevel {
+    #[default]
+    Full,
+    Reduced,
+    Paused,
+}
+
+/// Level of detail of the animation of a character, on its [`AnimationPlayer`].
+#[derive(Component, Debug, Default)]
+pub struct AnimationLod {
+    pub level: AnimationLodLevel,
+    /// Time not yet played while skipping frames, caught up by the next evaluated frame.
+    skipped: f32,
+    /// Factor applied to the speeds of the active animations to catch up this frame.
+    catch_up: Option<f32>,
+    /// Whether the animation graph was taken away to skip this frame.
+    skipping: bool,
+}
+
+fn add_animation_lod(mut commands: Commands, players: Query<Entity, Added<Animations>>) {
+    for player in &players {
+        commands.entity(player).insert(AnimationLod::default());
+    }
+}
+
+/// Chooses the level of each character from its distance to the camera, then skips the
+/// evaluation of the players which are not due this frame by taking away their graph.
+#[allow(clippy::too_many_arguments)]
+fn skip_animation_frames(
+    time: Res<Time>,
+    config: Res<Config>,
+    camera: Query<(&GlobalTransform, &Frustum), With<Camera3d>>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    parents: Query<&ChildOf>,
+    transforms: Query<(&GlobalTransform, Has<RootMotion>)>,
+    mut players: Query<
+        (
+            Entity,
+            &mut AnimationLod,
+            &mut AnimationPlayer,
+            &mut AnimationGraphHandle,
+        ),
+        With<Animations>,
+    >,
+) {
+    let settings = config.animation_lod;
+    let local_character = controller.single().ok().map(ChildOf::parent);
+    let camera = camera.single().ok();
+    let dt = time.delta_secs();
+
+    for (entity, mut lod, mut player, mut graph) in &mut players {
+        let character = find_character(entity, &parents, &characters)
+            .and_then(|(character, _, _)| Some((character, transforms.get(character).ok()?)));
+        lod.level = match (character, camera) {
+            // Characters moved by their animation always animate fully.
+            (Some((character, (_, root_motion))), _)
+                if root_motion || Some(character) == local_character =>
+            {
+                AnimationLodLevel::Full
+            }
+            (Some((_, (transform, _))), Some((camera, frustum))) => {
+                let position = transform.translation();
+                let distance = position.distance(camera.translation());
+                let visible = frustum.intersects_sphere(
+                    &Sphere {
+                        center: position.into(),
+                        radius: CHARACTER_RADIUS,
+                    },
+                    false,
+                );
+                if distance > settings.paused_distance || (settings.pause_offscreen && !visible) {
+                    AnimationLodLevel::Paused
+                } else if distance > settings.reduced_distance {
+                    AnimationLodLevel::Reduced
+                } else {
+                    AnimationLodLevel::Full
+                }
+            }
+            _ => AnimationLodLevel::Full,
+        };
+
+        let evaluate = match lod.level {
+            AnimationLodLevel::Full => true,
+            // Paused characters resume where they stopped, without catching up.
+            AnimationLodLevel::Paused => {
+                lod.skipped = 0.0;
+                false
+            }
+            AnimationLodLevel::Reduced => {
+                lod.skipped + dt >= 1.0 / settings.reduced_rate.max(f32::EPSILON)
+            }
+        };
+        if evaluate {
+            if lod.skipped > 0.0 && dt > 0.0 {
+                let catch_up = (lod.skipped + dt) / dt;
+                player.adjust_speeds(catch_up);
+                lod.catch_up = Some(catch_up);
+            }
+            lod.skipped = 0.0;
+        } else {
+            if lod.level == AnimationLodLevel::Reduced {
+                lod.skipped += dt;
+            }
+            // Without a graph, the player is neither advanced nor applied to its bones.
+            *graph = AnimationGraphHandle::default();
+            lod.skipping = true;
+        }
+    }
+}
+
+fn restore_animation_graphs(
+    mut players: Query<(
+        &mut AnimationLod,
+        &mut AnimationPlayer,
+        &mut AnimationGraphHandle,
+        &Animations,
+    )>,
+) {
+    for (mut lod, mut player, mut graph, animations) in &mut players {
+        if let Some(catch_up) = lod.catch_up.take() {
+            player.adjust_speeds(1.0 / catch_up);
+        }
+        if lod.skipping {
+            lod.skipping = false;
+            graph.0 = animations.graph_handle().clone();
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use merlo_model::CharacterModel;
+use serde::Deserialize;
+
+use crate::animation::{CharacterAction, CharacterAnimation, CharacterClip};
+
+/// Maps the animation states of a character model to their clips and transitions,
+/// loaded from a `<prefix>.animset.ron` file next to the model.
+///
+/// Clips missing from the set fall back to the files named after the prefix of the
+/// model, such as `character-large-male-walk.glb`.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct AnimationSet {
+    /// Name of the bone carrying the root motion of the clips.
+    #[serde(default = "default_root_bone")]
+    pub root_bone: String,
+    /// glTF files whose first animation is played for each clip.
+    #[serde(default)]
+    pub clips: HashMap<CharacterClip, String>,
+    /// glTF files whose first animation is played for each upper body action.
+    #[serde(default)]
+    pub actions: HashMap<CharacterAction, String>,
+    /// Ground speed in meters per second at which the clips moving the character were
+    /// authored, to play them faster or slower as the character actually moves.
+    #[serde(default = "default_ground_speeds")]
+    pub ground_speeds: HashMap<CharacterClip, f32>,
+    /// Seconds to fade into each state.
+    #[serde(default)]
+    pub transitions: HashMap<CharacterAnimation, f32>,
+    /// Seconds to fade from one state into another, overriding the time of the state
+    /// entered, such as `(Locomotion, Fall): 0.0` to start falling at once.
+    #[serde(default)]
+    pub blends: HashMap<(CharacterAnimation, CharacterAnimation), f32>,
+    /// Bones adjusted to plant the feet on uneven ground, or `None` to keep them as animated.
+    #[serde(default = "default_foot_ik")]
+    pub foot_ik: Option<FootIkBones>,
+    /// How the head and chest turn toward what the character looks at, or `None` to keep
+    /// them as animated.
+    #[serde(default = "default_look_at")]
+    pub look_at: Option<LookAtSettings>,
+    /// How the body leans into turns and changes of speed, or `None` to keep it as animated.
+    #[serde(default = "default_lean")]
+    pub lean: Option<LeanSettings>,
+}
+
+/// Names of the bones moved by foot IK.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FootIkBones {
+    /// Lowered along with both legs when a foot needs to reach further down.
+    pub pelvis: String,
+    pub left: LegBones,
+    pub right: LegBones,
+}
+
+/// Names of the bones of a two-bone leg chain.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LegBones {
+    pub upper: String,
+    pub lower: String,
+    pub foot: String,
+}
+
+impl LegBones {
+    fn new(side: &str) -> Self {
+        Self {
+            upper: format!("upper-leg-{side}"),
+            lower: format!("lower-leg-{side}"),
+            foot: format!("foot-{side}"),
+        }
+    }
+}
+
+/// Bones turned by the look-at layer, and how far and fast they turn.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct LookAtSettings {
+    pub head: String,
+    pub chest: String,
+    /// Largest angles from the forward direction of the character, in degrees.
+    pub max_yaw: f32,
+    pub max_pitch: f32,
+    /// Share of the rotation taken by the chest, the rest is taken by the head.
+    pub chest_weight: f32,
+    /// How quickly the bones follow the target.
+    pub smoothing: f32,
+}
+
+impl Default for LookAtSettings {
+    fn default() -> Self {
+        Self {
+            head: "head".into(),
+            chest: "torso".into(),
+            max_yaw: 70.0,
+            max_pitch: 40.0,
+            chest_weight: 0.3,
+            smoothing: 8.0,
+        }
+    }
+}
+
+/// Bone leaned by the procedural lean layer, and how far and fast it leans.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct LeanSettings {
+    pub bone: String,
+    /// Degrees of lean when turning at full rate and running speed.
+    pub turn_lean: f32,
+    /// Degrees of lean for every unit of acceleration, sideways and forward.
+    pub acceleration_lean: f32,
+    /// Largest lean in any direction, in degrees.
+    pub max_angle: f32,
+    /// How quickly the bone follows the target lean.
+    pub smoothing: f32,
+}
+
+impl Default for LeanSettings {
+    fn default() -> Self {
+        Self {
+            bone: "hips".into(),
+            turn_lean: 6.0,
+            acceleration_lean: 1.5,
+            max_angle: 12.0,
+            smoothing: 6.0,
+        }
+    }
+}
+
+fn default_ground_speeds() -> HashMap<CharacterClip, f32> {
+    HashMap::from_iter([
+        (CharacterClip::Walk, 3.0),
+        (CharacterClip::Run, 9.0),
+        (CharacterClip::WalkBack, 3.0),
+    ])
+}
+
+fn default_root_bone() -> String {
+    "root".into()
+}
+
+fn default_look_at() -> Option<LookAtSettings> {
+    Some(LookAtSettings::default())
+}
+
+fn default_lean() -> Option<LeanSettings> {
+    Some(LeanSettings::default())
+}
+
+fn default_foot_ik() -> Option<FootIkBones> {
+    Some(FootIkBones {
+        pelvis: "hips".into(),
+        left: LegBones::new("left"),
+        right: LegBones::new("right"),
+    })
+}
+
+impl Default for AnimationSet {
+    fn default() -> Self {
+        Self {
+            root_bone: default_root_bone(),
+            clips: default(),
+            actions: default(),
+            ground_speeds: default_ground_speeds(),
+            transitions: default(),
+            blends: default(),
+            foot_ik: default_foot_ik(),
+            look_at: default_look_at(),
+            lean: default_lean(),
+        }
+    }
+}
+
+impl AnimationSet {
+    pub fn path(model: &CharacterModel) -> String {
+        format!("{}.animset.ron", model.prefix)
+    }
+
+    pub fn clip_path(&self, model: &CharacterModel, clip: CharacterClip) -> String {
+        self.clips
+            .get(&clip)
+            .cloned()
+            .unwrap_or_else(|| model.clip_path(clip.clip_name()))
+    }
+
+    pub fn action_path(&self, model: &CharacterModel, action: CharacterAction) -> String {
+        self.actions
+            .get(&action)
+            .cloned()
+            .unwrap_or_else(|| model.clip_path(action.clip_name()))
+    }
+}
+
+#[derive(Default, TypePath)]
+pub struct AnimationSetLoader;
+
+impl AssetLoader for AnimationSetLoader {
+    type Asset = AnimationSet;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<AnimationSet> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["animset.ron"]
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_simulation::controller::CharacterMovementState;
+
+use crate::animation::CharacterAnimation;
+
+/// Upward velocity above which an airborne character is considered jumping rather than falling.
+const JUMP_VELOCITY: f32 = 0.5;
+/// How long landing blocks any other state before going back to locomotion.
+const LAND_TIME: f32 = 0.3;
+
+/// What the conditions of an [`AnimationStateMachine`] know about a character.
+#[derive(Debug, Clone, Copy)]
+pub struct TransitionContext {
+    pub movement: CharacterMovementState,
+    pub vertical_velocity: f32,
+    /// Seconds since the current state was entered.
+    pub time_in_state: f32,
+    /// Whether the character died and was not respawned yet.
+    pub dead: bool,
+}
+
+impl TransitionContext {
+    /// Whether the character is going up fast enough to be jumping.
+    pub fn rising(&self) -> bool {
+        self.vertical_velocity > JUMP_VELOCITY
+    }
+}
+
+type Condition = Box<dyn Fn(&TransitionContext) -> bool + Send + Sync>;
+
+/// A transition of an [`AnimationStateMachine`], taken as soon as its condition holds.
+pub struct Transition<S> {
+    /// State the transition leaves, or `None` to leave any state.
+    pub from: Option<S>,
+    pub to: S,
+    /// Seconds to fade into `to`, or `None` for the fade time of that state.
+    pub blend_time: Option<f32>,
+    condition: Condition,
+}
+
+/// States and the conditions to go from one to another, checked in the order they are
+/// added, so that earlier transitions take priority.
+///
+/// Games extend the [`CharacterAnimation`] machine by inserting their own resource,
+/// usually built on top of its default transitions.
+#[derive(Resource)]
+pub struct AnimationStateMachine<S: Send + Sync + 'static> {
+    transitions: Vec<Transition<S>>,
+}
+
+impl<S: Copy + PartialEq + Send + Sync + 'static> AnimationStateMachine<S> {
+    pub fn new() -> Self {
+        Self {
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Adds a transition from the state `from`, or from any state when `None`, to the
+    /// state `to`, fading over `blend_time` seconds or the fade time of `to` when `None`.
+    pub fn with_transition(
+        mut self,
+        from: impl Into<Option<S>>,
+        to: S,
+        blend_time: impl Into<Option<f32>>,
+        condition: impl Fn(&TransitionContext) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.transitions.push(Transition {
+            from: from.into(),
+            to,
+            blend_time: blend_time.into(),
+            condition: Box::new(condition),
+        });
+        self
+    }
+
+    /// The first transition leaving `state` whose condition holds, if any.
+    pub fn next(&self, state: S, context: &TransitionContext) -> Option<&Transition<S>> {
+        self.transitions.iter().find(|transition| {
+            transition.to != state
+                && transition.from.is_none_or(|from| from == state)
+                && (transition.condition)(context)
+        })
+    }
+}
+
+impl<S: Copy + PartialEq + Send + Sync + 'static> Default for AnimationStateMachine<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimationStateMachine<CharacterAnimation> {
+    /// Jumping and falling while airborne, landing when touching the ground again, then
+    /// walking forward or backward, until dying and standing up again when respawned.
+    pub fn character() -> Self {
+        use CharacterAnimation::*;
+
+        let machine = Self::new()
+            .with_transition(None, Death, None, |context| context.dead)
+            .with_transition(Death, Locomotion, None, |context| !context.dead)
+            .with_transition(Jump, Land, None, |context| context.movement.grounded)
+            // Keep going up with the jump until the apex, without restarting it.
+            .with_transition(Jump, Fall, None, |context| !context.rising())
+            .with_transition(Fall, Land, None, |context| context.movement.grounded);
+        [Locomotion, WalkBack, Land]
+            .into_iter()
+            .fold(machine, |machine, state| {
+                machine
+                    .with_transition(state, Jump, None, |context| {
+                        !context.movement.grounded && context.rising()
+                    })
+                    .with_transition(state, Fall, None, |context| !context.movement.grounded)
+            })
+            .with_transition(Land, WalkBack, None, |context| {
+                context.time_in_state >= LAND_TIME && context.movement.is_moving_backwards()
+            })
+            .with_transition(Land, Locomotion, None, |context| {
+                context.time_in_state >= LAND_TIME
+            })
+            .with_transition(Locomotion, WalkBack, None, |context| {
+                context.movement.is_moving_backwards()
+            })
+            .with_transition(WalkBack, Locomotion, None, |context| {
+                !context.movement.is_moving_backwards()
+            })
+    }
+}
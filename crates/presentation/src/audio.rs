@@ -0,0 +1,181 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{audio::Volume, prelude::*};
+use bevy_egui::egui;
+
+use crate::{
+    animation::AnimationMarkerReached, locale::Localization,
+    simulation::controller::CharacterMovementState,
+};
+
+#[derive(Default)]
+pub struct MovementAudioPlugin;
+
+impl Plugin for MovementAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioSettings>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    add_spatial_listener,
+                    add_movement_audio,
+                    play_movement_sounds,
+                ),
+            );
+    }
+}
+
+/// Volume levels in the linear scale, where `1.0` is the original level.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AudioSettings {
+    pub master: f32,
+    pub sfx: f32,
+    pub music: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            sfx: 1.0,
+            music: 0.6,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn sfx_volume(&self) -> Volume {
+        Volume::Linear(self.master * self.sfx)
+    }
+}
+
+/// The kind of surface a character is standing on, selecting the footstep variant.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Surface {
+    #[default]
+    Ground,
+}
+
+impl Surface {
+    fn footstep_path(self) -> &'static str {
+        match self {
+            Surface::Ground => "sounds/footstep-ground.ogg",
+        }
+    }
+}
+
+#[derive(Resource)]
+struct MovementSounds {
+    footstep_ground: Handle<AudioSource>,
+    jump: Handle<AudioSource>,
+    land: Handle<AudioSource>,
+}
+
+impl MovementSounds {
+    fn footstep(&self, surface: Surface) -> Handle<AudioSource> {
+        match surface {
+            Surface::Ground => self.footstep_ground.clone(),
+        }
+    }
+}
+
+/// Tracks the movement state of a character to detect jumps and landings.
+#[derive(Component)]
+struct MovementAudio {
+    was_grounded: bool,
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(MovementSounds {
+        footstep_ground: asset_server.load(Surface::Ground.footstep_path()),
+        jump: asset_server.load("sounds/jump.ogg"),
+        land: asset_server.load("sounds/land.ogg"),
+    });
+}
+
+fn add_spatial_listener(mut commands: Commands, cameras: Query<Entity, Added<Camera3d>>) {
+    for camera in &cameras {
+        commands.entity(camera).insert(SpatialListener::default());
+    }
+}
+
+fn add_movement_audio(
+    mut commands: Commands,
+    characters: Query<Entity, Added<CharacterMovementState>>,
+) {
+    for entity in &characters {
+        commands
+            .entity(entity)
+            .insert(MovementAudio { was_grounded: true });
+    }
+}
+
+/// Number of footsteps per second for the given movement speed.
+pub fn steps_per_second(speed: f32) -> f32 {
+    (speed * 20.0).clamp(1.5, 3.5)
+}
+
+/// Plays footsteps on the foot contacts of the animation, and jump and land sounds on
+/// changes of the grounded state.
+fn play_movement_sounds(
+    mut commands: Commands,
+    mut marker_reader: MessageReader<AnimationMarkerReached>,
+    settings: Res<AudioSettings>,
+    sounds: Res<MovementSounds>,
+    mut characters: Query<(
+        &CharacterMovementState,
+        &GlobalTransform,
+        &mut MovementAudio,
+        Option<&Surface>,
+    )>,
+) {
+    let playback = PlaybackSettings::DESPAWN
+        .with_spatial(true)
+        .with_volume(settings.sfx_volume());
+
+    let mut play = |sound: Handle<AudioSource>, transform: &GlobalTransform| {
+        commands.spawn((
+            AudioPlayer::new(sound),
+            playback,
+            Transform::from_translation(transform.translation()),
+        ));
+    };
+
+    for (state, transform, mut audio, _) in &mut characters {
+        if audio.was_grounded && !state.grounded && state.jumping {
+            play(sounds.jump.clone(), transform);
+        } else if !audio.was_grounded && state.grounded {
+            play(sounds.land.clone(), transform);
+        }
+        audio.was_grounded = state.grounded;
+    }
+
+    for reached in marker_reader.read() {
+        if !reached.marker.is_footstep() {
+            continue;
+        }
+        if let Ok((_, transform, _, surface)) = characters.get(reached.character) {
+            play(
+                sounds.footstep(surface.copied().unwrap_or_default()),
+                transform,
+            );
+        }
+    }
+}
+
+/// Shows the volume sliders.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let labels =
+        ["audio.master", "audio.sfx", "audio.music"].map(|key| localization.tr(key).to_owned());
+
+    let mut settings = *world.resource::<AudioSettings>();
+    ui.add(egui::Slider::new(&mut settings.master, 0.0..=1.0).text(&labels[0]));
+    ui.add(egui::Slider::new(&mut settings.sfx, 0.0..=1.0).text(&labels[1]));
+    ui.add(egui::Slider::new(&mut settings.music, 0.0..=1.0).text(&labels[2]));
+
+    world.resource_mut::<AudioSettings>().set_if_neq(settings);
+}
@@ -12,23 +12,75 @@ use bevy::{
 };
 use merlo_simulation::controller::CharacterController;
 
+use crate::click_to_move;
+
 #[derive(Default)]
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<Mesh3dClicked>()
+            .init_resource::<CameraMode>()
+            .init_resource::<GameViewHovered>()
             .add_systems(Startup, setup)
+            .add_systems(Update, toggle_camera_mode)
             .add_systems(
                 PostUpdate,
                 (
-                    pick_mesh3d_on_left_click.after(TransformSystems::Propagate),
+                    // Clicks walk the character instead while moving by clicking.
+                    pick_mesh3d_on_left_click
+                        .after(TransformSystems::Propagate)
+                        .run_if(not(click_to_move::is_enabled)),
                     mesh3d_clicked.after(pick_mesh3d_on_left_click),
+                    follow_pivot.after(TransformSystems::Propagate),
                 ),
             );
     }
 }
 
+/// Where the camera is placed relative to the controlled character, toggled with V.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CameraMode {
+    #[default]
+    ThirdPerson,
+    FirstPerson,
+}
+
+impl CameraMode {
+    /// Offset of the camera from the pivot, which sits 3 meters above the character origin.
+    fn follow(self, target: Entity) -> SmoothFollow {
+        match self {
+            CameraMode::ThirdPerson => SmoothFollow::new(
+                target,
+                Transform::from_xyz(0.0, 0.0, -12.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ),
+            // At eye height, slightly in front of the head, following rigidly.
+            CameraMode::FirstPerson => SmoothFollow {
+                target,
+                offset: Transform::from_xyz(0.0, -2.3, 0.3).looking_to(Vec3::Z, Vec3::Y),
+                stiffness: 40.0,
+            },
+        }
+    }
+}
+
+fn toggle_camera_mode(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut mode: ResMut<CameraMode>,
+    mut followers: Query<&mut SmoothFollow, With<Camera3d>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyV) {
+        return;
+    }
+    *mode = match *mode {
+        CameraMode::ThirdPerson => CameraMode::FirstPerson,
+        CameraMode::FirstPerson => CameraMode::ThirdPerson,
+    };
+    for mut follow in &mut followers {
+        *follow = mode.follow(follow.target);
+    }
+}
+
 #[derive(Message, Debug, Clone, Copy)]
 pub struct Mesh3dClicked {
     entity: Entity,
@@ -72,7 +124,7 @@ fn move_controller(
     commands.entity(controller.0).insert(ChildOf(next));
 }
 
-/// Spawns a dummy entity to be controlled, with a camera pivot and a camera as child.
+/// Spawns a dummy entity to be controlled, with a camera pivot as child and a camera following it.
 pub fn setup(mut commands: Commands) {
     commands
         .spawn((
@@ -83,19 +135,84 @@ pub fn setup(mut commands: Commands) {
 }
 
 pub fn spawn<R: Relationship>(parent: &mut RelatedSpawnerCommands<R>) {
-    parent
+    let pivot = parent
         .spawn((
             CharacterController,
             Transform::from_xyz(0.0, 3.0, 0.0),
             InheritedVisibility::default(),
         ))
-        .with_children(|pivot| {
-            // Camera offset behind the pivot
-            pivot.spawn((
-                Camera3d::default(),
-                Transform::from_xyz(0.0, 0.0, -12.0).looking_at(Vec3::ZERO, Vec3::Y),
-            ));
-        });
+        .id();
+
+    // The camera is not parented to the pivot, so that it can smoothly catch up with it
+    // instead of following it rigidly, e.g. when the controller moves to another character.
+    let follow = CameraMode::default().follow(pivot);
+    parent
+        .commands_mut()
+        .spawn((Camera3d::default(), follow.offset, follow));
+}
+
+/// Makes a root entity smoothly follow a target entity, at an offset relative to it.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SmoothFollow {
+    pub target: Entity,
+    pub offset: Transform,
+    /// How quickly the follower catches up with the target, higher is snappier.
+    pub stiffness: f32,
+}
+
+impl SmoothFollow {
+    pub fn new(target: Entity, offset: Transform) -> Self {
+        Self {
+            target,
+            offset,
+            stiffness: 8.0,
+        }
+    }
+}
+
+/// Moves followers toward their targets, after transforms have been propagated so that
+/// the latest target position is used without lagging a frame behind.
+pub fn follow_pivot(
+    time: Res<Time>,
+    targets: Query<&GlobalTransform, Without<SmoothFollow>>,
+    mut followers: Query<(&SmoothFollow, &mut Transform, &mut GlobalTransform)>,
+) {
+    for (follow, mut transform, mut global_transform) in &mut followers {
+        let Ok(target) = targets.get(follow.target) else {
+            continue;
+        };
+        let desired = target.mul_transform(follow.offset).compute_transform();
+
+        // Frame-rate independent exponential smoothing.
+        let t = 1.0 - (-follow.stiffness * time.delta_secs()).exp();
+        transform.translation = transform.translation.lerp(desired.translation, t);
+        transform.rotation = transform.rotation.slerp(desired.rotation, t);
+
+        // Followers are root entities, so their global transform is their local one.
+        *global_transform = GlobalTransform::from(*transform);
+    }
+}
+
+/// Whether the pointer is over the game view docked in the GameView tab, and not over an
+/// egui window or popup covering it, as egui wants the pointer over the whole dock.
+#[derive(Resource, Debug, Default)]
+pub struct GameViewHovered(pub bool);
+
+/// Returns the world-space ray under the cursor, if the cursor is inside the camera viewport.
+pub fn cursor_ray(
+    window: &Window,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Ray3d> {
+    let cursor_position = window.cursor_position()?;
+    // The camera may render into a sub-rect of the window, e.g. the GameView tab.
+    let viewport = camera.logical_viewport_rect()?;
+    if !viewport.contains(cursor_position) {
+        return None;
+    }
+    camera
+        .viewport_to_world(camera_transform, cursor_position - viewport.min)
+        .ok()
 }
 
 fn pick_mesh3d_on_left_click(
@@ -110,12 +227,8 @@ fn pick_mesh3d_on_left_click(
         return;
     }
 
-    let Some(cursor_position) = window.cursor_position() else {
-        return;
-    };
-
     let (camera, camera_transform) = *camera;
-    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
+    let Some(ray) = cursor_ray(&window, camera, camera_transform) else {
         return;
     };
 
@@ -0,0 +1,120 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use merlo_simulation::{
+    chat::{ChatInput, ChatMessage, MAX_CHAT_LEN},
+    network::Cli,
+    persistence::{RestoreAutosave, SaveWorld},
+    physics::SetGravity,
+};
+
+use crate::locale::Localization;
+
+/// Number of chat lines kept in the scrollback.
+const SCROLLBACK_LEN: usize = 200;
+
+#[derive(Default)]
+pub struct ChatWindowPlugin;
+
+impl Plugin for ChatWindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatLog>()
+            .add_systems(Update, receive_chat);
+    }
+}
+
+/// Received chat lines and the line being typed.
+#[derive(Resource, Default)]
+pub struct ChatLog {
+    lines: VecDeque<ChatMessage>,
+    input: String,
+}
+
+fn receive_chat(mut chat_reader: MessageReader<ChatMessage>, mut log: ResMut<ChatLog>) {
+    for message in chat_reader.read() {
+        if log.lines.len() == SCROLLBACK_LEN {
+            log.lines.pop_front();
+        }
+        log.lines.push_back(message.clone());
+    }
+}
+
+/// Formats a Unix timestamp as `HH:MM` in UTC.
+fn format_time(timestamp: u64) -> String {
+    let minutes = timestamp / 60;
+    format!("{:02}:{:02}", (minutes / 60) % 24, minutes % 60)
+}
+
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let name = world.resource::<Cli>().name().to_owned();
+    let hint = world.resource::<Localization>().tr("chat.hint").to_owned();
+    let mut sent = None;
+
+    let mut log = world.resource_mut::<ChatLog>();
+    let input_height = ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+    egui::ScrollArea::vertical()
+        .auto_shrink(false)
+        .max_height(ui.available_height() - input_height)
+        .stick_to_bottom(true)
+        .show(ui, |ui| {
+            for line in &log.lines {
+                let text = format!(
+                    "[{}] {}: {}",
+                    format_time(line.timestamp),
+                    line.sender,
+                    line.text
+                );
+                let mentioned = line.sender != name && line.text.contains(name.as_str());
+                let mut text = egui::RichText::new(text);
+                if mentioned {
+                    text = text
+                        .strong()
+                        .background_color(ui.visuals().selection.bg_fill);
+                }
+                ui.label(text);
+            }
+        });
+
+    let response = ui.add(
+        egui::TextEdit::singleline(&mut log.input)
+            .char_limit(MAX_CHAT_LEN)
+            .hint_text(hint)
+            .desired_width(f32::INFINITY),
+    );
+    if response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+        let text = std::mem::take(&mut log.input);
+        if !text.trim().is_empty() {
+            sent = Some(text);
+        }
+        response.request_focus();
+    }
+
+    if let Some(text) = sent {
+        // Commands for the server start with a slash, everything else is chat.
+        let command = text.trim();
+        if let Some(path) = command.strip_prefix("/save")
+            && (path.is_empty() || path.starts_with(' '))
+        {
+            let path = path.trim();
+            world.write_message(SaveWorld {
+                path: (!path.is_empty()).then(|| path.into()),
+            });
+        } else if command == "/restore" {
+            world.write_message(RestoreAutosave);
+        } else if let Some(acceleration) = command.strip_prefix("/gravity ") {
+            match acceleration.trim().parse() {
+                Ok(acceleration) => {
+                    world.write_message(SetGravity { acceleration });
+                }
+                Err(err) => warn!("Invalid gravity {acceleration}: {err}"),
+            }
+        } else {
+            world.write_message(ChatInput::new(text));
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{math::cubic_splines::CubicCurve, prelude::*, transform::TransformSystems};
+use merlo_model::CameraPath;
+use merlo_simulation::camera_path::PlayCameraPath;
+
+use crate::camera;
+
+#[derive(Default)]
+pub struct CinematicCameraPlugin;
+
+impl Plugin for CinematicCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (start_camera_paths, skip_camera_path))
+            .add_systems(
+                PostUpdate,
+                fly_camera_paths
+                    .after(TransformSystems::Propagate)
+                    .after(camera::follow_pivot),
+            );
+    }
+}
+
+/// A camera flying along a [`CameraPath`], overriding its usual follow behavior until the end.
+#[derive(Component)]
+pub struct CameraPathPlayback {
+    positions: CubicCurve<Vec3>,
+    targets: CubicCurve<Vec3>,
+    duration: f32,
+    easing: EaseFunction,
+    elapsed: f32,
+}
+
+impl CameraPathPlayback {
+    /// Returns `None` when the path has less than two waypoints.
+    pub fn new(path: &CameraPath) -> Option<Self> {
+        let positions = path.waypoints.iter().map(|waypoint| waypoint.position);
+        let targets = path.waypoints.iter().map(|waypoint| waypoint.look_at);
+        Some(Self {
+            positions: CubicCardinalSpline::new_catmull_rom(positions)
+                .to_curve()
+                .ok()?,
+            targets: CubicCardinalSpline::new_catmull_rom(targets)
+                .to_curve()
+                .ok()?,
+            duration: path.duration,
+            easing: path.easing,
+            elapsed: 0.0,
+        })
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Camera transform at the current point of the path.
+    fn transform(&self) -> Transform {
+        let progress = if self.duration > 0.0 {
+            self.elapsed / self.duration
+        } else {
+            1.0
+        };
+        let eased = self.easing.sample_clamped(progress);
+        // Each segment of the curves joins two consecutive waypoints.
+        let t = eased * self.positions.segments().len() as f32;
+        Transform::from_translation(self.positions.position(t))
+            .looking_at(self.targets.position(t), Vec3::Y)
+    }
+}
+
+fn start_camera_paths(
+    mut commands: Commands,
+    mut play_reader: MessageReader<PlayCameraPath>,
+    paths: Query<&CameraPath>,
+    camera: Single<Entity, With<Camera3d>>,
+) {
+    for play in play_reader.read() {
+        let Ok(path) = paths.get(play.path) else {
+            warn!("Cannot play missing camera path {}", play.path);
+            continue;
+        };
+        let Some(playback) = CameraPathPlayback::new(path) else {
+            warn!("Camera path {} needs at least two waypoints", play.path);
+            continue;
+        };
+        commands.entity(*camera).insert(playback);
+    }
+}
+
+/// Stops the current camera path with Escape.
+fn skip_camera_path(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    cameras: Query<Entity, With<CameraPathPlayback>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    for camera in &cameras {
+        commands.entity(camera).remove::<CameraPathPlayback>();
+    }
+}
+
+/// Moves cameras along their path, after they have been moved by their usual follow behavior.
+/// Once done, the camera smoothly catches up with the followed target again.
+fn fly_camera_paths(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(
+        Entity,
+        &mut CameraPathPlayback,
+        &mut Transform,
+        &mut GlobalTransform,
+    )>,
+) {
+    for (entity, mut playback, mut transform, mut global_transform) in &mut cameras {
+        playback.elapsed += time.delta_secs();
+        *transform = playback.transform();
+        *global_transform = GlobalTransform::from(*transform);
+
+        if playback.is_finished() {
+            commands.entity(entity).remove::<CameraPathPlayback>();
+        }
+    }
+}
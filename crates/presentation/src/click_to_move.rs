@@ -0,0 +1,103 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{prelude::*, transform::TransformSystems, window::PrimaryWindow};
+use bevy_rapier3d::prelude::*;
+use merlo_simulation::{colors::ColorScheme, controller::CharacterController, move_to::MoveTo};
+
+use crate::camera::cursor_ray;
+
+#[derive(Default)]
+pub struct ClickToMovePlugin;
+
+impl Plugin for ClickToMovePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ClickToMove>()
+            .add_systems(Update, (toggle_click_to_move, draw_move_target))
+            .add_systems(
+                PostUpdate,
+                move_to_on_left_click
+                    .after(TransformSystems::Propagate)
+                    .run_if(is_enabled),
+            );
+    }
+}
+
+/// RTS-style control of the local character, toggled with M: left-clicking the ground
+/// walks it there instead of taking control of the clicked character.
+#[derive(Resource, Debug, Default)]
+pub struct ClickToMove {
+    enabled: bool,
+    /// Last point clicked on the ground.
+    target: Option<Vec3>,
+}
+
+pub fn is_enabled(click_to_move: Res<ClickToMove>) -> bool {
+    click_to_move.enabled
+}
+
+fn toggle_click_to_move(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut click_to_move: ResMut<ClickToMove>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyM) {
+        click_to_move.enabled = !click_to_move.enabled;
+        click_to_move.target = None;
+    }
+}
+
+fn move_to_on_left_click(
+    buttons: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera3d>>,
+    rapier_context: ReadRapierContext,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    mut click_to_move: ResMut<ClickToMove>,
+    mut move_writer: MessageWriter<MoveTo>,
+) {
+    if !buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Ok(child) = controller.single() else {
+        return;
+    };
+    let (camera, camera_transform) = *camera;
+    let Some(ray) = cursor_ray(&window, camera, camera_transform) else {
+        return;
+    };
+
+    // The level geometry, falling back to the plane of the ground when missing.
+    let distance = rapier_context
+        .single()
+        .ok()
+        .and_then(|context| {
+            context.cast_ray(
+                ray.origin,
+                *ray.direction,
+                f32::MAX,
+                true,
+                QueryFilter::only_fixed().exclude_sensors(),
+            )
+        })
+        .map(|(_, distance)| distance)
+        .or_else(|| ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y)));
+    let Some(distance) = distance else {
+        return;
+    };
+
+    let target = ray.get_point(distance);
+    click_to_move.target = Some(target);
+    move_writer.write(MoveTo {
+        character: child.parent(),
+        target,
+    });
+}
+
+fn draw_move_target(click_to_move: Res<ClickToMove>, scheme: Res<ColorScheme>, mut gizmos: Gizmos) {
+    let Some(target) = click_to_move.target.filter(|_| click_to_move.enabled) else {
+        return;
+    };
+    let isometry = Isometry3d::new(target, Quat::from_rotation_x(std::f32::consts::FRAC_PI_2));
+    gizmos.circle(isometry, 0.3, scheme.selection);
+}
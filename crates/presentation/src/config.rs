@@ -0,0 +1,54 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{animation_lod::AnimationLodSettings, graphics::GraphicsQuality};
+
+/// File where the client settings are persisted across launches.
+pub const CONFIG_PATH: &str = "config.ron";
+
+#[derive(Default)]
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Config::load()).add_systems(
+            Last,
+            save_config.run_if(resource_changed::<Config>.and(not(resource_added::<Config>))),
+        );
+    }
+}
+
+/// Client settings, where missing entries of the file fall back to their default.
+#[derive(Resource, Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    pub graphics: GraphicsQuality,
+    pub animation_lod: AnimationLodSettings,
+}
+
+impl Config {
+    fn load() -> Self {
+        let Ok(text) = std::fs::read_to_string(CONFIG_PATH) else {
+            return Self::default();
+        };
+        ron::from_str(&text)
+            .inspect_err(|err| warn!("Ignoring invalid config {CONFIG_PATH}: {err}"))
+            .unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<()> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(CONFIG_PATH, text)?;
+        Ok(())
+    }
+}
+
+fn save_config(config: Res<Config>) {
+    if let Err(err) = config.save() {
+        error!("Cannot save config to {CONFIG_PATH}: {err}");
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{light::light_consts::lux, prelude::*};
+use merlo_model::TimeOfDay;
+
+#[derive(Default)]
+pub struct DayNightPlugin;
+
+impl Plugin for DayNightPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup)
+            .add_systems(Update, update_sun);
+    }
+}
+
+/// Marker for the directional light driven by the replicated [`TimeOfDay`].
+#[derive(Component)]
+pub struct Sun;
+
+const SUNRISE_TEMPERATURE: f32 = 2000.0;
+const NOON_TEMPERATURE: f32 = 6500.0;
+const NIGHT_AMBIENT: Color = Color::srgb(0.4, 0.5, 0.9);
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        Sun,
+        DirectionalLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::default(),
+    ));
+}
+
+fn update_sun(
+    times_of_day: Query<&TimeOfDay>,
+    mut sun: Single<(&mut DirectionalLight, &mut Transform), With<Sun>>,
+    mut ambient_light: ResMut<AmbientLight>,
+) {
+    let Some(time_of_day) = times_of_day.iter().next() else {
+        return;
+    };
+
+    let angle = time_of_day.sun_angle();
+    let (light, transform) = &mut *sun;
+    transform.rotation = Quat::from_rotation_x(-angle);
+
+    // How high the sun is, from zero at the horizon or below, to one at noon.
+    let daylight = angle.sin().max(0.0);
+    let temperature = SUNRISE_TEMPERATURE.lerp(NOON_TEMPERATURE, daylight);
+    light.color = color_temperature(temperature);
+    light.illuminance = lux::OVERCAST_DAY * daylight;
+
+    ambient_light.color = NIGHT_AMBIENT.mix(&light.color, daylight);
+    ambient_light.brightness = 20.0.lerp(200.0, daylight);
+}
+
+/// Approximates the color of a black body at the given temperature in Kelvin.
+fn color_temperature(kelvin: f32) -> Color {
+    let t = kelvin / 100.0;
+    let red = if t <= 66.0 {
+        255.0
+    } else {
+        329.699 * (t - 60.0).powf(-0.133_204_76)
+    };
+    let green = if t <= 66.0 {
+        99.470_8 * t.ln() - 161.119_57
+    } else {
+        288.122_16 * (t - 60.0).powf(-0.075_514_85)
+    };
+    let blue = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.517_73 * (t - 10.0).ln() - 305.044_8
+    };
+    Color::srgb(
+        red.clamp(0.0, 255.0) / 255.0,
+        green.clamp(0.0, 255.0) / 255.0,
+        blue.clamp(0.0, 255.0) / 255.0,
+    )
+}
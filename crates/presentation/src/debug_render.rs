@@ -0,0 +1,86 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use bevy_rapier3d::render::{DebugRenderContext, DebugRenderMode};
+use merlo_simulation::config::{ConfigFile, ConfigReloaded};
+
+use crate::locale::Localization;
+
+#[derive(Default)]
+pub struct PhysicsDebugPlugin;
+
+impl Plugin for PhysicsDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, apply_debug_config).add_systems(
+            Update,
+            (
+                toggle_debug_render,
+                apply_debug_config.run_if(on_message::<ConfigReloaded>),
+            ),
+        );
+    }
+}
+
+/// Turns physics debug rendering on or off as in the configuration.
+fn apply_debug_config(config: Res<ConfigFile>, debug_render: Option<ResMut<DebugRenderContext>>) {
+    if let Some(mut debug_render) = debug_render {
+        debug_render.enabled = config.debug.physics;
+    }
+}
+
+/// Flips physics debug rendering on and off with F4.
+fn toggle_debug_render(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut debug_render: ResMut<DebugRenderContext>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F4) {
+        debug_render.enabled = !debug_render.enabled;
+    }
+}
+
+/// Shows the physics debug rendering toggle and its per-category filters.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let labels = [
+        "debug.physics",
+        "debug.colliders",
+        "debug.contacts",
+        "debug.joints",
+    ]
+    .map(|key| localization.tr(key).to_owned());
+    let Some(mut debug_render) = world.get_resource_mut::<DebugRenderContext>() else {
+        return;
+    };
+
+    let mut enabled = debug_render.enabled;
+    if ui.checkbox(&mut enabled, &labels[0]).changed() {
+        debug_render.enabled = enabled;
+    }
+
+    ui.add_enabled_ui(enabled, |ui| {
+        ui.horizontal(|ui| {
+            let mut mode = debug_render.pipeline.mode;
+            mode_checkbox(ui, &mut mode, DebugRenderMode::COLLIDER_SHAPES, &labels[1]);
+            mode_checkbox(ui, &mut mode, DebugRenderMode::CONTACTS, &labels[2]);
+            mode_checkbox(ui, &mut mode, DebugRenderMode::JOINTS, &labels[3]);
+            if mode != debug_render.pipeline.mode {
+                debug_render.pipeline.mode = mode;
+            }
+        });
+    });
+}
+
+fn mode_checkbox(
+    ui: &mut egui::Ui,
+    mode: &mut DebugRenderMode,
+    flag: DebugRenderMode,
+    label: &str,
+) {
+    let mut checked = mode.contains(flag);
+    if ui.checkbox(&mut checked, label).changed() {
+        mode.set(flag, checked);
+    }
+}
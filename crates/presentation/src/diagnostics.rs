@@ -0,0 +1,166 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, Instant};
+
+use bevy::{
+    diagnostic::{
+        Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, EntityCountDiagnosticsPlugin,
+        FrameTimeDiagnosticsPlugin, RegisterDiagnostic,
+    },
+    ecs::schedule::ScheduleLabel,
+    prelude::*,
+};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use bevy_rapier3d::plugin::PhysicsSet;
+use merlo_simulation::physics::PhysicsSettings;
+
+/// Time spent in the rapier simulation steps of a frame, in milliseconds.
+pub const RAPIER_STEP_TIME: DiagnosticPath = DiagnosticPath::const_new("rapier_step_time");
+
+#[derive(Default)]
+pub struct DiagnosticsOverlayPlugin;
+
+impl Plugin for DiagnosticsOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin::default());
+        }
+
+        // Rapier steps in a fixed schedule, maybe several times per frame, or in PostUpdate.
+        let schedule = app
+            .world()
+            .get_resource::<PhysicsSettings>()
+            .map_or_else(|| PostUpdate.intern(), PhysicsSettings::schedule);
+        app.register_diagnostic(Diagnostic::new(RAPIER_STEP_TIME).with_suffix("ms"))
+            .init_resource::<DiagnosticsOverlay>()
+            .init_resource::<PhysicsStepTime>()
+            .add_systems(Update, toggle_overlay)
+            .add_systems(
+                schedule,
+                (
+                    start_physics_step.before(PhysicsSet::StepSimulation),
+                    measure_physics_step.after(PhysicsSet::StepSimulation),
+                ),
+            )
+            .add_systems(Last, report_physics_steps)
+            .add_systems(EguiPrimaryContextPass, show_overlay);
+    }
+}
+
+/// Whether the diagnostics overlay is shown, toggled with F3.
+#[derive(Resource, Default)]
+pub struct DiagnosticsOverlay {
+    pub visible: bool,
+}
+
+/// When the current rapier step started, and the time the steps of the frame took so far.
+#[derive(Resource, Default)]
+struct PhysicsStepTime {
+    start: Option<Instant>,
+    frame: Option<Duration>,
+}
+
+fn toggle_overlay(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<DiagnosticsOverlay>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F3) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+fn start_physics_step(mut time: ResMut<PhysicsStepTime>) {
+    time.start = Some(Instant::now());
+}
+
+fn measure_physics_step(mut time: ResMut<PhysicsStepTime>) {
+    let Some(start) = time.start.take() else {
+        return;
+    };
+    *time.frame.get_or_insert_default() += start.elapsed();
+}
+
+/// Measures the steps of the frame all together, skipping the frames without any.
+fn report_physics_steps(mut time: ResMut<PhysicsStepTime>, mut diagnostics: Diagnostics) {
+    let Some(frame) = time.frame.take() else {
+        return;
+    };
+    diagnostics.add_measurement(&RAPIER_STEP_TIME, || frame.as_secs_f64() * 1000.0);
+}
+
+fn show_overlay(
+    mut contexts: EguiContexts,
+    overlay: Res<DiagnosticsOverlay>,
+    diagnostics: Res<DiagnosticsStore>,
+) -> Result<()> {
+    if !overlay.visible {
+        return Ok(());
+    }
+
+    let smoothed = |path: &DiagnosticPath| {
+        diagnostics
+            .get(path)
+            .and_then(Diagnostic::smoothed)
+            .unwrap_or_default()
+    };
+    let fps = smoothed(&FrameTimeDiagnosticsPlugin::FPS);
+    let frame_time = smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME);
+    let entity_count = smoothed(&EntityCountDiagnosticsPlugin::ENTITY_COUNT);
+    let step_time = smoothed(&RAPIER_STEP_TIME);
+
+    egui::Area::new(egui::Id::new("diagnostics_overlay"))
+        .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-8.0, 8.0))
+        .interactable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.monospace(format!("FPS         {fps:>8.1}"));
+                ui.monospace(format!("Frame time  {frame_time:>6.2}ms"));
+                ui.monospace(format!("Entities    {entity_count:>8.0}"));
+                ui.monospace(format!("Rapier step {step_time:>6.2}ms"));
+                if let Some(frame_times) = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+                {
+                    frame_time_graph(ui, frame_times);
+                }
+            });
+        });
+
+    Ok(())
+}
+
+/// Paints the frame time history as a line graph, with a reference line at 60 FPS.
+fn frame_time_graph(ui: &mut egui::Ui, frame_times: &Diagnostic) {
+    const MAX_FRAME_TIME: f32 = 50.0;
+    const TARGET_FRAME_TIME: f32 = 1000.0 / 60.0;
+
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(200.0, 60.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(128));
+
+    let to_y = |frame_time: f32| {
+        rect.bottom() - rect.height() * (frame_time / MAX_FRAME_TIME).clamp(0.0, 1.0)
+    };
+
+    let target_y = to_y(TARGET_FRAME_TIME);
+    painter.hline(
+        rect.x_range(),
+        target_y,
+        egui::Stroke::new(1.0, egui::Color32::DARK_GREEN),
+    );
+
+    let len = frame_times.history_len().max(2) as f32;
+    let step = rect.width() / (len - 1.0);
+    let points = frame_times
+        .values()
+        .enumerate()
+        .map(|(i, value)| egui::pos2(rect.left() + i as f32 * step, to_y(*value as f32)))
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.0, egui::Color32::LIGHT_YELLOW),
+    ));
+}
@@ -0,0 +1,193 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::{egui, input::EguiWantsInput};
+use bevy_replicon::prelude::*;
+use merlo_model::{Doodad, Health, Lamp, SpawnPoint};
+use merlo_simulation::{
+    edit::{EditRequest, Property},
+    spawn::Archetype,
+};
+
+use crate::locale::Localization;
+
+#[derive(Default)]
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Selection>()
+            .add_systems(Update, edit_shortcuts);
+    }
+}
+
+/// The entity edited in the palette tab, and the property being dragged, which is only
+/// sent to the server once the drag is over, so that it is undone in one go.
+#[derive(Resource, Default)]
+pub struct Selection {
+    entity: Option<Entity>,
+    dragging: Option<Property>,
+}
+
+/// Undoes the last edit with Ctrl+Z, redoes it with Ctrl+Shift+Z, and duplicates the
+/// selected entity with Ctrl+D, unless typing.
+fn edit_shortcuts(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    egui_wants_input: Res<EguiWantsInput>,
+    selection: Res<Selection>,
+    mut edit_writer: MessageWriter<EditRequest>,
+) {
+    if egui_wants_input.wants_keyboard_input()
+        || !keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+    {
+        return;
+    }
+    if keyboard_input.just_pressed(KeyCode::KeyZ) {
+        if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            edit_writer.write(EditRequest::Redo);
+        } else {
+            edit_writer.write(EditRequest::Undo);
+        }
+    }
+    if keyboard_input.just_pressed(KeyCode::KeyD)
+        && let Some(entity) = selection.entity
+    {
+        edit_writer.write(EditRequest::Duplicate { entity });
+    }
+}
+
+/// Whether the edit of a property through `responses` is over, or still being dragged.
+fn edit_state(responses: &[egui::Response]) -> Option<bool> {
+    let done = responses
+        .iter()
+        .any(|response| response.drag_stopped() || response.changed() && !response.dragged());
+    if done {
+        Some(true)
+    } else if responses.iter().any(egui::Response::dragged) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Edits the properties of the selected entity through the server.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let [select, position, health, duplicate, delete, undo, redo] = [
+        "editor.select",
+        "editor.position",
+        "editor.health",
+        "editor.duplicate",
+        "editor.delete",
+        "editor.undo",
+        "editor.redo",
+    ]
+    .map(|key| localization.tr(key).to_owned());
+
+    let mut edits = Vec::new();
+    ui.horizontal(|ui| {
+        if ui.button(undo).clicked() {
+            edits.push(EditRequest::Undo);
+        }
+        if ui.button(redo).clicked() {
+            edits.push(EditRequest::Redo);
+        }
+    });
+
+    let mut entities: Vec<_> = world
+        .query_filtered::<(Entity, Option<&Doodad>, Has<Lamp>, Has<SpawnPoint>), With<Replicated>>()
+        .iter(world)
+        .filter_map(|(entity, doodad, lamp, spawn_point)| {
+            let archetype = match (doodad, lamp, spawn_point) {
+                (Some(&doodad), ..) => Archetype::Doodad(doodad),
+                (_, true, _) => Archetype::Lamp,
+                (_, _, true) => Archetype::SpawnPoint,
+                _ => return None,
+            };
+            Some((entity, format!("{} {entity}", archetype.name())))
+        })
+        .collect();
+    entities.sort_by_key(|&(entity, _)| entity);
+
+    world.resource_scope(|world, mut selection: Mut<Selection>| {
+        let selected = selection
+            .entity
+            .and_then(|selected| entities.iter().find(|&&(entity, _)| entity == selected));
+        let selected_text = selected.map_or(select.as_str(), |(_, label)| label.as_str());
+        let mut entity = selected.map(|&(entity, _)| entity);
+        egui::ComboBox::from_id_salt("editor_selection")
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                for (candidate, label) in &entities {
+                    ui.selectable_value(&mut entity, Some(*candidate), label);
+                }
+            });
+        if entity != selection.entity {
+            selection.entity = entity;
+            selection.dragging = None;
+        }
+        let Some(entity) = entity else {
+            return;
+        };
+
+        let (mut transform, mut current_health) = match world
+            .query::<(&Transform, Option<&Health>)>()
+            .get(world, entity)
+        {
+            Ok((transform, health)) => (*transform, health.copied()),
+            Err(_) => return,
+        };
+        match selection.dragging {
+            Some(Property::Transform(dragged)) => transform = dragged,
+            Some(Property::Health(dragged)) => current_health = Some(dragged),
+            None => {}
+        }
+
+        let mut edited = Vec::new();
+        ui.horizontal(|ui| {
+            ui.label(&position);
+            let responses: Vec<_> = transform
+                .translation
+                .as_mut()
+                .iter_mut()
+                .map(|value| ui.add(egui::DragValue::new(value).speed(0.1)))
+                .collect();
+            edited.push((edit_state(&responses), Property::Transform(transform)));
+        });
+        if let Some(mut current_health) = current_health {
+            ui.horizontal(|ui| {
+                ui.label(&health);
+                let response = ui.add(
+                    egui::DragValue::new(&mut current_health.current)
+                        .range(0.0..=current_health.max),
+                );
+                edited.push((edit_state(&[response]), Property::Health(current_health)));
+            });
+        }
+        for (state, property) in edited {
+            match state {
+                Some(true) => {
+                    selection.dragging = None;
+                    edits.push(EditRequest::Set { entity, property });
+                }
+                Some(false) => selection.dragging = Some(property),
+                None => {}
+            }
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button(&duplicate).clicked() {
+                edits.push(EditRequest::Duplicate { entity });
+            }
+            if ui.button(&delete).clicked() {
+                selection.entity = None;
+                edits.push(EditRequest::Delete { entity });
+            }
+        });
+    });
+
+    // Edits go through the server so that they are replicated, and can be undone.
+    world.write_message_batch(edits);
+}
@@ -0,0 +1,42 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use merlo_simulation::FatalError;
+
+use crate::locale::Localization;
+
+#[derive(Default)]
+pub struct FatalErrorPlugin;
+
+impl Plugin for FatalErrorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            EguiPrimaryContextPass,
+            show_fatal_error.run_if(resource_exists::<FatalError>),
+        );
+    }
+}
+
+/// Tells why the game cannot start, exiting with the code of the error once read.
+fn show_fatal_error(
+    mut contexts: EguiContexts,
+    error: Res<FatalError>,
+    localization: Res<Localization>,
+    mut exit_writer: MessageWriter<AppExit>,
+) -> Result<()> {
+    egui::Window::new(localization.tr("error.title"))
+        .id(egui::Id::new("fatal_error"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .collapsible(false)
+        .resizable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            ui.label(&error.message);
+            if ui.button(localization.tr("error.quit")).clicked() {
+                exit_writer.write(AppExit::from_code(error.code));
+            }
+        });
+    Ok(())
+}
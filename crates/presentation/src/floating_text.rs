@@ -0,0 +1,130 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_model::DamageKind;
+use merlo_simulation::damage::DamageDealt;
+
+#[derive(Default)]
+pub struct FloatingTextPlugin;
+
+impl Plugin for FloatingTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spawn_damage_numbers).add_systems(
+            PostUpdate,
+            update_floating_text.after(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// Screen-space text anchored to a world position, rising and fading until it expires.
+#[derive(Component, Debug, Clone)]
+pub struct FloatingText {
+    pub anchor: Vec3,
+    pub rise_speed: f32,
+    pub lifetime: f32,
+    pub color: Color,
+    age: f32,
+}
+
+impl FloatingText {
+    pub fn new(anchor: Vec3, color: Color) -> Self {
+        Self {
+            anchor,
+            rise_speed: 1.0,
+            lifetime: 1.0,
+            color,
+            age: 0.0,
+        }
+    }
+}
+
+/// Spawns a floating text rendered by the given camera.
+pub fn spawn_floating_text(
+    commands: &mut Commands,
+    camera: Entity,
+    text: impl Into<String>,
+    floating_text: FloatingText,
+) {
+    commands.spawn((
+        Text::new(text),
+        TextFont::from_font_size(24.0),
+        TextColor(floating_text.color),
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        UiTargetCamera(camera),
+        Visibility::Hidden,
+        floating_text,
+    ));
+}
+
+fn update_floating_text(
+    mut commands: Commands,
+    time: Res<Time>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut texts: Query<(
+        Entity,
+        &mut FloatingText,
+        &UiTargetCamera,
+        &mut Node,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut text, target, mut node, mut color, mut visibility) in &mut texts {
+        text.age += dt;
+        if text.age >= text.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        text.anchor.y += text.rise_speed * dt;
+
+        let position = cameras
+            .get(target.entity())
+            .ok()
+            .and_then(|(camera, transform)| camera.world_to_viewport(transform, text.anchor).ok());
+        let Some(position) = position else {
+            // Behind the camera or outside its viewport.
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        *visibility = Visibility::Inherited;
+        node.left = Val::Px(position.x);
+        node.top = Val::Px(position.y);
+        color.0 = text.color.with_alpha(1.0 - text.age / text.lifetime);
+    }
+}
+
+fn damage_color(kind: DamageKind) -> Color {
+    match kind {
+        DamageKind::Physical => Color::srgb(1.0, 0.9, 0.8),
+        DamageKind::Fire => Color::srgb(1.0, 0.5, 0.1),
+        DamageKind::Fall => Color::srgb(0.7, 0.7, 1.0),
+    }
+}
+
+/// Shows a floating number above every damaged entity.
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    mut damage_reader: MessageReader<DamageDealt>,
+    targets: Query<&GlobalTransform>,
+    camera: Single<Entity, With<Camera3d>>,
+) {
+    for damage in damage_reader.read() {
+        let Ok(target) = targets.get(damage.target) else {
+            continue;
+        };
+        let anchor = target.translation() + Vec3::Y * 2.0;
+        spawn_floating_text(
+            &mut commands,
+            *camera,
+            format!("{:.0}", damage.amount),
+            FloatingText::new(anchor, damage_color(damage.kind)),
+        );
+    }
+}
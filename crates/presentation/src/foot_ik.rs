@@ -0,0 +1,280 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    animation::AnimationTarget, app::AnimationSystems, prelude::*, transform::TransformSystems,
+    transform::helper::TransformHelper,
+};
+use bevy_rapier3d::prelude::*;
+use merlo_model::Dead;
+use merlo_simulation::controller::{CharacterDimensions, CharacterMovementState};
+
+use crate::{
+    animation::{Animations, find_character},
+    animation_set::LegBones,
+    lean, root_motion,
+};
+
+#[derive(Default)]
+pub struct FootIkPlugin;
+
+impl Plugin for FootIkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, find_leg_bones).add_systems(
+            PostUpdate,
+            (solve_foot_ik, apply_foot_ik)
+                .chain()
+                .after(AnimationSystems)
+                .after(root_motion::extract_root_motion)
+                .after(lean::apply_lean)
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// How far above the soles the ground is probed, to find steps going up.
+const PROBE_HEIGHT: f32 = 0.5;
+/// How far a foot can reach below the soles, to find steps going down.
+const MAX_REACH_DOWN: f32 = 0.5;
+/// How quickly feet and pelvis follow changes of the ground.
+const SMOOTHING: f32 = 15.0;
+
+/// Bones of a character adjusted so that its feet plant on uneven ground.
+#[derive(Component)]
+struct FootIk {
+    character: Entity,
+    pelvis: Entity,
+    /// Upper, lower and foot bones of the left and right legs.
+    legs: [[Entity; 3]; 2],
+    /// Vertical offsets in world space, smoothed over time.
+    pelvis_offset: f32,
+    foot_offsets: [f32; 2],
+    /// Pelvis translation written last frame, to tell whether animation overwrote it.
+    written_pelvis: Option<(Vec3, Vec3)>,
+    solution: Option<FootIkSolution>,
+}
+
+/// Local transforms computed by [`solve_foot_ik`], applied by [`apply_foot_ik`].
+struct FootIkSolution {
+    pelvis_translation: Vec3,
+    /// Rotations of the upper and lower bones of each leg.
+    rotations: [Option<[Quat; 2]>; 2],
+}
+
+fn find_leg_bones(
+    mut commands: Commands,
+    players: Query<(Entity, &Animations), Added<Animations>>,
+    bones: Query<(Entity, &AnimationTarget, &Name)>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    parents: Query<&ChildOf>,
+) {
+    for (player, animations) in &players {
+        let Some(names) = animations.foot_ik() else {
+            continue;
+        };
+        let find = |name: &str| {
+            bones
+                .iter()
+                .find(|(_, target, bone_name)| {
+                    target.player == player && bone_name.as_str() == name
+                })
+                .map(|(entity, _, _)| entity)
+        };
+        let find_leg =
+            |leg: &LegBones| Some([find(&leg.upper)?, find(&leg.lower)?, find(&leg.foot)?]);
+
+        let (Some(pelvis), Some(left), Some(right)) = (
+            find(&names.pelvis),
+            find_leg(&names.left),
+            find_leg(&names.right),
+        ) else {
+            continue;
+        };
+        let Some((character, _, _)) = find_character(player, &parents, &characters) else {
+            continue;
+        };
+        commands.entity(player).insert(FootIk {
+            character,
+            pelvis,
+            legs: [left, right],
+            pelvis_offset: 0.0,
+            foot_offsets: [0.0; 2],
+            written_pelvis: None,
+            solution: None,
+        });
+    }
+}
+
+/// Probes the ground below each foot, lowers the pelvis so that the lowest foot can reach
+/// it, and bends each leg so that its foot lands on the ground.
+fn solve_foot_ik(
+    time: Res<Time>,
+    rapier_context: ReadRapierContext,
+    helper: TransformHelper,
+    transforms: Query<&Transform>,
+    parents: Query<&ChildOf>,
+    characters: Query<(&CharacterMovementState, &CharacterDimensions, Has<Dead>)>,
+    mut foot_iks: Query<&mut FootIk>,
+) {
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    let global = |entity: Entity| helper.compute_global_transform(entity).ok();
+    let parent_global = |entity: Entity| global(parents.get(entity).ok()?.parent());
+    let blend = 1.0 - (-SMOOTHING * time.delta_secs()).exp();
+
+    for mut foot_ik in &mut foot_iks {
+        foot_ik.solution = None;
+        let (Ok((state, dimensions, dead)), Some(character)) =
+            (characters.get(foot_ik.character), global(foot_ik.character))
+        else {
+            continue;
+        };
+        let ground = character.translation().y - dimensions.origin_to_foot();
+        let filter = QueryFilter::default()
+            .exclude_collider(foot_ik.character)
+            .exclude_sensors();
+
+        // Feet keep their animation in the air or when dead, then blend back onto the ground.
+        let mut target_offsets = [0.0; 2];
+        for (leg, target_offset) in foot_ik.legs.iter().zip(&mut target_offsets) {
+            let Some(foot) = global(leg[2]) else {
+                continue;
+            };
+            let origin = foot.translation().with_y(ground + PROBE_HEIGHT);
+            if state.grounded
+                && !dead
+                && let Some((_, distance)) = rapier_context.cast_ray(
+                    origin,
+                    Vec3::NEG_Y,
+                    PROBE_HEIGHT + MAX_REACH_DOWN,
+                    true,
+                    filter,
+                )
+            {
+                *target_offset = PROBE_HEIGHT - distance;
+            }
+        }
+        let target_pelvis_offset = target_offsets[0].min(target_offsets[1]).min(0.0);
+        foot_ik.pelvis_offset = foot_ik.pelvis_offset.lerp(target_pelvis_offset, blend);
+        for (offset, target) in foot_ik.foot_offsets.iter_mut().zip(target_offsets) {
+            *offset = offset.lerp(target, blend);
+        }
+
+        let (Ok(pelvis), Some(pelvis_parent)) = (
+            transforms.get(foot_ik.pelvis),
+            parent_global(foot_ik.pelvis),
+        ) else {
+            continue;
+        };
+        // Without animated translation, the pelvis still holds the offset of last frame.
+        let base = match foot_ik.written_pelvis {
+            Some((written, offset)) if written == pelvis.translation => written - offset,
+            _ => pelvis.translation,
+        };
+        let pelvis_drop = Vec3::Y * foot_ik.pelvis_offset;
+        let local_drop = pelvis_parent
+            .affine()
+            .inverse()
+            .transform_vector3(pelvis_drop);
+        let pelvis_translation = base + local_drop;
+        foot_ik.written_pelvis = Some((pelvis_translation, local_drop));
+        // The legs are then moved by the difference with the offset they already hold.
+        let held_drop = pelvis_parent
+            .affine()
+            .transform_vector3(pelvis.translation - base);
+        let extra_drop = pelvis_drop - held_drop;
+
+        let mut rotations = [None; 2];
+        for (side, leg) in foot_ik.legs.iter().enumerate() {
+            let (Some(upper_parent), Some(upper), Some(lower), Some(foot)) = (
+                parent_global(leg[0]),
+                global(leg[0]),
+                global(leg[1]),
+                global(leg[2]),
+            ) else {
+                continue;
+            };
+            let animated_foot = foot.translation() - held_drop;
+            let target = animated_foot + Vec3::Y * foot_ik.foot_offsets[side];
+            let bend_axis = character.rotation() * Vec3::X;
+            let (hip, knee) = solve_two_bones(
+                upper.translation() + extra_drop,
+                lower.translation() + extra_drop,
+                foot.translation() + extra_drop,
+                target,
+                bend_axis,
+            );
+
+            let upper_rotation = hip * upper.rotation();
+            let lower_rotation = hip * knee * lower.rotation();
+            rotations[side] = Some([
+                upper_parent.rotation().inverse() * upper_rotation,
+                upper_rotation.inverse() * lower_rotation,
+            ]);
+        }
+
+        foot_ik.solution = Some(FootIkSolution {
+            pelvis_translation,
+            rotations,
+        });
+    }
+}
+
+/// Returns the world rotations of the hip and of the knee which bring the end of the
+/// chain `hip`, `knee`, `end` to the `target`, bending around `bend_axis` when straight.
+fn solve_two_bones(
+    hip: Vec3,
+    knee: Vec3,
+    end: Vec3,
+    target: Vec3,
+    bend_axis: Vec3,
+) -> (Quat, Quat) {
+    let upper_length = hip.distance(knee);
+    let lower_length = knee.distance(end);
+    let reach = hip.distance(target).clamp(
+        (upper_length - lower_length).abs() + 0.001,
+        upper_length + lower_length - 0.001,
+    );
+
+    // Law of cosines for the angle at the knee.
+    let to_hip = hip - knee;
+    let to_end = end - knee;
+    let current_angle = to_hip.angle_between(to_end);
+    let cos_angle = (upper_length.powi(2) + lower_length.powi(2) - reach.powi(2))
+        / (2.0 * upper_length * lower_length);
+    let target_angle = cos_angle.clamp(-1.0, 1.0).acos();
+    let axis = to_hip.cross(to_end).try_normalize().unwrap_or(bend_axis);
+    let knee_rotation = Quat::from_axis_angle(axis, target_angle - current_angle);
+
+    let bent_end = knee + knee_rotation * to_end;
+    let (Some(from), Some(to)) = (
+        (bent_end - hip).try_normalize(),
+        (target - hip).try_normalize(),
+    ) else {
+        return (Quat::IDENTITY, knee_rotation);
+    };
+    (Quat::from_rotation_arc(from, to), knee_rotation)
+}
+
+fn apply_foot_ik(foot_iks: Query<&FootIk>, mut transforms: Query<&mut Transform>) {
+    for foot_ik in &foot_iks {
+        let Some(solution) = &foot_ik.solution else {
+            continue;
+        };
+        if let Ok(mut pelvis) = transforms.get_mut(foot_ik.pelvis) {
+            pelvis.translation = solution.pelvis_translation;
+        }
+        for (leg, rotations) in foot_ik.legs.iter().zip(solution.rotations) {
+            let Some(rotations) = rotations else {
+                continue;
+            };
+            for (bone, rotation) in leg.iter().zip(rotations) {
+                if let Ok(mut transform) = transforms.get_mut(*bone) {
+                    transform.rotation = rotation;
+                }
+            }
+        }
+    }
+}
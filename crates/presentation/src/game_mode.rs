@@ -0,0 +1,141 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use merlo_model::{GameModeKind, It, Match, RoundPhase};
+use merlo_simulation::{
+    colors::ColorScheme,
+    controller::CharacterController,
+    game_mode::{self, PhaseChanged},
+};
+
+use crate::locale::Localization;
+
+#[derive(Default)]
+pub struct GameModeHudPlugin;
+
+impl Plugin for GameModeHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(EguiPrimaryContextPass, show_match_hud)
+            .add_systems(Update, draw_it_marker);
+    }
+}
+
+/// Height of the marker above the character who is it.
+const IT_MARKER_HEIGHT: f32 = 1.2;
+/// Seconds the start of a round is announced for.
+const BANNER_DURATION: f32 = 2.0;
+
+/// Shows the game mode, the round, and the time left at the top of the screen, announcing
+/// when the round starts and who won once it is over. Nothing is shown in the sandbox.
+#[allow(clippy::too_many_arguments)]
+fn show_match_hud(
+    mut contexts: EguiContexts,
+    time: Res<Time>,
+    mut phase_reader: MessageReader<PhaseChanged>,
+    mut banner: Local<Option<Timer>>,
+    localization: Res<Localization>,
+    scheme: Res<ColorScheme>,
+    matches: Query<&Match>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    its: Query<(), With<It>>,
+) -> Result<()> {
+    if phase_reader
+        .read()
+        .any(|changed| changed.phase == RoundPhase::Active)
+    {
+        *banner = Some(Timer::from_seconds(BANNER_DURATION, TimerMode::Once));
+    }
+    if let Some(timer) = banner.as_mut()
+        && timer.tick(time.delta()).is_finished()
+    {
+        *banner = None;
+    }
+
+    let Ok(game) = matches.single() else {
+        return Ok(());
+    };
+    if game.mode == GameModeKind::Sandbox {
+        return Ok(());
+    }
+    let rules = game_mode::rules(game.mode);
+    let mode_key = format!("mode.{}", rules.name());
+    let local_is_it = controller
+        .single()
+        .is_ok_and(|character| its.contains(character.parent()));
+
+    egui::Area::new(egui::Id::new("match_hud"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 8.0))
+        .interactable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.strong(format!(
+                        "{} - {} {}",
+                        localization.tr(&mode_key),
+                        localization.tr("mode.round"),
+                        game.round
+                    ));
+                    match game.phase {
+                        RoundPhase::Warmup => {
+                            ui.label(localization.tr("mode.warmup"));
+                            if let Some(seconds) = game.seconds_left {
+                                ui.heading(seconds.to_string());
+                            }
+                        }
+                        RoundPhase::Active => {
+                            if banner.is_some() {
+                                ui.heading(localization.tr("mode.fight"));
+                            }
+                            if let Some(limit) = rules.score_limit() {
+                                ui.label(format!("{} {limit}", localization.tr("mode.first_to")));
+                            }
+                            if let Some(seconds) = game.seconds_left {
+                                ui.monospace(format!("{}:{:02}", seconds / 60, seconds % 60));
+                            }
+                            if local_is_it {
+                                let [r, g, b, _] = scheme.red_team.to_srgba().to_u8_array();
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(r, g, b),
+                                    localization.tr("mode.you_are_it"),
+                                );
+                            }
+                        }
+                        RoundPhase::PostRound => {
+                            match &game.winner {
+                                Some(winner) => ui.heading(format!(
+                                    "{} {winner}",
+                                    localization.tr("mode.winner")
+                                )),
+                                None => ui.heading(localization.tr("mode.draw")),
+                            };
+                            if let Some(seconds) = game.seconds_left {
+                                ui.label(format!(
+                                    "{} {seconds}",
+                                    localization.tr("mode.next_round")
+                                ));
+                            }
+                        }
+                    }
+                });
+            });
+        });
+
+    Ok(())
+}
+
+/// Circles the head of whoever is it, so that everyone knows whom to run from.
+fn draw_it_marker(
+    scheme: Res<ColorScheme>,
+    its: Query<&GlobalTransform, With<It>>,
+    mut gizmos: Gizmos,
+) {
+    for transform in &its {
+        let position = transform.translation() + Vec3::Y * IT_MARKER_HEIGHT;
+        let isometry =
+            Isometry3d::new(position, Quat::from_rotation_x(std::f32::consts::FRAC_PI_2));
+        gizmos.circle(isometry, 0.4, scheme.red_team);
+    }
+}
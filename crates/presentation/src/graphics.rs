@@ -0,0 +1,149 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    core_pipeline::tonemapping::Tonemapping,
+    light::{CascadeShadowConfigBuilder, DirectionalLightShadowMap, PointLightShadowMap},
+    post_process::bloom::Bloom,
+    prelude::*,
+    render::view::Hdr,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, day_night::Sun, locale::Localization};
+
+#[derive(Default)]
+pub struct GraphicsPlugin;
+
+impl Plugin for GraphicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_graphics_quality.run_if(resource_changed::<Config>),
+        );
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsQuality {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+/// Rendering settings configured by a [`GraphicsQuality`].
+#[derive(Debug, Clone, Copy)]
+pub struct GraphicsPreset {
+    pub shadow_map_size: usize,
+    pub shadow_cascades: usize,
+    pub msaa: Msaa,
+    pub bloom: bool,
+    pub tonemapping: Tonemapping,
+}
+
+impl GraphicsQuality {
+    pub const ALL: [GraphicsQuality; 3] = [
+        GraphicsQuality::Low,
+        GraphicsQuality::Medium,
+        GraphicsQuality::High,
+    ];
+
+    /// Localization key of the quality name.
+    pub fn key(self) -> &'static str {
+        match self {
+            GraphicsQuality::Low => "graphics.low",
+            GraphicsQuality::Medium => "graphics.medium",
+            GraphicsQuality::High => "graphics.high",
+        }
+    }
+
+    pub fn preset(self) -> GraphicsPreset {
+        match self {
+            GraphicsQuality::Low => GraphicsPreset {
+                shadow_map_size: 1024,
+                shadow_cascades: 1,
+                msaa: Msaa::Off,
+                bloom: false,
+                tonemapping: Tonemapping::Reinhard,
+            },
+            GraphicsQuality::Medium => GraphicsPreset {
+                shadow_map_size: 2048,
+                shadow_cascades: 2,
+                msaa: Msaa::Sample4,
+                bloom: false,
+                tonemapping: Tonemapping::TonyMcMapface,
+            },
+            GraphicsQuality::High => GraphicsPreset {
+                shadow_map_size: 4096,
+                shadow_cascades: 4,
+                msaa: Msaa::Sample4,
+                bloom: true,
+                tonemapping: Tonemapping::TonyMcMapface,
+            },
+        }
+    }
+}
+
+fn apply_graphics_quality(
+    mut commands: Commands,
+    config: Res<Config>,
+    cameras: Query<Entity, With<Camera3d>>,
+    suns: Query<Entity, With<Sun>>,
+) {
+    let preset = config.graphics.preset();
+
+    commands.insert_resource(DirectionalLightShadowMap {
+        size: preset.shadow_map_size,
+    });
+    commands.insert_resource(PointLightShadowMap {
+        size: preset.shadow_map_size / 2,
+    });
+
+    for sun in &suns {
+        commands.entity(sun).insert(
+            CascadeShadowConfigBuilder {
+                num_cascades: preset.shadow_cascades,
+                ..default()
+            }
+            .build(),
+        );
+    }
+
+    for camera in &cameras {
+        let mut camera = commands.entity(camera);
+        camera.insert((preset.msaa, preset.tonemapping));
+        if preset.bloom {
+            camera.insert(Bloom::NATURAL);
+        } else {
+            camera.remove::<(Bloom, Hdr)>();
+        }
+    }
+}
+
+/// Shows a graphics quality picker.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let label = localization.tr("graphics.quality").to_owned();
+    let names = GraphicsQuality::ALL.map(|quality| localization.tr(quality.key()).to_owned());
+
+    let mut quality = world.resource::<Config>().graphics;
+    let selected = GraphicsQuality::ALL
+        .iter()
+        .position(|option| *option == quality)
+        .unwrap_or_default();
+    egui::ComboBox::from_label(label)
+        .selected_text(&names[selected])
+        .show_ui(ui, |ui| {
+            for (option, name) in GraphicsQuality::ALL.into_iter().zip(&names) {
+                ui.selectable_value(&mut quality, option, name);
+            }
+        });
+
+    let mut config = world.resource_mut::<Config>();
+    if config.graphics != quality {
+        config.graphics = quality;
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    color::palettes::css::{BLUE, RED},
+    prelude::*,
+    transform::TransformSystems,
+    window::PrimaryWindow,
+};
+use bevy_egui::egui;
+
+use crate::{camera::cursor_ray, locale::Localization, palette::GroundPick};
+
+#[derive(Default)]
+pub struct GridGizmosPlugin;
+
+impl Plugin for GridGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GridGizmos>()
+            .init_resource::<GroundCursor>()
+            .add_systems(
+                PostUpdate,
+                update_ground_cursor.after(TransformSystems::Propagate),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_grid,
+                    draw_grid.run_if(|gizmos: Res<GridGizmos>| gizmos.grid),
+                    draw_axes.run_if(|gizmos: Res<GridGizmos>| gizmos.axes),
+                    draw_measurement.run_if(|gizmos: Res<GridGizmos>| gizmos.measure),
+                ),
+            );
+    }
+}
+
+/// Number of one meter cells along each side of the grid, covering the whole base.
+const GRID_CELLS: u32 = 48;
+/// Height of the top of the base, where the grid is drawn to avoid z-fighting with it.
+const GRID_HEIGHT: f32 = 0.051;
+
+/// Which level editing gizmos are drawn.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct GridGizmos {
+    /// Ground grid with one meter cells, toggled with F5.
+    pub grid: bool,
+    /// World axes at the origin.
+    pub axes: bool,
+    /// Distance from the picked ground position to the one under the cursor.
+    pub measure: bool,
+}
+
+/// The ground position under the cursor, if any.
+#[derive(Resource, Default)]
+struct GroundCursor(Option<Vec3>);
+
+fn toggle_grid(keyboard_input: Res<ButtonInput<KeyCode>>, mut gizmos: ResMut<GridGizmos>) {
+    if keyboard_input.just_pressed(KeyCode::F5) {
+        gizmos.grid = !gizmos.grid;
+    }
+}
+
+fn update_ground_cursor(
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut ground_cursor: ResMut<GroundCursor>,
+) {
+    let (camera, camera_transform) = *camera;
+    ground_cursor.0 = cursor_ray(&window, camera, camera_transform).and_then(|ray| {
+        let distance = ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y))?;
+        Some(ray.get_point(distance))
+    });
+}
+
+fn draw_grid(mut gizmos: Gizmos) {
+    // The grid is built on the XY plane, rotate it onto the ground.
+    let isometry = Isometry3d::new(
+        Vec3::Y * GRID_HEIGHT,
+        Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+    );
+    gizmos
+        .grid(
+            isometry,
+            UVec2::splat(GRID_CELLS),
+            Vec2::ONE,
+            Color::srgba(1.0, 1.0, 1.0, 0.15),
+        )
+        .outer_edges();
+}
+
+fn draw_axes(mut gizmos: Gizmos) {
+    gizmos.axes(Transform::from_translation(Vec3::Y * GRID_HEIGHT), 1.0);
+}
+
+/// Draws the measured segment with its X and Z legs, and a tick every meter.
+fn draw_measurement(
+    ground_pick: Res<GroundPick>,
+    ground_cursor: Res<GroundCursor>,
+    mut gizmos: Gizmos,
+) {
+    let (Some(start), Some(end)) = (ground_pick.position(), ground_cursor.0) else {
+        return;
+    };
+    let corner = Vec3::new(end.x, start.y, start.z);
+    gizmos.line(start, corner, RED);
+    gizmos.line(corner, end, BLUE);
+    gizmos.line(start, end, Color::WHITE);
+
+    let length = start.distance(end);
+    let direction = (end - start).normalize_or_zero();
+    for meter in 1..=length as u32 {
+        let tick = start + direction * meter as f32;
+        gizmos.line(tick, tick + Vec3::Y * 0.2, Color::WHITE);
+    }
+}
+
+/// Shows the gizmo toggles, and the measured distance when measuring.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let labels = ["grid.grid", "grid.axes", "grid.measure", "grid.distance"]
+        .map(|key| localization.tr(key).to_owned());
+
+    let mut gizmos = *world.resource::<GridGizmos>();
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut gizmos.grid, &labels[0]);
+        ui.checkbox(&mut gizmos.axes, &labels[1]);
+        ui.checkbox(&mut gizmos.measure, &labels[2]);
+    });
+
+    let start = world.resource::<GroundPick>().position();
+    let end = world.resource::<GroundCursor>().0;
+    if let (true, Some(start), Some(end)) = (gizmos.measure, start, end) {
+        let delta = end - start;
+        ui.label(format!(
+            "{} {:.2} m (Δx {:.2}, Δz {:.2})",
+            labels[3],
+            delta.length(),
+            delta.x,
+            delta.z
+        ));
+    }
+
+    world.resource_mut::<GridGizmos>().set_if_neq(gizmos);
+}
@@ -0,0 +1,92 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::f32::consts::{PI, TAU};
+
+use bevy::prelude::*;
+use merlo_simulation::controller::{CharacterController, CharacterMovementState};
+
+use crate::{
+    accessibility::AccessibilitySettings,
+    audio::steps_per_second,
+    camera::{self, CameraMode},
+    cinematic::CameraPathPlayback,
+};
+
+#[derive(Default)]
+pub struct HeadBobPlugin;
+
+impl Plugin for HeadBobPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeadBob>()
+            .add_systems(PostUpdate, apply_head_bob.after(camera::follow_pivot));
+    }
+}
+
+/// Vertical travel of the camera for every step, in meters.
+const BOB_HEIGHT: f32 = 0.04;
+/// Sideways sway of the camera, a full cycle every two steps.
+const BOB_SWAY: f32 = 0.025;
+/// How far the camera dips when landing.
+const LANDING_DIP: f32 = 0.12;
+/// How quickly the bob fades in and out, and the camera recovers from a landing.
+const RECOVERY: f32 = 8.0;
+
+/// State of the procedural first person camera motion.
+#[derive(Resource, Default)]
+struct HeadBob {
+    /// Progress in steps, matching the footstep sounds.
+    phase: f32,
+    /// Blend of the bob, from zero when standing still to one when moving.
+    weight: f32,
+    dip: f32,
+    was_grounded: bool,
+}
+
+impl HeadBob {
+    fn offset(&self) -> Vec3 {
+        let vertical = -BOB_HEIGHT * (1.0 - (self.phase * TAU).cos()) / 2.0;
+        let sideways = BOB_SWAY * (self.phase * PI).sin();
+        Vec3::new(sideways, vertical, 0.0) * self.weight - Vec3::Y * self.dip
+    }
+}
+
+/// Offsets the global transform of the first person camera only, after it has followed
+/// the character, so the bob never feeds back into the smoothing of the follow.
+fn apply_head_bob(
+    time: Res<Time>,
+    mode: Res<CameraMode>,
+    settings: Res<AccessibilitySettings>,
+    mut bob: ResMut<HeadBob>,
+    controller: Single<&ChildOf, With<CharacterController>>,
+    characters: Query<&CharacterMovementState>,
+    mut camera: Single<&mut GlobalTransform, (With<Camera3d>, Without<CameraPathPlayback>)>,
+) {
+    if *mode != CameraMode::FirstPerson || settings.reduce_motion {
+        *bob = HeadBob::default();
+        return;
+    }
+    let Ok(state) = characters.get(controller.parent()) else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let walking = state.grounded && state.is_moving();
+    if walking {
+        bob.phase = (bob.phase + steps_per_second(state.speed) * dt) % 2.0;
+    }
+    let target_weight = if walking { 1.0 } else { 0.0 };
+    let blend = 1.0 - (-RECOVERY * dt).exp();
+    bob.weight = bob.weight.lerp(target_weight, blend);
+
+    if state.grounded && !bob.was_grounded {
+        bob.dip = LANDING_DIP;
+    }
+    bob.was_grounded = state.grounded;
+    bob.dip = bob.dip.lerp(0.0, blend);
+
+    let mut transform = camera.compute_transform();
+    transform.translation += transform.rotation * bob.offset();
+    **camera = GlobalTransform::from(transform);
+}
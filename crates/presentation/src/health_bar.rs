@@ -0,0 +1,190 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{prelude::*, transform::TransformSystems, window::PrimaryWindow};
+use bevy_rapier3d::plugin::PhysicsSet;
+use merlo_model::{Health, Player};
+
+use crate::camera::cursor_ray;
+
+#[derive(Default)]
+pub struct HealthBarPlugin;
+
+impl Plugin for HealthBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HealthBarPool>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                PostUpdate,
+                update_health_bars
+                    .after(PhysicsSet::Writeback)
+                    .before(TransformSystems::Propagate),
+            );
+    }
+}
+
+const BAR_SIZE: Vec2 = Vec2::new(1.0, 0.12);
+/// Height of the bar above the origin of a character, or of any other entity.
+const PLAYER_BAR_HEIGHT: f32 = 1.9;
+const BAR_HEIGHT: f32 = 0.9;
+/// Distance from the cursor ray within which an entity is considered targeted.
+const TARGET_RADIUS: f32 = 0.75;
+
+#[derive(Resource)]
+struct HealthBarAssets {
+    quad: Handle<Mesh>,
+    background: Handle<StandardMaterial>,
+}
+
+/// Bar entities reused across frames, so they are not spawned and despawned as health changes.
+#[derive(Resource, Default)]
+struct HealthBarPool(Vec<HealthBar>);
+
+struct HealthBar {
+    root: Entity,
+    fill: Entity,
+    fill_material: Handle<StandardMaterial>,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(HealthBarAssets {
+        quad: meshes.add(Rectangle::from_size(Vec2::ONE)),
+        background: materials.add(bar_material(Color::srgba(0.0, 0.0, 0.0, 0.6))),
+    });
+}
+
+fn bar_material(color: Color) -> StandardMaterial {
+    StandardMaterial {
+        base_color: color,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    }
+}
+
+/// Red when almost dead, green when healthy.
+fn fill_color(fraction: f32) -> Color {
+    Color::srgb(1.0, 0.2, 0.2).mix(&Color::srgb(0.2, 0.9, 0.2), fraction)
+}
+
+fn spawn_bar(
+    commands: &mut Commands,
+    assets: &HealthBarAssets,
+    materials: &mut Assets<StandardMaterial>,
+) -> HealthBar {
+    let fill_material = materials.add(bar_material(Color::WHITE));
+    let mut fill = Entity::PLACEHOLDER;
+    let root = commands
+        .spawn((Transform::default(), Visibility::Hidden))
+        .with_children(|parent| {
+            parent.spawn((
+                Mesh3d(assets.quad.clone()),
+                MeshMaterial3d(assets.background.clone()),
+                Transform::from_scale(BAR_SIZE.extend(1.0)),
+            ));
+            // Slightly in front of the background, toward the camera.
+            fill = parent
+                .spawn((
+                    Mesh3d(assets.quad.clone()),
+                    MeshMaterial3d(fill_material.clone()),
+                    Transform::from_xyz(0.0, 0.0, 0.001),
+                ))
+                .id();
+        })
+        .id();
+    HealthBar {
+        root,
+        fill,
+        fill_material,
+    }
+}
+
+/// Places a bar over every damaged or targeted entity, facing the camera.
+///
+/// Runs before transform propagation, so bars follow the latest position of root entities
+/// written back by physics without lagging a frame behind.
+#[allow(clippy::too_many_arguments)]
+fn update_health_bars(
+    mut commands: Commands,
+    assets: Res<HealthBarAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut pool: ResMut<HealthBarPool>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera3d>>,
+    entities: Query<(&Health, &Transform, Has<Player>)>,
+    mut bars: Query<(&mut Transform, &mut Visibility), Without<Health>>,
+) {
+    let (camera, camera_transform) = *camera;
+    let ray = cursor_ray(&window, camera, camera_transform);
+    let targeted = |position: Vec3| {
+        ray.is_some_and(|ray| {
+            let along = (position - ray.origin).dot(*ray.direction);
+            along > 0.0 && ray.get_point(along).distance(position) < TARGET_RADIUS
+        })
+    };
+
+    let mut shown = 0;
+    for (health, transform, is_player) in &entities {
+        let position = transform.translation;
+        if !health.is_damaged() && !targeted(position) {
+            continue;
+        }
+
+        if shown == pool.0.len() {
+            let bar = spawn_bar(&mut commands, &assets, &mut materials);
+            pool.0.push(bar);
+        }
+        let bar = &pool.0[shown];
+        shown += 1;
+
+        let height = if is_player {
+            PLAYER_BAR_HEIGHT
+        } else {
+            BAR_HEIGHT
+        };
+        let root_transform = Transform::from_translation(position + Vec3::Y * height)
+            .with_rotation(camera_transform.rotation());
+        let fraction = health.fraction();
+        let fill_transform = Transform::from_xyz(BAR_SIZE.x * (fraction - 1.0) / 2.0, 0.0, 0.001)
+            .with_scale(Vec3::new(BAR_SIZE.x * fraction, BAR_SIZE.y, 1.0));
+
+        // Freshly spawned bars get their transform on insertion, the others are updated in place.
+        match bars.get_mut(bar.root) {
+            Ok((mut transform, mut visibility)) => {
+                *transform = root_transform;
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            Err(_) => {
+                commands
+                    .entity(bar.root)
+                    .insert((root_transform, Visibility::Inherited));
+            }
+        }
+        match bars.get_mut(bar.fill) {
+            Ok((mut transform, _)) => *transform = fill_transform,
+            Err(_) => {
+                commands.entity(bar.fill).insert(fill_transform);
+            }
+        }
+        // Only touch the material when needed, as changes are uploaded again to the GPU.
+        let color = fill_color(fraction);
+        if materials
+            .get(&bar.fill_material)
+            .is_some_and(|material| material.base_color != color)
+            && let Some(material) = materials.get_mut(&bar.fill_material)
+        {
+            material.base_color = color;
+        }
+    }
+
+    for bar in &pool.0[shown..] {
+        if let Ok((_, mut visibility)) = bars.get_mut(bar.root) {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_simulation::attack::AttackHit;
+
+use crate::animation::Animations;
+
+#[derive(Default)]
+pub struct HitstopPlugin;
+
+impl Plugin for HitstopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HitFlashes>()
+            .add_systems(Update, (start_hitstops, end_hitstops, draw_hit_flashes));
+    }
+}
+
+/// Seconds the animations of the attacker and of the target freeze on a hit.
+const HITSTOP_DURATION: f32 = 0.08;
+/// Seconds the flash of a hit stays visible while shrinking.
+const FLASH_LIFETIME: f32 = 0.15;
+const FLASH_RADIUS: f32 = 0.3;
+const FLASH_COLOR: Color = Color::srgb(1.0, 0.95, 0.8);
+
+/// Seconds left before an [`AnimationPlayer`] frozen by a hit plays again.
+#[derive(Component)]
+struct Hitstop(f32);
+
+/// Hits confirmed recently, with their age, drawn until they fade out.
+#[derive(Resource, Default)]
+struct HitFlashes(Vec<(Vec3, f32)>);
+
+/// Freezes the animations of both characters of every hit confirmed by the server, so
+/// that the hit lands with some weight.
+fn start_hitstops(
+    mut commands: Commands,
+    mut hit_reader: MessageReader<AttackHit>,
+    mut flashes: ResMut<HitFlashes>,
+    parents: Query<&ChildOf>,
+    mut players: Query<(Entity, &mut AnimationPlayer), With<Animations>>,
+) {
+    let hits: Vec<_> = hit_reader.read().copied().collect();
+    if hits.is_empty() {
+        return;
+    }
+    flashes.0.extend(hits.iter().map(|hit| (hit.point, 0.0)));
+
+    for (entity, mut player) in &mut players {
+        let hit = parents.iter_ancestors(entity).any(|ancestor| {
+            hits.iter()
+                .any(|hit| ancestor == hit.attacker || ancestor == hit.target)
+        });
+        if hit {
+            player.pause_all();
+            commands.entity(entity).insert(Hitstop(HITSTOP_DURATION));
+        }
+    }
+}
+
+fn end_hitstops(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut players: Query<(Entity, &mut Hitstop, &mut AnimationPlayer)>,
+) {
+    for (entity, mut hitstop, mut player) in &mut players {
+        hitstop.0 -= time.delta_secs();
+        if hitstop.0 <= 0.0 {
+            player.resume_all();
+            commands.entity(entity).remove::<Hitstop>();
+        }
+    }
+}
+
+fn draw_hit_flashes(time: Res<Time>, mut flashes: ResMut<HitFlashes>, mut gizmos: Gizmos) {
+    flashes.0.retain_mut(|(_, age)| {
+        *age += time.delta_secs();
+        *age < FLASH_LIFETIME
+    });
+    for &(point, age) in &flashes.0 {
+        let fade = 1.0 - age / FLASH_LIFETIME;
+        gizmos.sphere(
+            Isometry3d::from_translation(point),
+            FLASH_RADIUS * fade,
+            FLASH_COLOR.with_alpha(fade),
+        );
+    }
+}
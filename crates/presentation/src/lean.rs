@@ -0,0 +1,178 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    animation::AnimationTarget, app::AnimationSystems, prelude::*, transform::TransformSystems,
+    transform::helper::TransformHelper,
+};
+use bevy_rapier3d::prelude::Velocity;
+use merlo_model::Dead;
+use merlo_simulation::controller::CharacterMovementState;
+
+use crate::{
+    animation::{Animations, find_character},
+    animation_set::LeanSettings,
+    root_motion,
+};
+
+#[derive(Default)]
+pub struct LeanPlugin;
+
+impl Plugin for LeanPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, find_lean_bones).add_systems(
+            PostUpdate,
+            (
+                restore_lean.before(AnimationSystems),
+                (solve_lean, apply_lean)
+                    .chain()
+                    .after(AnimationSystems)
+                    .after(root_motion::extract_root_motion)
+                    .before(TransformSystems::Propagate),
+            ),
+        );
+    }
+}
+
+/// Horizontal speed of a running character, where turning leans the most.
+const RUN_VELOCITY: f32 = 9.0;
+
+/// A bone of a character leaned into turns and changes of speed.
+#[derive(Component)]
+pub struct Lean {
+    character: Entity,
+    bone: Entity,
+    settings: LeanSettings,
+    /// Velocity of the character last frame, to measure its acceleration.
+    previous_velocity: Option<Vec3>,
+    /// Smoothed sideways and forward lean in radians.
+    roll: f32,
+    pitch: f32,
+    /// Local rotation of the bone to write this frame.
+    solution: Option<Quat>,
+    /// Local rotation of the bone before leaning, restored before animating so that
+    /// a bone which is not animated does not accumulate the lean.
+    animated: Option<Quat>,
+}
+
+fn find_lean_bones(
+    mut commands: Commands,
+    players: Query<(Entity, &Animations), Added<Animations>>,
+    bones: Query<(Entity, &AnimationTarget, &Name)>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    parents: Query<&ChildOf>,
+) {
+    for (player, animations) in &players {
+        let Some(settings) = animations.lean() else {
+            continue;
+        };
+        let bone = bones
+            .iter()
+            .find(|(_, target, name)| target.player == player && name.as_str() == settings.bone)
+            .map(|(entity, _, _)| entity);
+        let (Some(bone), Some((character, _, _))) =
+            (bone, find_character(player, &parents, &characters))
+        else {
+            continue;
+        };
+        commands.entity(player).insert(Lean {
+            character,
+            bone,
+            settings: settings.clone(),
+            previous_velocity: None,
+            roll: 0.0,
+            pitch: 0.0,
+            solution: None,
+            animated: None,
+        });
+    }
+}
+
+fn restore_lean(leans: Query<&Lean>, mut transforms: Query<&mut Transform>) {
+    for lean in &leans {
+        if let Some(animated) = lean.animated
+            && let Ok(mut transform) = transforms.get_mut(lean.bone)
+        {
+            transform.rotation = animated;
+        }
+    }
+}
+
+/// Leans the bone sideways into turns and toward the acceleration of the character,
+/// such as forward when it starts sprinting and backward when it stops.
+fn solve_lean(
+    time: Res<Time>,
+    helper: TransformHelper,
+    parents: Query<&ChildOf>,
+    transforms: Query<&Transform>,
+    characters: Query<(&CharacterMovementState, &Velocity, Has<Dead>)>,
+    mut leans: Query<&mut Lean>,
+) {
+    let dt = time.delta_secs();
+    for mut lean in &mut leans {
+        lean.solution = None;
+        let (Ok(character), Ok((state, velocity, dead)), Ok(local)) = (
+            transforms.get(lean.character),
+            characters.get(lean.character),
+            transforms.get(lean.bone),
+        ) else {
+            continue;
+        };
+        let Some(parent) = parents
+            .get(lean.bone)
+            .ok()
+            .and_then(|parent| helper.compute_global_transform(parent.parent()).ok())
+        else {
+            continue;
+        };
+
+        let horizontal = velocity.linvel.with_y(0.0);
+        let previous = lean.previous_velocity.replace(horizontal);
+        let acceleration = match previous {
+            Some(previous) if dt > 0.0 => (horizontal - previous) / dt,
+            _ => Vec3::ZERO,
+        };
+        // In the local space of the character, where +X is on its left and +Z is forward.
+        let local_acceleration = character.rotation.inverse() * acceleration;
+
+        let settings = &lean.settings;
+        let (target_roll, target_pitch) = if state.grounded && !dead {
+            let speed = (horizontal.length() / RUN_VELOCITY).min(1.0);
+            let turn = settings.turn_lean * state.rotating * speed;
+            let max_angle = settings.max_angle;
+            (
+                (turn + settings.acceleration_lean * local_acceleration.x)
+                    .clamp(-max_angle, max_angle)
+                    .to_radians(),
+                (settings.acceleration_lean * local_acceleration.z)
+                    .clamp(-max_angle, max_angle)
+                    .to_radians(),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let blend = 1.0 - (-settings.smoothing * dt).exp();
+        lean.roll = lean.roll.lerp(target_roll, blend);
+        lean.pitch = lean.pitch.lerp(target_pitch, blend);
+
+        // Tilting the top of the bone toward +X and +Z, about the axes of the character.
+        let local_lean = Quat::from_rotation_z(-lean.roll) * Quat::from_rotation_x(lean.pitch);
+        let world_lean = character.rotation * local_lean * character.rotation.inverse();
+        lean.solution =
+            Some(parent.rotation().inverse() * world_lean * parent.rotation() * local.rotation);
+    }
+}
+
+pub fn apply_lean(mut leans: Query<&mut Lean>, mut transforms: Query<&mut Transform>) {
+    for mut lean in &mut leans {
+        let Some(solution) = lean.solution else {
+            lean.animated = None;
+            continue;
+        };
+        if let Ok(mut transform) = transforms.get_mut(lean.bone) {
+            lean.animated = Some(transform.rotation);
+            transform.rotation = solution;
+        }
+    }
+}
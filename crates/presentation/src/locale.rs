@@ -0,0 +1,278 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::collections::HashMap;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use bevy_egui::egui;
+
+#[derive(Default)]
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Translations>()
+            .init_asset_loader::<TranslationsLoader>()
+            .init_resource::<Localization>()
+            .add_systems(Update, apply_translations);
+    }
+}
+
+/// Built-in English strings, also used as a fallback for keys missing from a translation.
+const ENGLISH: &[(&str, &str)] = &[
+    ("tab.game_view", "Game View"),
+    ("tab.panel", "Panel"),
+    ("tab.palette", "Palette"),
+    ("tab.chat", "Chat"),
+    ("tab.admin", "Admin"),
+    ("tab.traffic", "Traffic"),
+    ("panel.title", "Character Controller Demo"),
+    ("panel.move", "Use WASD to move the character."),
+    ("panel.jump", "Use SPACE to jump."),
+    (
+        "panel.camera",
+        "Use V to switch between third and first person.",
+    ),
+    ("panel.look", "Use mouse to look around."),
+    (
+        "panel.click_to_move",
+        "Use M to toggle walking to the ground clicked with the left button.",
+    ),
+    ("panel.shoot", "Use G to shoot forward."),
+    ("panel.carry", "Use R to grab or drop the doodad in front."),
+    (
+        "panel.throw",
+        "Hold T to charge a throw of the carried doodad.",
+    ),
+    (
+        "panel.undo",
+        "Use Ctrl+Z to undo an edit, and Ctrl+Shift+Z to redo it.",
+    ),
+    ("panel.language", "Language"),
+    ("accessibility.ui_scale", "UI scale"),
+    ("accessibility.high_contrast", "High contrast"),
+    ("accessibility.colorblind", "Colorblind-safe colors"),
+    ("accessibility.reduce_motion", "Reduce motion"),
+    (
+        "palette.pick",
+        "Left click on the ground to pick a spawn position.",
+    ),
+    ("palette.spawn_at", "Spawn at"),
+    ("editor.select", "Select an entity"),
+    ("editor.position", "Position"),
+    ("editor.health", "Health"),
+    ("editor.duplicate", "Duplicate (Ctrl+D)"),
+    ("editor.delete", "Delete"),
+    ("editor.undo", "Undo"),
+    ("editor.redo", "Redo"),
+    ("chat.hint", "Press Enter to send"),
+    ("scoreboard.title", "Scoreboard"),
+    ("scoreboard.name", "Name"),
+    ("scoreboard.team", "Team"),
+    ("scoreboard.score", "Score"),
+    ("scoreboard.ping", "Ping"),
+    ("error.title", "Cannot start the game"),
+    ("error.quit", "Quit"),
+    ("respawn.title", "Choose where to respawn"),
+    ("respawn.point", "Spawn point"),
+    ("mode.tag", "Tag"),
+    ("mode.deathmatch", "Deathmatch"),
+    ("mode.round", "Round"),
+    ("mode.warmup", "Warmup, the round starts in"),
+    ("mode.fight", "Fight!"),
+    ("mode.first_to", "First to"),
+    ("mode.you_are_it", "You are it!"),
+    ("mode.winner", "Round won by"),
+    ("mode.draw", "Draw"),
+    ("mode.next_round", "Next round in"),
+    ("debug.physics", "Physics debug (F4)"),
+    ("debug.colliders", "Colliders"),
+    ("debug.contacts", "Contacts"),
+    ("debug.joints", "Joints"),
+    ("panel.reset_layout", "Reset layout"),
+    ("grid.grid", "Grid (F5)"),
+    ("grid.axes", "Axes"),
+    ("grid.measure", "Measure"),
+    ("grid.distance", "Distance"),
+    ("status.local", "Playing locally"),
+    ("status.connecting", "Connecting to server..."),
+    ("status.connected", "Connected to server"),
+    (
+        "admin.not_hosting",
+        "Only available while hosting a server.",
+    ),
+    ("admin.no_clients", "No clients connected."),
+    ("admin.id", "Id"),
+    ("admin.name", "Name"),
+    ("admin.ping", "Ping"),
+    ("admin.entity", "Entity"),
+    ("admin.kick", "Kick"),
+    ("admin.mute", "Mute"),
+    ("admin.unmute", "Unmute"),
+    ("admin.teleport", "Teleport to"),
+    ("audio.master", "Master volume"),
+    ("audio.sfx", "Effects volume"),
+    ("audio.music", "Music volume"),
+    ("graphics.quality", "Graphics quality"),
+    ("graphics.low", "Low"),
+    ("graphics.medium", "Medium"),
+    ("graphics.high", "High"),
+    (
+        "traffic.empty",
+        "No replication traffic in the last second.",
+    ),
+    ("traffic.component", "Component"),
+    ("traffic.sent", "Sent (updates / bytes)"),
+    ("traffic.received", "Received (updates / bytes)"),
+    ("traffic.connection", "Connection"),
+    ("screenshot.include_ui", "Include UI in screenshots (F12)"),
+    ("screenshot.saved", "Screenshot saved to"),
+];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    Italian,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Italian];
+
+    /// Name of the language in the language itself.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Italian => "Italiano",
+        }
+    }
+
+    /// Path of the translation asset, English being built in.
+    fn path(self) -> Option<&'static str> {
+        match self {
+            Language::English => None,
+            Language::Italian => Some("locales/it.lang"),
+        }
+    }
+}
+
+/// A key to string table loaded from a `.lang` file of `key = value` lines.
+#[derive(Asset, TypePath, Debug, Default)]
+pub struct Translations(HashMap<String, String>);
+
+impl Translations {
+    fn parse(text: &str) -> Self {
+        let strings = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+            .collect();
+        Self(strings)
+    }
+}
+
+#[derive(Default, TypePath)]
+struct TranslationsLoader;
+
+impl AssetLoader for TranslationsLoader {
+    type Asset = Translations;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(Translations::parse(&String::from_utf8_lossy(&bytes)))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["lang"]
+    }
+}
+
+/// The current language, and its strings once loaded.
+#[derive(Resource, Default)]
+pub struct Localization {
+    language: Language,
+    handle: Option<Handle<Translations>>,
+    strings: HashMap<String, String>,
+}
+
+impl Localization {
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Switches language, keeping the current strings until the new ones are loaded.
+    pub fn set_language(&mut self, language: Language, asset_server: &AssetServer) {
+        if language == self.language {
+            return;
+        }
+        self.language = language;
+        self.handle = language.path().map(|path| asset_server.load(path));
+        if self.handle.is_none() {
+            self.strings.clear();
+        }
+    }
+
+    /// Returns the string for `key` in the current language.
+    pub fn tr<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings
+            .get(key)
+            .map(String::as_str)
+            .or_else(|| {
+                ENGLISH
+                    .iter()
+                    .find_map(|(english_key, value)| (*english_key == key).then_some(*value))
+            })
+            .unwrap_or(key)
+    }
+}
+
+/// Copies the strings of the current language once loaded, or when hot-reloaded.
+fn apply_translations(
+    mut events: MessageReader<AssetEvent<Translations>>,
+    translations: Res<Assets<Translations>>,
+    mut localization: ResMut<Localization>,
+) {
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = *event
+        else {
+            continue;
+        };
+        let current = localization.handle.as_ref().map(Handle::id);
+        if current != Some(id) {
+            continue;
+        }
+        if let Some(loaded) = translations.get(id) {
+            localization.strings = loaded.0.clone();
+        }
+    }
+}
+
+/// Shows a language picker.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let asset_server = world.resource::<AssetServer>().clone();
+    let mut localization = world.resource_mut::<Localization>();
+    let mut language = localization.language();
+    let label = localization.tr("panel.language").to_owned();
+    egui::ComboBox::from_label(label)
+        .selected_text(language.native_name())
+        .show_ui(ui, |ui| {
+            for option in Language::ALL {
+                ui.selectable_value(&mut language, option, option.native_name());
+            }
+        });
+    localization.set_language(language, &asset_server);
+}
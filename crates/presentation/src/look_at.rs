@@ -0,0 +1,250 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    animation::AnimationTarget, app::AnimationSystems, prelude::*, transform::TransformSystems,
+    transform::helper::TransformHelper,
+};
+use bevy_rapier3d::prelude::Velocity;
+use merlo_model::{Dead, Player};
+use merlo_simulation::controller::{CharacterController, CharacterMovementState};
+
+use crate::{
+    animation::{Animations, find_character},
+    animation_set::LookAtSettings,
+    lean,
+};
+
+#[derive(Default)]
+pub struct LookAtPlugin;
+
+impl Plugin for LookAtPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, find_look_at_bones).add_systems(
+            PostUpdate,
+            (
+                restore_look_at.before(AnimationSystems),
+                (solve_look_at, apply_look_at)
+                    .chain()
+                    .after(AnimationSystems)
+                    .after(lean::apply_lean)
+                    .before(TransformSystems::Propagate),
+            ),
+        );
+    }
+}
+
+/// Other players closer than this are looked at by remote characters.
+const LOOK_AT_DISTANCE: f32 = 6.0;
+/// Height of the eyes above the origin of a character.
+const EYE_HEIGHT: f32 = 1.2;
+/// Targets further behind than this are ignored, looking ahead instead.
+const MAX_TURN: f32 = 120.0;
+/// Slowest horizontal speed for a character to look where it is going.
+const MIN_MOVING_SPEED: f32 = 0.1;
+
+/// Head and chest bones of a character turned toward what it looks at.
+#[derive(Component)]
+struct HeadLookAt {
+    character: Entity,
+    chest: Entity,
+    head: Entity,
+    settings: LookAtSettings,
+    /// Smoothed angles in radians, relative to the forward direction of the character.
+    yaw: f32,
+    pitch: f32,
+    /// Local rotations of chest and head to write this frame.
+    solution: Option<[Quat; 2]>,
+    /// Local rotations of chest and head before being turned, restored before animating
+    /// so that bones which are not animated do not accumulate the rotation.
+    animated: Option<[Quat; 2]>,
+}
+
+fn find_look_at_bones(
+    mut commands: Commands,
+    players: Query<(Entity, &Animations), Added<Animations>>,
+    bones: Query<(Entity, &AnimationTarget, &Name)>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    parents: Query<&ChildOf>,
+) {
+    for (player, animations) in &players {
+        let Some(settings) = animations.look_at() else {
+            continue;
+        };
+        let find = |name: &str| {
+            bones
+                .iter()
+                .find(|(_, target, bone_name)| {
+                    target.player == player && bone_name.as_str() == name
+                })
+                .map(|(entity, _, _)| entity)
+        };
+        let (Some(chest), Some(head), Some((character, _, _))) = (
+            find(&settings.chest),
+            find(&settings.head),
+            find_character(player, &parents, &characters),
+        ) else {
+            continue;
+        };
+        commands.entity(player).insert(HeadLookAt {
+            character,
+            chest,
+            head,
+            settings: settings.clone(),
+            yaw: 0.0,
+            pitch: 0.0,
+            solution: None,
+            animated: None,
+        });
+    }
+}
+
+fn restore_look_at(look_ats: Query<&HeadLookAt>, mut transforms: Query<&mut Transform>) {
+    for look_at in &look_ats {
+        let Some(animated) = look_at.animated else {
+            continue;
+        };
+        for (bone, rotation) in [look_at.chest, look_at.head].into_iter().zip(animated) {
+            if let Ok(mut transform) = transforms.get_mut(bone) {
+                transform.rotation = rotation;
+            }
+        }
+    }
+}
+
+/// Direction a character looks toward: where the camera looks for the local player,
+/// otherwise the nearest player around, or where the character is going.
+fn look_direction(
+    character: Entity,
+    eyes: Vec3,
+    local_character: Option<Entity>,
+    camera: Option<&GlobalTransform>,
+    players: &Query<(Entity, &Transform), With<Player>>,
+    velocities: &Query<&Velocity>,
+) -> Option<Vec3> {
+    if Some(character) == local_character {
+        return camera.map(|camera| *camera.forward());
+    }
+
+    let nearest = players
+        .iter()
+        .filter(|(other, _)| *other != character)
+        .map(|(_, transform)| transform.translation + Vec3::Y * EYE_HEIGHT)
+        .filter(|other_eyes| other_eyes.distance(eyes) < LOOK_AT_DISTANCE)
+        .min_by(|a, b| a.distance(eyes).total_cmp(&b.distance(eyes)));
+    if let Some(other_eyes) = nearest {
+        return (other_eyes - eyes).try_normalize();
+    }
+
+    let velocity = velocities.get(character).ok()?.linvel.with_y(0.0);
+    (velocity.length() > MIN_MOVING_SPEED).then(|| velocity.normalize())
+}
+
+/// Turns chest and head toward the target, within the limits of the character.
+#[allow(clippy::too_many_arguments)]
+fn solve_look_at(
+    time: Res<Time>,
+    helper: TransformHelper,
+    parents: Query<&ChildOf>,
+    transforms: Query<&Transform>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    camera: Query<&GlobalTransform, With<Camera3d>>,
+    players: Query<(Entity, &Transform), With<Player>>,
+    velocities: Query<&Velocity>,
+    dead: Query<(), With<Dead>>,
+    mut look_ats: Query<&mut HeadLookAt>,
+) {
+    let local_character = controller.single().ok().map(ChildOf::parent);
+    let camera = camera.single().ok();
+    let global = |entity: Entity| helper.compute_global_transform(entity).ok();
+    let parent_global = |entity: Entity| global(parents.get(entity).ok()?.parent());
+
+    for mut look_at in &mut look_ats {
+        look_at.solution = None;
+        let (Ok(character), Some(chest_parent), Some(head_parent), Some(head)) = (
+            transforms.get(look_at.character),
+            parent_global(look_at.chest),
+            parent_global(look_at.head),
+            global(look_at.head),
+        ) else {
+            continue;
+        };
+        let (Ok(chest_local), Ok(head_local)) =
+            (transforms.get(look_at.chest), transforms.get(look_at.head))
+        else {
+            continue;
+        };
+
+        // Dead characters stop looking around.
+        let direction = if dead.contains(look_at.character) {
+            None
+        } else {
+            look_direction(
+                look_at.character,
+                head.translation(),
+                local_character,
+                camera,
+                &players,
+                &velocities,
+            )
+        };
+        let local_direction = direction.map(|direction| character.rotation.inverse() * direction);
+        let settings = &look_at.settings;
+        let (target_yaw, target_pitch) = match local_direction {
+            Some(local) if local.x.atan2(local.z).abs() <= MAX_TURN.to_radians() => {
+                let max_yaw = settings.max_yaw.to_radians();
+                let max_pitch = settings.max_pitch.to_radians();
+                (
+                    local.x.atan2(local.z).clamp(-max_yaw, max_yaw),
+                    local.y.clamp(-1.0, 1.0).asin().clamp(-max_pitch, max_pitch),
+                )
+            }
+            _ => (0.0, 0.0),
+        };
+        let blend = 1.0 - (-settings.smoothing * time.delta_secs()).exp();
+        let chest_weight = settings.chest_weight;
+        look_at.yaw = look_at.yaw.lerp(target_yaw, blend);
+        look_at.pitch = look_at.pitch.lerp(target_pitch, blend);
+
+        // Rotations about the up and right axes of the character, in world space.
+        let turn = |weight: f32| {
+            let local_turn = Quat::from_rotation_y(look_at.yaw * weight)
+                * Quat::from_rotation_x(-look_at.pitch * weight);
+            character.rotation * local_turn * character.rotation.inverse()
+        };
+        let chest_turn = turn(chest_weight);
+        let head_turn = turn(1.0 - chest_weight);
+
+        // Locally, a world rotation of a bone is conjugated by the rotation of its parent.
+        let chest_rotation = chest_parent.rotation().inverse()
+            * chest_turn
+            * chest_parent.rotation()
+            * chest_local.rotation;
+        let turned_head_parent = chest_turn * head_parent.rotation();
+        let head_rotation =
+            turned_head_parent.inverse() * head_turn * turned_head_parent * head_local.rotation;
+        look_at.solution = Some([chest_rotation, head_rotation]);
+    }
+}
+
+fn apply_look_at(mut look_ats: Query<&mut HeadLookAt>, mut transforms: Query<&mut Transform>) {
+    for mut look_at in &mut look_ats {
+        let Some(solution) = look_at.solution else {
+            look_at.animated = None;
+            continue;
+        };
+        let mut animated = [Quat::IDENTITY; 2];
+        for ((bone, rotation), animated) in [look_at.chest, look_at.head]
+            .into_iter()
+            .zip(solution)
+            .zip(&mut animated)
+        {
+            if let Ok(mut transform) = transforms.get_mut(bone) {
+                *animated = transform.rotation;
+                transform.rotation = rotation;
+            }
+        }
+        look_at.animated = Some(animated);
+    }
+}
@@ -2,66 +2,246 @@
 // Author: Antonio Caggiano <info@antoniocaggiano.eu>
 // SPDX-License-Identifier: MIT
 
+mod accessibility;
+mod admin;
 mod animation;
+mod animation_lod;
+mod animation_set;
+mod animation_state;
+mod audio;
 mod camera;
+mod chat;
+mod cinematic;
+mod click_to_move;
+mod config;
+mod day_night;
+mod debug_render;
+mod diagnostics;
+mod editor;
+mod fatal_error;
+mod floating_text;
+mod foot_ik;
+mod game_mode;
+mod graphics;
+mod grid;
+mod head_bob;
+mod health_bar;
+mod hitstop;
+mod lean;
+mod locale;
+mod look_at;
+mod music;
+mod palette;
+mod particles;
+mod pickups;
+mod respawn;
+mod root_motion;
+mod scoreboard;
+mod screenshot;
+mod sky;
+mod throw_preview;
+mod tracers;
+mod traffic;
+mod validate;
+mod weather;
+mod window;
+mod zones;
 
 use bevy::app::plugin_group;
-use bevy::prelude::*;
-use bevy_egui::{EguiContext, EguiPlugin, PrimaryEguiContext, egui};
+use std::time::Duration;
+
+use bevy::{
+    app::ScheduleRunnerPlugin,
+    camera::{Viewport, visibility::RenderLayers},
+    prelude::*,
+    window::PrimaryWindow,
+};
+use bevy_egui::{
+    EguiContext, EguiContextSettings, EguiGlobalSettings, EguiPlugin, PrimaryEguiContext, egui,
+};
 use bevy_inspector_egui::{
     DefaultInspectorConfigPlugin, bevy_egui::EguiPrimaryContextPass,
     bevy_inspector::ui_for_entities,
 };
 use bevy_rapier3d::prelude::*;
+use bevy_replicon::prelude::{ClientState, ServerState};
+use clap::{CommandFactory, Parser, error::ErrorKind};
 use egui_dock::{DockArea, DockState, NodeIndex};
+use serde::{Deserialize, Serialize};
 
 use merlo_simulation as simulation;
 
+use crate::{camera::GameViewHovered, locale::Localization, window::WindowArgs};
+
 plugin_group! {
     #[derive(Debug)]
     pub struct PresentationPluginGroup {
         camera:::CameraPlugin,
+        cinematic:::CinematicCameraPlugin,
+        click_to_move:::ClickToMovePlugin,
+        throw_preview:::ThrowPreviewPlugin,
+        head_bob:::HeadBobPlugin,
         animation:::CharacterAnimationPlugin,
+        animation_lod:::AnimationLodPlugin,
+        root_motion:::RootMotionPlugin,
+        lean:::LeanPlugin,
+        foot_ik:::FootIkPlugin,
+        look_at:::LookAtPlugin,
+        palette:::PalettePlugin,
+        editor:::EditorPlugin,
+        diagnostics:::DiagnosticsOverlayPlugin,
+        debug_render:::PhysicsDebugPlugin,
+        grid:::GridGizmosPlugin,
+        sky:::SkyPlugin,
+        day_night:::DayNightPlugin,
+        weather:::WeatherPlugin,
+        audio:::MovementAudioPlugin,
+        music:::MusicPlugin,
+        particles:::DustParticlesPlugin,
+        tracers:::TracerPlugin,
+        zones:::ZonesPlugin,
+        pickups:::PickupsPlugin,
+        chat:::ChatWindowPlugin,
+        scoreboard:::ScoreboardPlugin,
+        respawn:::RespawnChooserPlugin,
+        fatal_error:::FatalErrorPlugin,
+        game_mode:::GameModeHudPlugin,
+        floating_text:::FloatingTextPlugin,
+        health_bar:::HealthBarPlugin,
+        hitstop:::HitstopPlugin,
+        locale:::LocalizationPlugin,
+        accessibility:::AccessibilityPlugin,
+        config:::ConfigPlugin,
+        graphics:::GraphicsPlugin,
+        screenshot:::ScreenshotPlugin,
     }
 }
 
-fn main() {
+/// A 3D game prototype.
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    window: WindowArgs,
+
+    #[command(flatten)]
+    physics: simulation::physics::PhysicsArgs,
+
+    /// Host the server without a window nor the editor UI, such as on a machine without a
+    /// display.
+    #[arg(long, env = "MERLO_HEADLESS", global = true)]
+    headless: bool,
+
+    #[command(subcommand)]
+    mode: simulation::network::Cli,
+}
+
+fn main() -> AppExit {
+    let (args, config) = simulation::config::ConfigFile::parse_with::<Args>();
+    if args.headless && !matches!(args.mode, simulation::network::Cli::Server { .. }) {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--headless needs the server mode",
+            )
+            .exit();
+    }
+    if args.headless || args.mode.bench().is_some() {
+        return run_headless(args, config);
+    }
+    if let simulation::network::Cli::Replay {
+        recording, bless, ..
+    } = &args.mode
+    {
+        return match simulation::replay::run(recording, *bless) {
+            Ok(()) => AppExit::Success,
+            Err(err) => {
+                eprintln!("Replay of {} failed: {err}", recording.display());
+                AppExit::error()
+            }
+        };
+    }
+    if let simulation::network::Cli::ValidateAssets = &args.mode {
+        let assets = config
+            .assets
+            .path
+            .clone()
+            .unwrap_or_else(|| AssetPlugin::default().file_path);
+        let problems = validate::validate_assets(&assets);
+        for problem in &problems {
+            eprintln!("{problem}");
+        }
+        if !problems.is_empty() {
+            eprintln!("Found {} problems with the assets", problems.len());
+            return AppExit::error();
+        }
+        println!("The assets are valid");
+        return AppExit::Success;
+    }
+    let hosting = matches!(args.mode, simulation::network::Cli::Server { .. });
+
+    let default_plugins = config.configure(DefaultPlugins.set(WindowPlugin {
+        primary_window: Some(args.window.window()),
+        ..default()
+    }));
     App::new()
-        .add_plugins(DefaultPlugins)
-        .add_plugins(simulation::SimulationPlugin)
-        .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
-        .add_plugins(RapierDebugRenderPlugin::default())
+        .insert_resource(config)
+        .add_plugins(default_plugins)
+        .insert_resource(args.mode)
+        .add_plugins(simulation::SimulationPlugin::default())
+        .add_plugins(simulation::physics::PhysicsPlugin::new(args.physics))
+        .add_plugins(RapierDebugRenderPlugin::default().disabled())
         .add_plugins(simulation::controller::CharacterControllerPlugin)
         .add_plugins(EguiPlugin::default())
         .add_plugins(DefaultInspectorConfigPlugin)
         .add_systems(Startup, setup)
         .add_systems(EguiPrimaryContextPass, ui)
+        .add_systems(PostUpdate, set_camera_viewport.after(ui))
+        .add_systems(Last, save_layout.run_if(on_message::<AppExit>))
         .add_plugins(PresentationPluginGroup)
-        .init_resource::<UiState>()
-        .run();
+        .insert_resource(UiState::load(hosting))
+        .run()
 }
 
-/// Set up a simple 3D scene
+/// Rate of the server loop without a window, close to the one of a window with vsync,
+/// unless the server has a tick rate.
+const HEADLESS_TICK_RATE: f64 = 60.0;
+
+/// Runs the server for `--headless` or `--bench` without a window nor a renderer, and only
+/// the simulation.
+fn run_headless(args: Args, config: simulation::config::ConfigFile) -> AppExit {
+    let mut app = simulation::headless_app_with(
+        config,
+        args.mode,
+        args.physics,
+        simulation::SimulationPlugin::default(),
+    );
+    let tick_rate = app
+        .world()
+        .get_resource::<simulation::network::TickRate>()
+        .map_or(HEADLESS_TICK_RATE, |tick_rate| tick_rate.0);
+    app.add_plugins(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(
+        1.0 / tick_rate,
+    )))
+    .run()
+}
+
+/// Set up the camera of the editor UI, the level comes from the server
 fn setup(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut egui_global_settings: ResMut<EguiGlobalSettings>,
 ) -> Result<()> {
-    // Circular base
+    // Egui gets its own camera, so the game camera can be restricted to the GameView tab.
+    egui_global_settings.auto_create_primary_context = false;
     commands.spawn((
-        RigidBody::Fixed,
-        Collider::cylinder(0.05, 24.0),
-        Mesh3d(meshes.add(Cylinder::new(24.0, 0.1))),
-        MeshMaterial3d(materials.add(Color::WHITE)),
-    ));
-
-    // Light
-    commands.spawn((
-        PointLight {
-            shadows_enabled: true,
+        Camera2d,
+        Name::new("Egui Camera"),
+        PrimaryEguiContext,
+        RenderLayers::none(),
+        Camera {
+            order: 1,
+            clear_color: ClearColorConfig::None,
             ..default()
         },
-        Transform::from_xyz(4.0, 8.0, 4.0),
     ));
 
     Ok(())
@@ -81,11 +261,67 @@ fn ui(world: &mut World) {
     });
 }
 
-#[derive(Debug, Default)]
+/// Makes the game camera render only inside the GameView tab.
+fn set_camera_viewport(
+    ui_state: Res<UiState>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut camera: Single<&mut Camera, Without<PrimaryEguiContext>>,
+    egui_settings: Single<&EguiContextSettings>,
+) {
+    let scale_factor = window.scale_factor() * egui_settings.scale_factor;
+
+    let viewport_pos = ui_state.viewport_rect.left_top().to_vec2() * scale_factor;
+    let viewport_size = ui_state.viewport_rect.size() * scale_factor;
+
+    let physical_position = UVec2::new(viewport_pos.x as u32, viewport_pos.y as u32);
+    let physical_size = UVec2::new(viewport_size.x as u32, viewport_size.y as u32);
+
+    // The dock may not have been laid out yet, or it is being resized past the window.
+    let rect = physical_position + physical_size;
+    let window_size = window.physical_size();
+    if physical_size.cmpeq(UVec2::ZERO).any() || rect.x > window_size.x || rect.y > window_size.y {
+        return;
+    }
+
+    camera.viewport = Some(Viewport {
+        physical_position,
+        physical_size,
+        depth: 0.0..1.0,
+    });
+}
+
+/// File where the dock layout is persisted across launches.
+const LAYOUT_PATH: &str = "layout.ron";
+
+fn save_layout(ui_state: Res<UiState>) {
+    if let Err(err) = ui_state.save() {
+        error!("Cannot save dock layout to {LAYOUT_PATH}: {err}");
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
 enum EguiWindow {
     GameView,
     #[default]
     Panel,
+    Palette,
+    Chat,
+    Admin,
+    Traffic,
+}
+
+impl EguiWindow {
+    /// Localization key of the tab title, also used as a stable tab id.
+    fn key(&self) -> &'static str {
+        match self {
+            EguiWindow::GameView => "tab.game_view",
+            EguiWindow::Panel => "tab.panel",
+            EguiWindow::Palette => "tab.palette",
+            EguiWindow::Chat => "tab.chat",
+            EguiWindow::Admin => "tab.admin",
+            EguiWindow::Traffic => "tab.traffic",
+        }
+    }
 }
 
 #[derive(Resource)]
@@ -94,32 +330,102 @@ struct UiState {
     viewport_rect: egui::Rect,
 }
 
-impl Default for UiState {
-    fn default() -> Self {
-        let mut state = DockState::new(vec![EguiWindow::GameView]);
-        let tree = state.main_surface_mut();
-        let [_game, _inspector] =
-            tree.split_right(NodeIndex::root(), 0.75, vec![EguiWindow::Panel]);
-        UiState {
-            state,
-            viewport_rect: egui::Rect::NOTHING,
+/// The default dock layout, with the admin tab only when hosting.
+fn default_layout(hosting: bool) -> DockState<EguiWindow> {
+    let mut state = DockState::new(vec![EguiWindow::GameView]);
+    let tree = state.main_surface_mut();
+    // Only the host spawns and administers.
+    let side_tabs = if hosting {
+        vec![
+            EguiWindow::Panel,
+            EguiWindow::Palette,
+            EguiWindow::Traffic,
+            EguiWindow::Admin,
+        ]
+    } else {
+        vec![EguiWindow::Panel, EguiWindow::Traffic]
+    };
+    let [game, _inspector] = tree.split_right(NodeIndex::root(), 0.75, side_tabs);
+    let [_game, _chat] = tree.split_below(game, 0.8, vec![EguiWindow::Chat]);
+    state
+}
+
+/// Fits a saved layout to the default one, dropping the tabs it lacks, such as the admin tab
+/// of a layout saved while hosting, and adding the missing ones next to their neighbors of the
+/// default layout, such as tabs added since it was saved.
+fn fit_layout(mut state: DockState<EguiWindow>, hosting: bool) -> DockState<EguiWindow> {
+    let default = default_layout(hosting);
+    let keys: Vec<_> = default.iter_all_tabs().map(|(_, tab)| tab.key()).collect();
+    state.retain_tabs(|tab| keys.contains(&tab.key()));
+    let find = |state: &DockState<EguiWindow>, tab: &EguiWindow| {
+        state.find_tab_from(|other| other.key() == tab.key())
+    };
+    for (_, leaf) in default.iter_leaves() {
+        for tab in leaf.tabs() {
+            if find(&state, tab).is_some() {
+                continue;
+            }
+            match leaf
+                .tabs()
+                .iter()
+                .find_map(|neighbor| find(&state, neighbor))
+            {
+                Some((surface, node, _)) => state[surface][node].append_tab(*tab),
+                None => state.push_to_first_leaf(*tab),
+            }
         }
     }
+    state
 }
 
 impl UiState {
+    /// Restores the layout saved on the last exit, fitted to whether hosting, falling back
+    /// to the default one.
+    fn load(hosting: bool) -> Self {
+        let state = match std::fs::read_to_string(LAYOUT_PATH) {
+            Ok(text) => ron::from_str(&text)
+                .inspect_err(|err| warn!("Ignoring invalid dock layout {LAYOUT_PATH}: {err}"))
+                .ok(),
+            Err(_) => None,
+        };
+        UiState {
+            state: state.map_or_else(
+                || default_layout(hosting),
+                |state| fit_layout(state, hosting),
+            ),
+            viewport_rect: egui::Rect::NOTHING,
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        let text = ron::ser::to_string_pretty(&self.state, ron::ser::PrettyConfig::default())?;
+        std::fs::write(LAYOUT_PATH, text)?;
+        Ok(())
+    }
+
     fn ui(&mut self, world: &mut World, egui_ctx: &mut egui::Context) {
+        let mut reset_layout = false;
         let mut tab_viewer = TabViewer {
             viewport_rect: &mut self.viewport_rect,
+            reset_layout: &mut reset_layout,
             world,
         };
 
+        // Only set while the GameView tab is shown.
+        tab_viewer.world.resource_mut::<GameViewHovered>().0 = false;
         DockArea::new(&mut self.state).show(egui_ctx, &mut tab_viewer);
+
+        if *tab_viewer.reset_layout {
+            let server_state = tab_viewer.world.resource::<State<ServerState>>();
+            self.state = default_layout(**server_state == ServerState::Running);
+        }
     }
 }
 
 struct TabViewer<'a> {
     viewport_rect: &'a mut egui::Rect,
+    /// Set by the Panel tab, as the dock cannot be replaced while it is being shown.
+    reset_layout: &'a mut bool,
     world: &'a mut World,
 }
 
@@ -128,23 +434,62 @@ impl<'a> egui_dock::TabViewer for TabViewer<'a> {
 
     fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
         match tab {
-            EguiWindow::GameView => *self.viewport_rect = ui.clip_rect(),
+            EguiWindow::GameView => {
+                *self.viewport_rect = ui.clip_rect();
+                self.world.resource_mut::<GameViewHovered>().0 =
+                    ui.rect_contains_pointer(ui.clip_rect());
+            }
             EguiWindow::Panel => {
-                ui.label("Character Controller Demo");
-                ui.label("Use WASD to move the character.");
-                ui.label("Use SPACE to jump.");
-                ui.label("Use mouse to look around.");
+                let localization = self.world.resource::<Localization>();
+                let status = match **self.world.resource::<State<ClientState>>() {
+                    ClientState::Disconnected => "status.local",
+                    ClientState::Connecting => "status.connecting",
+                    ClientState::Connected => "status.connected",
+                };
+                ui.label(localization.tr("panel.title"));
+                ui.label(localization.tr("panel.move"));
+                ui.label(localization.tr("panel.jump"));
+                ui.label(localization.tr("panel.look"));
+                ui.label(localization.tr("panel.camera"));
+                ui.label(localization.tr("panel.click_to_move"));
+                ui.label(localization.tr("panel.shoot"));
+                ui.label(localization.tr("panel.carry"));
+                ui.label(localization.tr("panel.throw"));
+                ui.label(localization.tr("panel.undo"));
+                ui.label(localization.tr(status));
+                if ui.button(localization.tr("panel.reset_layout")).clicked() {
+                    *self.reset_layout = true;
+                }
+                ui.separator();
+                locale::ui(self.world, ui);
+                accessibility::ui(self.world, ui);
+                graphics::ui(self.world, ui);
+                audio::ui(self.world, ui);
+                screenshot::ui(self.world, ui);
+                ui.separator();
+                debug_render::ui(self.world, ui);
+                grid::ui(self.world, ui);
                 ui.separator();
                 ui_for_entities(self.world, ui);
             }
+            EguiWindow::Palette => {
+                palette::ui(self.world, ui);
+                ui.separator();
+                editor::ui(self.world, ui);
+            }
+            EguiWindow::Chat => chat::ui(self.world, ui),
+            EguiWindow::Admin => admin::ui(self.world, ui),
+            EguiWindow::Traffic => traffic::ui(self.world, ui),
         }
     }
 
     fn title(&mut self, tab: &mut EguiWindow) -> egui::WidgetText {
-        match tab {
-            EguiWindow::GameView => "Game View".into(),
-            EguiWindow::Panel => "Panel".into(),
-        }
+        let localization = self.world.resource::<Localization>();
+        localization.tr(tab.key()).into()
+    }
+
+    fn id(&mut self, tab: &mut EguiWindow) -> egui::Id {
+        egui::Id::new(tab.key())
     }
 
     fn clear_background(&self, tab: &Self::Tab) -> bool {
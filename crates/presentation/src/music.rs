@@ -0,0 +1,133 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use bevy::{audio::Volume, prelude::*};
+use bevy_replicon::prelude::ClientState;
+use merlo_simulation::damage::DamageDealt;
+
+use crate::audio::AudioSettings;
+
+#[derive(Default)]
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicState>()
+            .init_resource::<CombatTimer>()
+            .add_systems(
+                Update,
+                (update_music_state, start_music_tracks, crossfade_music).chain(),
+            );
+    }
+}
+
+/// Seconds to fade a track fully in or out.
+const CROSSFADE_TIME: f32 = 2.0;
+/// How long combat music keeps playing after the last damage.
+const COMBAT_COOLDOWN: Duration = Duration::from_secs(10);
+
+/// Which music track should be playing.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MusicState {
+    Menu,
+    #[default]
+    Exploration,
+    Combat,
+}
+
+impl MusicState {
+    fn track_path(self) -> &'static str {
+        match self {
+            MusicState::Menu => "music/menu.ogg",
+            MusicState::Exploration => "music/exploration.ogg",
+            MusicState::Combat => "music/combat.ogg",
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct CombatTimer(Option<Timer>);
+
+/// A looping music track, fading in while its state is current and out otherwise.
+#[derive(Component)]
+struct MusicTrack {
+    state: MusicState,
+    fade: f32,
+}
+
+/// Plays menu music while connecting, and combat music for a while after any damage.
+fn update_music_state(
+    time: Res<Time>,
+    client_state: Res<State<ClientState>>,
+    mut damage_reader: MessageReader<DamageDealt>,
+    mut combat: ResMut<CombatTimer>,
+    mut music_state: ResMut<MusicState>,
+) {
+    if damage_reader.read().count() > 0 {
+        combat.0 = Some(Timer::new(COMBAT_COOLDOWN, TimerMode::Once));
+    }
+    if let Some(timer) = &mut combat.0
+        && timer.tick(time.delta()).is_finished()
+    {
+        combat.0 = None;
+    }
+
+    let next = if *client_state == ClientState::Connecting {
+        MusicState::Menu
+    } else if combat.0.is_some() {
+        MusicState::Combat
+    } else {
+        MusicState::Exploration
+    };
+    music_state.set_if_neq(next);
+}
+
+fn start_music_tracks(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    music_state: Res<MusicState>,
+    tracks: Query<&MusicTrack>,
+) {
+    if tracks.iter().any(|track| track.state == *music_state) {
+        return;
+    }
+    commands.spawn((
+        MusicTrack {
+            state: *music_state,
+            fade: 0.0,
+        },
+        AudioPlayer::<AudioSource>::new(asset_server.load(music_state.track_path())),
+        PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+    ));
+}
+
+fn crossfade_music(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<AudioSettings>,
+    music_state: Res<MusicState>,
+    mut tracks: Query<(Entity, &mut MusicTrack, Option<&mut AudioSink>)>,
+) {
+    let step = time.delta_secs() / CROSSFADE_TIME;
+
+    for (entity, mut track, sink) in &mut tracks {
+        if track.state == *music_state {
+            track.fade = (track.fade + step).min(1.0);
+        } else {
+            track.fade -= step;
+            if track.fade <= 0.0 {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        }
+
+        // The sink is only added once the track has been loaded.
+        if let Some(mut sink) = sink {
+            let volume = settings.master * settings.music * track.fade;
+            sink.set_volume(Volume::Linear(volume));
+        }
+    }
+}
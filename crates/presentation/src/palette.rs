@@ -0,0 +1,123 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{prelude::*, transform::TransformSystems, window::PrimaryWindow};
+use bevy_egui::{egui, input::EguiWantsInput};
+use bevy_rapier3d::prelude::*;
+use merlo_model::Doodad;
+use merlo_simulation::{
+    colors::ColorScheme,
+    spawn::{Archetype, SpawnRequest},
+};
+
+use crate::{
+    camera::{GameViewHovered, cursor_ray},
+    locale::Localization,
+};
+
+#[derive(Default)]
+pub struct PalettePlugin;
+
+impl Plugin for PalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GroundPick>()
+            .add_systems(
+                PostUpdate,
+                pick_ground_on_left_click.after(TransformSystems::Propagate),
+            )
+            .add_systems(Update, draw_ground_pick);
+    }
+}
+
+/// The last ground position picked with the left mouse button.
+#[derive(Resource, Default)]
+pub struct GroundPick(Option<Vec3>);
+
+impl GroundPick {
+    pub fn position(&self) -> Option<Vec3> {
+        self.0
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn pick_ground_on_left_click(
+    buttons: Res<ButtonInput<MouseButton>>,
+    egui_wants_input: Res<EguiWantsInput>,
+    game_view_hovered: Res<GameViewHovered>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera3d>>,
+    rapier_context: ReadRapierContext,
+    doodads: Query<(), With<Doodad>>,
+    mut ground_pick: ResMut<GroundPick>,
+) {
+    // Clicks on egui go to egui, unless on the game view the dock lays out.
+    if !buttons.just_pressed(MouseButton::Left)
+        || (egui_wants_input.wants_any_pointer_input() && !game_view_hovered.0)
+    {
+        return;
+    }
+
+    let (camera, camera_transform) = *camera;
+    let Some(ray) = cursor_ray(&window, camera, camera_transform) else {
+        return;
+    };
+
+    // The terrain, platforms, and level geometry, but not the doodads carried around, falling
+    // back to the plane of the ground when missing.
+    let not_doodad = |entity| !doodads.contains(entity);
+    let distance = rapier_context
+        .single()
+        .ok()
+        .and_then(|context| {
+            context.cast_ray(
+                ray.origin,
+                *ray.direction,
+                f32::MAX,
+                true,
+                QueryFilter::exclude_dynamic()
+                    .exclude_sensors()
+                    .predicate(&not_doodad),
+            )
+        })
+        .map(|(_, distance)| distance)
+        .or_else(|| ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y)));
+    let Some(distance) = distance else {
+        return;
+    };
+
+    ground_pick.0 = Some(ray.get_point(distance));
+}
+
+fn draw_ground_pick(ground_pick: Res<GroundPick>, scheme: Res<ColorScheme>, mut gizmos: Gizmos) {
+    let Some(position) = ground_pick.0 else {
+        return;
+    };
+    let isometry = Isometry3d::new(position, Quat::from_rotation_x(std::f32::consts::FRAC_PI_2));
+    gizmos.circle(isometry, 0.5, scheme.selection);
+}
+
+/// Lists spawnable archetypes, spawning the clicked one at the picked ground position.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let ground = world.resource::<GroundPick>().0;
+    let localization = world.resource::<Localization>();
+    match ground {
+        Some(position) => ui.label(format!(
+            "{} ({:.1}, {:.1}, {:.1})",
+            localization.tr("palette.spawn_at"),
+            position.x,
+            position.y,
+            position.z
+        )),
+        None => ui.label(localization.tr("palette.pick")),
+    };
+    ui.separator();
+
+    for archetype in Archetype::ALL {
+        let button = ui.add_enabled(ground.is_some(), egui::Button::new(archetype.name()));
+        if let (true, Some(ground)) = (button.clicked(), ground) {
+            // Spawning goes through the server so the new entity is replicated.
+            world.write_message(SpawnRequest::new(archetype, ground));
+        }
+    }
+}
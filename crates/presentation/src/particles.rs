@@ -0,0 +1,171 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+
+use crate::simulation::controller::{CharacterDimensions, CharacterMovementState};
+
+#[derive(Default)]
+pub struct DustParticlesPlugin;
+
+impl Plugin for DustParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup).add_systems(
+            Update,
+            (add_dust_emitter, emit_dust, update_dust_particles).chain(),
+        );
+    }
+}
+
+/// Downward speed above which a landing is considered hard enough to raise dust.
+const HARD_LANDING_SPEED: f32 = 6.0;
+/// Seconds between trail puffs while sprinting.
+const TRAIL_INTERVAL: f32 = 0.12;
+
+#[derive(Resource)]
+struct DustAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Tracks movement state transitions of a character to emit dust.
+#[derive(Component)]
+struct DustEmitter {
+    was_grounded: bool,
+    /// Fastest downward speed reached while airborne.
+    fall_speed: f32,
+    trail_timer: f32,
+}
+
+#[derive(Component)]
+struct DustParticle {
+    velocity: Vec3,
+    age: f32,
+    lifetime: f32,
+    size: f32,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(DustAssets {
+        mesh: meshes.add(Sphere::new(0.5)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgba(0.6, 0.55, 0.45, 0.6),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+fn add_dust_emitter(
+    mut commands: Commands,
+    characters: Query<Entity, Added<CharacterMovementState>>,
+) {
+    for entity in &characters {
+        commands.entity(entity).insert(DustEmitter {
+            was_grounded: true,
+            fall_speed: 0.0,
+            trail_timer: 0.0,
+        });
+    }
+}
+
+fn emit_dust(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<DustAssets>,
+    mut characters: Query<(
+        &CharacterMovementState,
+        &CharacterDimensions,
+        &GlobalTransform,
+        Option<&Velocity>,
+        &mut DustEmitter,
+    )>,
+) {
+    for (state, dimensions, transform, velocity, mut emitter) in &mut characters {
+        let feet = transform.translation() - Vec3::Y * dimensions.origin_to_foot();
+
+        if !state.grounded {
+            let vertical_speed = velocity.map_or(0.0, |velocity| velocity.linvel.y);
+            emitter.fall_speed = emitter.fall_speed.max(-vertical_speed);
+        } else if !emitter.was_grounded {
+            if emitter.fall_speed >= HARD_LANDING_SPEED {
+                let strength = emitter.fall_speed / HARD_LANDING_SPEED;
+                spawn_landing_burst(&mut commands, &assets, feet, strength);
+            }
+            emitter.fall_speed = 0.0;
+        }
+        emitter.was_grounded = state.grounded;
+
+        if !state.grounded || !state.is_moving() || !state.is_running() {
+            emitter.trail_timer = 0.0;
+            continue;
+        }
+
+        emitter.trail_timer += time.delta_secs();
+        if emitter.trail_timer >= TRAIL_INTERVAL {
+            emitter.trail_timer -= TRAIL_INTERVAL;
+            spawn_particle(&mut commands, &assets, feet, Vec3::Y * 0.4, 0.15, 0.5);
+        }
+    }
+}
+
+fn spawn_landing_burst(commands: &mut Commands, assets: &DustAssets, feet: Vec3, strength: f32) {
+    const COUNT: usize = 12;
+    for i in 0..COUNT {
+        let angle = i as f32 / COUNT as f32 * std::f32::consts::TAU;
+        let outward = Vec3::new(angle.cos(), 0.2, angle.sin()) * 1.5 * strength.min(2.0);
+        spawn_particle(commands, assets, feet, outward, 0.3, 0.6);
+    }
+}
+
+fn spawn_particle(
+    commands: &mut Commands,
+    assets: &DustAssets,
+    position: Vec3,
+    velocity: Vec3,
+    size: f32,
+    lifetime: f32,
+) {
+    commands.spawn((
+        DustParticle {
+            velocity,
+            age: 0.0,
+            lifetime,
+            size,
+        },
+        Mesh3d(assets.mesh.clone()),
+        MeshMaterial3d(assets.material.clone()),
+        Transform::from_translation(position).with_scale(Vec3::splat(size)),
+    ));
+}
+
+/// Moves particles and grows them while shrinking toward the end of their life.
+fn update_dust_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particles: Query<(Entity, &mut DustParticle, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut particle, mut transform) in &mut particles {
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Air drag slows particles down quickly.
+        particle.velocity *= 1.0 - (4.0 * dt).min(1.0);
+        transform.translation += particle.velocity * dt;
+
+        let life = particle.age / particle.lifetime;
+        let scale = particle.size * (1.0 + life) * (1.0 - life * life);
+        transform.scale = Vec3::splat(scale);
+    }
+}
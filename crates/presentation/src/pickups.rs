@@ -0,0 +1,99 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy::prelude::*;
+use merlo_model::{Pickup, PickupIncoming, PickupKind, PickupSpawner};
+
+#[derive(Default)]
+pub struct PickupsPlugin;
+
+impl Plugin for PickupsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(init_pickup_mesh)
+            .add_systems(Update, (spin_pickups, draw_spawners));
+    }
+}
+
+const HEALTH_COLOR: Color = Color::srgb(0.3, 0.9, 0.4);
+const SPAWNER_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.2);
+const SPAWNER_RADIUS: f32 = 0.6;
+/// Radians per second pickups turn around.
+const SPIN_SPEED: f32 = 2.0;
+const BOB_HEIGHT: f32 = 0.1;
+/// Pulses per second of spawners whose pickup is about to come back.
+const PULSE_RATE: f32 = 2.0;
+
+/// The mesh of a pickup, spun and bobbed without touching its replicated transform.
+#[derive(Component)]
+struct PickupMesh;
+
+fn init_pickup_mesh(
+    add: On<Add, Pickup>,
+    mut commands: Commands,
+    pickups: Query<&Pickup>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok(pickup) = pickups.get(add.entity) else {
+        return;
+    };
+    let (mesh, color) = match pickup.kind {
+        // A cross, with a bar in each direction.
+        PickupKind::Health => (meshes.add(Cuboid::new(0.5, 0.16, 0.16)), HEALTH_COLOR),
+    };
+    let material = materials.add(StandardMaterial {
+        base_color: color,
+        emissive: color.to_linear() * 2.0,
+        ..default()
+    });
+    commands
+        .entity(add.entity)
+        .insert(Visibility::default())
+        .with_children(|parent| {
+            parent
+                .spawn((PickupMesh, Transform::default(), Visibility::default()))
+                .with_children(|parent| {
+                    parent.spawn((Mesh3d(mesh.clone()), MeshMaterial3d(material.clone())));
+                    parent.spawn((
+                        Mesh3d(mesh),
+                        MeshMaterial3d(material),
+                        Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_2)),
+                    ));
+                });
+        });
+}
+
+fn spin_pickups(time: Res<Time>, mut meshes: Query<&mut Transform, With<PickupMesh>>) {
+    let seconds = time.elapsed_secs();
+    for mut transform in &mut meshes {
+        transform.rotation = Quat::from_rotation_y(seconds * SPIN_SPEED);
+        transform.translation.y = (seconds * SPIN_SPEED).sin() * BOB_HEIGHT;
+    }
+}
+
+/// Rings every spawner, pulsing those whose pickup is about to come back.
+fn draw_spawners(
+    time: Res<Time>,
+    spawners: Query<(&PickupSpawner, &GlobalTransform, Has<PickupIncoming>)>,
+    mut gizmos: Gizmos,
+) {
+    let pulse = 0.5 + 0.5 * (time.elapsed_secs() * PULSE_RATE * TAU).sin();
+    for (spawner, transform, incoming) in &spawners {
+        let isometry = Isometry3d::new(transform.translation(), Quat::from_rotation_x(FRAC_PI_2));
+        if !incoming {
+            gizmos.circle(isometry, SPAWNER_RADIUS, SPAWNER_COLOR);
+            continue;
+        }
+        let color = match spawner.kind {
+            PickupKind::Health => HEALTH_COLOR,
+        };
+        gizmos.circle(
+            isometry,
+            SPAWNER_RADIUS * (1.0 + 0.3 * pulse),
+            color.with_alpha(0.4 + 0.6 * pulse),
+        );
+    }
+}
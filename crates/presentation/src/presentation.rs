@@ -1,12 +1,92 @@
+pub mod accessibility;
+pub mod admin;
 pub mod animation;
+pub mod animation_lod;
+pub mod animation_set;
+pub mod animation_state;
+pub mod audio;
 pub mod camera;
+pub mod chat;
+pub mod cinematic;
+pub mod click_to_move;
+pub mod config;
+pub mod day_night;
+pub mod debug_render;
+pub mod diagnostics;
+pub mod editor;
+pub mod fatal_error;
+pub mod floating_text;
+pub mod foot_ik;
+pub mod game_mode;
+pub mod graphics;
+pub mod grid;
+pub mod head_bob;
+pub mod health_bar;
+pub mod hitstop;
+pub mod lean;
+pub mod locale;
+pub mod look_at;
+pub mod music;
+pub mod palette;
+pub mod particles;
+pub mod pickups;
+pub mod respawn;
+pub mod root_motion;
+pub mod scoreboard;
+pub mod screenshot;
+pub mod sky;
+pub mod throw_preview;
+pub mod tracers;
+pub mod traffic;
+pub mod validate;
+pub mod weather;
+pub mod window;
+pub mod zones;
 
 use bevy::app::plugin_group;
 
+use merlo_simulation as simulation;
+
 plugin_group! {
     #[derive(Debug)]
     pub struct PresentationPluginGroup {
         camera:::CameraPlugin,
+        cinematic:::CinematicCameraPlugin,
+        click_to_move:::ClickToMovePlugin,
+        throw_preview:::ThrowPreviewPlugin,
+        head_bob:::HeadBobPlugin,
         animation:::CharacterAnimationPlugin,
+        animation_lod:::AnimationLodPlugin,
+        root_motion:::RootMotionPlugin,
+        lean:::LeanPlugin,
+        foot_ik:::FootIkPlugin,
+        look_at:::LookAtPlugin,
+        palette:::PalettePlugin,
+        editor:::EditorPlugin,
+        diagnostics:::DiagnosticsOverlayPlugin,
+        debug_render:::PhysicsDebugPlugin,
+        grid:::GridGizmosPlugin,
+        sky:::SkyPlugin,
+        day_night:::DayNightPlugin,
+        weather:::WeatherPlugin,
+        audio:::MovementAudioPlugin,
+        music:::MusicPlugin,
+        particles:::DustParticlesPlugin,
+        tracers:::TracerPlugin,
+        zones:::ZonesPlugin,
+        pickups:::PickupsPlugin,
+        chat:::ChatWindowPlugin,
+        scoreboard:::ScoreboardPlugin,
+        respawn:::RespawnChooserPlugin,
+        fatal_error:::FatalErrorPlugin,
+        game_mode:::GameModeHudPlugin,
+        floating_text:::FloatingTextPlugin,
+        health_bar:::HealthBarPlugin,
+        hitstop:::HitstopPlugin,
+        locale:::LocalizationPlugin,
+        accessibility:::AccessibilityPlugin,
+        config:::ConfigPlugin,
+        graphics:::GraphicsPlugin,
+        screenshot:::ScreenshotPlugin,
     }
 }
@@ -0,0 +1,151 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use merlo_model::Dead;
+use merlo_simulation::{
+    colors::ColorScheme,
+    controller::CharacterController,
+    death::{ChooseSpawnPoint, SpawnChoices},
+};
+
+use crate::locale::Localization;
+
+/// Side of the map of the spawn points, in points.
+const MAP_SIZE: f32 = 160.0;
+/// Radius of a spawn point on the map, also the distance where clicks pick it.
+const MAP_POINT_RADIUS: f32 = 6.0;
+
+#[derive(Default)]
+pub struct RespawnChooserPlugin;
+
+impl Plugin for RespawnChooserPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpawnChooser>()
+            .add_systems(Update, receive_spawn_choices)
+            .add_systems(EguiPrimaryContextPass, show_spawn_chooser);
+    }
+}
+
+/// The spawn points offered by the server for the dead character of this client.
+#[derive(Resource, Default)]
+struct SpawnChooser {
+    character: Option<Entity>,
+    spawn_points: Vec<Entity>,
+    chosen: Option<Entity>,
+}
+
+/// Keeps the choices offered for the controlled character, until it is alive again.
+fn receive_spawn_choices(
+    mut choices_reader: MessageReader<SpawnChoices>,
+    mut chooser: ResMut<SpawnChooser>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    dead: Query<(), With<Dead>>,
+) {
+    let controlled = controller.single().ok().map(ChildOf::parent);
+    for choices in choices_reader.read() {
+        if Some(choices.character) == controlled {
+            *chooser = SpawnChooser {
+                character: Some(choices.character),
+                spawn_points: choices.spawn_points.clone(),
+                chosen: None,
+            };
+        }
+    }
+    if let Some(character) = chooser.character
+        && (Some(character) != controlled || !dead.contains(character))
+    {
+        *chooser = SpawnChooser::default();
+    }
+}
+
+/// Lists the spawn points offered while dead, on a small top-down map and by name.
+fn show_spawn_chooser(
+    mut contexts: EguiContexts,
+    mut chooser: ResMut<SpawnChooser>,
+    mut choice_writer: MessageWriter<ChooseSpawnPoint>,
+    transforms: Query<&Transform>,
+    localization: Res<Localization>,
+    scheme: Res<ColorScheme>,
+) -> Result<()> {
+    let Some(character) = chooser.character else {
+        return Ok(());
+    };
+    let points: Vec<_> = chooser
+        .spawn_points
+        .iter()
+        .filter_map(|&spawn_point| {
+            let transform = transforms.get(spawn_point).ok()?;
+            Some((spawn_point, transform.translation.xz()))
+        })
+        .collect();
+    if points.is_empty() {
+        return Ok(());
+    }
+
+    let [r, g, b, _] = scheme.selection.to_srgba().to_u8_array();
+    let selection = egui::Color32::from_rgb(r, g, b);
+    let mut chosen = chooser.chosen;
+    egui::Window::new(localization.tr("respawn.title"))
+        .id(egui::Id::new("spawn_chooser"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -32.0))
+        .collapsible(false)
+        .resizable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            // Fits the spawn points in the map, keeping the aspect of the level.
+            let (min, max) = points
+                .iter()
+                .fold((Vec2::MAX, Vec2::MIN), |(min, max), &(_, position)| {
+                    (min.min(position), max.max(position))
+                });
+            let extent = (max - min).max_element().max(1.0);
+            let (response, painter) =
+                ui.allocate_painter(egui::Vec2::splat(MAP_SIZE), egui::Sense::click());
+            let rect = response.rect.shrink(MAP_POINT_RADIUS * 2.0);
+            let to_map = |position: Vec2| {
+                let uv = (position - min) / extent;
+                rect.min + egui::vec2(uv.x, uv.y) * rect.width()
+            };
+            painter.rect_filled(response.rect, 4.0, ui.visuals().extreme_bg_color);
+            for &(spawn_point, position) in &points {
+                let color = if chosen == Some(spawn_point) {
+                    selection
+                } else {
+                    ui.visuals().text_color()
+                };
+                painter.circle_filled(to_map(position), MAP_POINT_RADIUS, color);
+            }
+            if response.clicked()
+                && let Some(click) = response.interact_pointer_pos()
+                && let Some(&(spawn_point, _)) = points
+                    .iter()
+                    .find(|&&(_, position)| to_map(position).distance(click) <= MAP_POINT_RADIUS)
+            {
+                chosen = Some(spawn_point);
+            }
+
+            for (index, &(spawn_point, position)) in points.iter().enumerate() {
+                let label = format!(
+                    "{} {} ({:.0}, {:.0})",
+                    localization.tr("respawn.point"),
+                    index + 1,
+                    position.x,
+                    position.y
+                );
+                ui.selectable_value(&mut chosen, Some(spawn_point), label);
+            }
+        });
+
+    if chosen != chooser.chosen
+        && let Some(spawn_point) = chosen
+    {
+        chooser.chosen = chosen;
+        choice_writer.write(ChooseSpawnPoint {
+            character,
+            spawn_point,
+        });
+    }
+    Ok(())
+}
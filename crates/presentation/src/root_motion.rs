@@ -0,0 +1,99 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    animation::AnimationTarget, app::AnimationSystems, prelude::*, transform::TransformSystems,
+};
+use bevy_rapier3d::prelude::Velocity;
+use merlo_simulation::controller::{CharacterMovementState, RootMotion};
+
+use crate::animation::{Animations, find_character};
+
+#[derive(Default)]
+pub struct RootMotionPlugin;
+
+impl Plugin for RootMotionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, find_root_bones).add_systems(
+            PostUpdate,
+            extract_root_motion
+                .after(AnimationSystems)
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// Longest distance the root may travel in a frame. Anything longer is a clip looping
+/// back to its start, or a transition between clips, rather than actual motion.
+const MAX_ROOT_STEP: f32 = 0.5;
+
+/// The bone carrying the root motion of the clips of a character.
+#[derive(Component)]
+pub struct RootBone {
+    character: Entity,
+    /// Translation in the bind pose, where the bone is kept horizontally.
+    rest: Vec3,
+    /// Animated translation of the previous frame.
+    previous: Option<Vec3>,
+}
+
+fn find_root_bones(
+    mut commands: Commands,
+    players: Query<&Animations, Added<Animations>>,
+    bones: Query<(Entity, &AnimationTarget, &Name, &Transform), Without<RootBone>>,
+    characters: Query<(Entity, &CharacterMovementState, Option<&Velocity>)>,
+    parents: Query<&ChildOf>,
+) {
+    if players.is_empty() {
+        return;
+    }
+    for (entity, target, name, transform) in &bones {
+        let Ok(animations) = players.get(target.player) else {
+            continue;
+        };
+        if name.as_str() != animations.root_bone() {
+            continue;
+        }
+        let Some((character, _, _)) = find_character(entity, &parents, &characters) else {
+            continue;
+        };
+        commands.entity(entity).insert(RootBone {
+            character,
+            rest: transform.translation,
+            previous: None,
+        });
+    }
+}
+
+/// Strips the horizontal translation of the root bone after the clips have been applied,
+/// so that the mesh never drifts out of the capsule of its character, and accumulates
+/// it into the [`RootMotion`] of characters moving by root motion.
+pub fn extract_root_motion(
+    mut bones: Query<(&mut RootBone, &mut Transform, &ChildOf), Without<CharacterMovementState>>,
+    global_transforms: Query<&GlobalTransform>,
+    mut characters: Query<(&Transform, Option<&mut RootMotion>), With<CharacterMovementState>>,
+) {
+    for (mut bone, mut transform, child_of) in &mut bones {
+        let translation = transform.translation;
+        let previous = bone.previous.replace(translation);
+        transform.translation.x = bone.rest.x;
+        transform.translation.z = bone.rest.z;
+
+        let (Some(previous), Ok(parent), Ok((character_transform, Some(mut root_motion)))) = (
+            previous,
+            global_transforms.get(child_of.parent()),
+            characters.get_mut(bone.character),
+        ) else {
+            continue;
+        };
+
+        // From the space of the armature to the local space of the character.
+        let world_delta = parent.affine().transform_vector3(translation - previous);
+        let mut delta = character_transform.rotation.inverse() * world_delta;
+        delta.y = 0.0;
+        if delta.length() <= MAX_ROOT_STEP {
+            root_motion.delta += delta;
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use merlo_model::ScoreEntry;
+use merlo_simulation::colors::ColorScheme;
+
+use crate::locale::Localization;
+
+#[derive(Default)]
+pub struct ScoreboardPlugin;
+
+impl Plugin for ScoreboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(EguiPrimaryContextPass, show_scoreboard);
+    }
+}
+
+/// Shows the scoreboard while Tab is held.
+fn show_scoreboard(
+    mut contexts: EguiContexts,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    entries: Query<&ScoreEntry>,
+    localization: Res<Localization>,
+    scheme: Res<ColorScheme>,
+) -> Result<()> {
+    if !keyboard_input.pressed(KeyCode::Tab) {
+        return Ok(());
+    }
+
+    let mut entries: Vec<_> = entries.iter().collect();
+    entries.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+
+    egui::Window::new(localization.tr("scoreboard.title"))
+        .id(egui::Id::new("scoreboard"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .collapsible(false)
+        .resizable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            egui::Grid::new("scoreboard_grid")
+                .striped(true)
+                .min_col_width(64.0)
+                .show(ui, |ui| {
+                    ui.strong(localization.tr("scoreboard.name"));
+                    ui.strong(localization.tr("scoreboard.team"));
+                    ui.strong(localization.tr("scoreboard.score"));
+                    ui.strong(localization.tr("scoreboard.ping"));
+                    ui.end_row();
+
+                    for entry in entries {
+                        ui.label(&entry.name);
+                        let [r, g, b, _] = scheme.team(entry.team).to_srgba().to_u8_array();
+                        ui.colored_label(
+                            egui::Color32::from_rgb(r, g, b),
+                            format!("{:?}", entry.team),
+                        );
+                        ui.label(entry.score.to_string());
+                        ui.label(format!("{} ms", entry.ping_ms));
+                        ui.end_row();
+                    }
+                });
+        });
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{
+    camera::Viewport,
+    prelude::*,
+    render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk},
+};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::locale::Localization;
+
+/// Directory where screenshots are saved, relative to the working directory.
+pub const SCREENSHOT_DIR: &str = "screenshots";
+
+/// How long the confirmation toast stays on screen.
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+pub struct ScreenshotPlugin;
+
+impl Plugin for ScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenshotSettings>()
+            .init_resource::<ScreenshotToast>()
+            .add_systems(Update, take_screenshot)
+            .add_systems(EguiPrimaryContextPass, show_toast);
+    }
+}
+
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ScreenshotSettings {
+    /// Whether to capture the whole window, or only the game view without the dock panels.
+    pub include_ui: bool,
+}
+
+impl Default for ScreenshotSettings {
+    fn default() -> Self {
+        Self { include_ui: true }
+    }
+}
+
+/// The path of the last saved screenshot, shown until the timer runs out.
+#[derive(Resource, Default)]
+struct ScreenshotToast {
+    path: Option<PathBuf>,
+    timer: Timer,
+}
+
+/// Formats a UNIX timestamp as `YYYYMMDD-hhmmss` in UTC.
+fn format_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, secs_of_day) = (secs / 86400, secs % 86400);
+
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}{month:02}{day:02}-{:02}{:02}{:02}",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
+}
+
+/// Returns a path in the screenshot directory that is not taken yet.
+fn screenshot_path() -> PathBuf {
+    let stem = format!("screenshot-{}", format_timestamp(SystemTime::now()));
+    let dir = Path::new(SCREENSHOT_DIR);
+    let mut path = dir.join(format!("{stem}.png"));
+    let mut index = 1;
+    while path.exists() {
+        path = dir.join(format!("{stem}-{index}.png"));
+        index += 1;
+    }
+    path
+}
+
+/// Saves only the region of the window covered by the viewport.
+fn save_viewport_to_disk(path: PathBuf, viewport: Viewport) -> impl FnMut(On<ScreenshotCaptured>) {
+    move |screenshot_captured| {
+        let image = match screenshot_captured.image.clone().try_into_dynamic() {
+            Ok(image) => image,
+            Err(err) => {
+                error!("Cannot convert screenshot: {err:?}");
+                return;
+            }
+        };
+        let UVec2 { x, y } = viewport.physical_position;
+        let UVec2 {
+            x: width,
+            y: height,
+        } = viewport.physical_size;
+        // The alpha channel stores brightness values when HDR is enabled.
+        let cropped = image.crop_imm(x, y, width, height).to_rgb8();
+        if let Err(err) = cropped.save(&path) {
+            error!("Cannot save screenshot to {}: {err}", path.display());
+        }
+    }
+}
+
+/// Captures the primary window with F12.
+fn take_screenshot(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<ScreenshotSettings>,
+    camera: Single<&Camera, With<Camera3d>>,
+    mut toast: ResMut<ScreenshotToast>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F12) {
+        return;
+    }
+
+    if let Err(err) = std::fs::create_dir_all(SCREENSHOT_DIR) {
+        error!("Cannot create {SCREENSHOT_DIR} directory: {err}");
+        return;
+    }
+
+    let path = screenshot_path();
+    let mut screenshot = commands.spawn(Screenshot::primary_window());
+    match camera.viewport.clone() {
+        Some(viewport) if !settings.include_ui => {
+            screenshot.observe(save_viewport_to_disk(path.clone(), viewport))
+        }
+        _ => screenshot.observe(save_to_disk(path.clone())),
+    };
+
+    toast.path = Some(path);
+    toast.timer = Timer::new(TOAST_DURATION, TimerMode::Once);
+}
+
+fn show_toast(
+    mut contexts: EguiContexts,
+    time: Res<Time>,
+    localization: Res<Localization>,
+    mut toast: ResMut<ScreenshotToast>,
+) -> Result<()> {
+    let Some(path) = &toast.path else {
+        return Ok(());
+    };
+    let text = format!("{} {}", localization.tr("screenshot.saved"), path.display());
+
+    egui::Area::new(egui::Id::new("screenshot_toast"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -32.0))
+        .interactable(false)
+        .show(contexts.ctx_mut()?, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| ui.label(text));
+        });
+
+    if toast.timer.tick(time.delta()).is_finished() {
+        toast.path = None;
+    }
+
+    Ok(())
+}
+
+/// Shows the screenshot options.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let label = world
+        .resource::<Localization>()
+        .tr("screenshot.include_ui")
+        .to_owned();
+    let mut settings = world.resource_mut::<ScreenshotSettings>();
+    let mut include_ui = settings.include_ui;
+    if ui.checkbox(&mut include_ui, label).changed() {
+        settings.include_ui = include_ui;
+    }
+}
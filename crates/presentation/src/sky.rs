@@ -0,0 +1,65 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{core_pipeline::Skybox, prelude::*};
+use merlo_model::Level;
+
+#[derive(Default)]
+pub struct SkyPlugin;
+
+impl Plugin for SkyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_level_sky);
+    }
+}
+
+const SKYBOX_BRIGHTNESS: f32 = 1000.0;
+const ENVIRONMENT_INTENSITY: f32 = 900.0;
+
+/// Cubemaps making up a sky, loaded from `skies/<name>/`.
+struct SkyImages {
+    skybox: Handle<Image>,
+    diffuse: Handle<Image>,
+    specular: Handle<Image>,
+}
+
+impl SkyImages {
+    fn load(asset_server: &AssetServer, sky: &str) -> Self {
+        Self {
+            skybox: asset_server.load(format!("skies/{sky}/skybox.ktx2")),
+            diffuse: asset_server.load(format!("skies/{sky}/diffuse.ktx2")),
+            specular: asset_server.load(format!("skies/{sky}/specular.ktx2")),
+        }
+    }
+}
+
+/// Applies the sky of the replicated [`Level`] to the game cameras, whenever it changes.
+fn apply_level_sky(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    levels: Query<&Level, Changed<Level>>,
+    cameras: Query<Entity, With<Camera3d>>,
+) {
+    let Some(level) = levels.iter().last() else {
+        return;
+    };
+
+    info!("loading sky {} for level {}", level.sky, level.name);
+    let images = SkyImages::load(&asset_server, &level.sky);
+    for camera in &cameras {
+        commands.entity(camera).insert((
+            Skybox {
+                image: images.skybox.clone(),
+                brightness: SKYBOX_BRIGHTNESS,
+                ..default()
+            },
+            EnvironmentMapLight {
+                diffuse_map: images.diffuse.clone(),
+                specular_map: images.specular.clone(),
+                intensity: ENVIRONMENT_INTENSITY,
+                ..default()
+            },
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use merlo_model::Carried;
+use merlo_simulation::{
+    carry::{ThrowCharge, throw_velocity},
+    colors::ColorScheme,
+    controller::CharacterController,
+};
+
+#[derive(Default)]
+pub struct ThrowPreviewPlugin;
+
+impl Plugin for ThrowPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, draw_throw_preview);
+    }
+}
+
+/// Seconds of flight previewed at most.
+const PREVIEW_TIME: f32 = 2.0;
+/// Seconds between two points of the previewed arc.
+const PREVIEW_STEP: f32 = 0.05;
+
+/// Draws the arc the carried doodad would fly along, while the local player charges a throw,
+/// up to where it would first hit something.
+#[allow(clippy::too_many_arguments)]
+fn draw_throw_preview(
+    throw_charge: Res<ThrowCharge>,
+    scheme: Res<ColorScheme>,
+    rapier_context: ReadRapierContext,
+    configuration: Query<&RapierConfiguration>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    characters: Query<(&Transform, Option<&Velocity>)>,
+    carried: Query<(Entity, &Carried, &Transform)>,
+    mut gizmos: Gizmos,
+) {
+    let Some(charge) = throw_charge.seconds() else {
+        return;
+    };
+    let Ok(character) = controller.single().map(ChildOf::parent) else {
+        return;
+    };
+    let Some((doodad, _, doodad_transform)) = carried
+        .iter()
+        .find(|(_, carried, _)| carried.by == character)
+    else {
+        return;
+    };
+    let Ok((transform, velocity)) = characters.get(character) else {
+        return;
+    };
+    let gravity = configuration
+        .single()
+        .map_or(Vec3::NEG_Y * 9.81, |configuration| configuration.gravity);
+    let start = doodad_transform.translation;
+    let velocity = velocity.map_or(Vec3::ZERO, |velocity| velocity.linvel)
+        + throw_velocity(transform.rotation, charge);
+    let rapier_context = rapier_context.single().ok();
+    let filter = QueryFilter::default()
+        .exclude_collider(doodad)
+        .exclude_collider(character)
+        .exclude_sensors();
+
+    let mut points = vec![start];
+    let steps = (PREVIEW_TIME / PREVIEW_STEP) as usize;
+    for step in 1..=steps {
+        let time = step as f32 * PREVIEW_STEP;
+        let point = start + velocity * time + 0.5 * gravity * time * time;
+        let previous = points[points.len() - 1];
+        let segment = point - previous;
+        let hit = rapier_context
+            .as_ref()
+            .and_then(|context| context.cast_ray(previous, segment, 1.0, true, filter));
+        if let Some((_, fraction)) = hit {
+            points.push(previous + segment * fraction);
+            break;
+        }
+        points.push(point);
+    }
+    gizmos.linestrip(points, scheme.selection);
+}
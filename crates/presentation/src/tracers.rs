@@ -0,0 +1,62 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_simulation::shooting::ShotFired;
+
+#[derive(Default)]
+pub struct TracerPlugin;
+
+impl Plugin for TracerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Tracers>()
+            .add_systems(Update, (spawn_tracers, draw_tracers).chain());
+    }
+}
+
+/// Seconds a tracer stays visible while fading out.
+const TRACER_LIFETIME: f32 = 0.2;
+/// Radius of the mark left where a shot hits a surface.
+const IMPACT_RADIUS: f32 = 0.1;
+const TRACER_COLOR: Color = Color::srgb(1.0, 0.9, 0.5);
+
+struct Tracer {
+    shot: ShotFired,
+    age: f32,
+}
+
+/// Shots fired recently, drawn until they fade out.
+#[derive(Resource, Default)]
+struct Tracers(Vec<Tracer>);
+
+fn spawn_tracers(mut shot_reader: MessageReader<ShotFired>, mut tracers: ResMut<Tracers>) {
+    tracers
+        .0
+        .extend(shot_reader.read().map(|&shot| Tracer { shot, age: 0.0 }));
+}
+
+fn draw_tracers(time: Res<Time>, mut tracers: ResMut<Tracers>, mut gizmos: Gizmos) {
+    tracers.0.retain_mut(|tracer| {
+        tracer.age += time.delta_secs();
+        tracer.age < TRACER_LIFETIME
+    });
+    for tracer in &tracers.0 {
+        let color = TRACER_COLOR.with_alpha(1.0 - tracer.age / TRACER_LIFETIME);
+        let ShotFired {
+            origin,
+            end,
+            impact_normal,
+            ..
+        } = tracer.shot;
+        gizmos.line(origin, end, color);
+        if let Some(normal) = impact_normal {
+            // Lift the mark off the surface so that it is not hidden by it.
+            let isometry = Isometry3d::new(
+                end + normal * 0.01,
+                Quat::from_rotation_arc(Vec3::Z, normal),
+            );
+            gizmos.circle(isometry, IMPACT_RADIUS, color);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use merlo_simulation::traffic::ReplicationTraffic;
+
+use crate::locale::Localization;
+
+/// Lists the replication traffic of the last second per component type, then the one of the
+/// connections, with the headers of replicon and the other messages.
+pub fn ui(world: &mut World, ui: &mut egui::Ui) {
+    let localization = world.resource::<Localization>();
+    let [empty, component, sent, received, connection] = [
+        "traffic.empty",
+        "traffic.component",
+        "traffic.sent",
+        "traffic.received",
+        "traffic.connection",
+    ]
+    .map(|key| localization.tr(key).to_owned());
+
+    let traffic = world.resource::<ReplicationTraffic>();
+    let mut rows: Vec<_> = traffic.last_second().collect();
+    if rows.is_empty() {
+        ui.label(empty);
+        return;
+    }
+    // The heaviest components first.
+    rows.sort_by_key(|(_, traffic)| std::cmp::Reverse(traffic.sent_bytes + traffic.received_bytes));
+
+    egui::Grid::new("traffic_grid")
+        .striped(true)
+        .num_columns(3)
+        .show(ui, |ui| {
+            ui.strong(component);
+            ui.strong(sent);
+            ui.strong(received);
+            ui.end_row();
+
+            for (name, traffic) in rows {
+                ui.monospace(name);
+                ui.monospace(format!(
+                    "{:>5} / {:>8} B",
+                    traffic.sent_updates, traffic.sent_bytes
+                ));
+                ui.monospace(format!(
+                    "{:>5} / {:>8} B",
+                    traffic.received_updates, traffic.received_bytes
+                ));
+                ui.end_row();
+            }
+
+            let (sent_bytes, received_bytes) = traffic.connection_bytes();
+            ui.strong(connection);
+            ui.monospace(format!("{:>5}   {sent_bytes:>8.0} B", ""));
+            ui.monospace(format!("{:>5}   {received_bytes:>8.0} B", ""));
+            ui.end_row();
+        });
+}
@@ -0,0 +1,91 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use bevy::{asset::io::file::FileAssetReader, prelude::*};
+use merlo_model::CharacterModel;
+use merlo_simulation::manifest::AssetManifest;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    animation::{CharacterAction, CharacterClip},
+    animation_set::AnimationSet,
+};
+
+/// Checks the assets in the `assets` folder without starting the game. The manifest, or the
+/// default one when there is none, and the animation set of its character are parsed. Its
+/// scene and the clips of its states and actions must exist as glTF files with a scene or
+/// an animation.
+/// Returns one problem for each file which fails, such as a clip missing for a state.
+pub fn validate_assets(assets: &str) -> Vec<String> {
+    let folder = FileAssetReader::get_base_path().join(assets);
+    let mut problems = Vec::new();
+
+    let path = AssetManifest::path(assets);
+    let manifest = if path.exists() {
+        parse_ron(&path).unwrap_or_else(|err| {
+            problems.push(err);
+            AssetManifest::default()
+        })
+    } else {
+        AssetManifest::default()
+    };
+
+    validate_character(&folder, &manifest.character, &mut problems);
+    problems
+}
+
+/// Checks the scene, animation set, and clips of a character model.
+fn validate_character(folder: &Path, model: &CharacterModel, problems: &mut Vec<String>) {
+    if let Err(err) = check_gltf(folder, &model.scene_path(), |gltf| gltf.scenes().len()) {
+        problems.push(format!("scene of {}: {err}", model.prefix));
+    }
+
+    let path = folder.join(AnimationSet::path(model));
+    let set = if path.exists() {
+        parse_ron(&path).unwrap_or_else(|err| {
+            problems.push(err);
+            AnimationSet::default()
+        })
+    } else {
+        AnimationSet::default()
+    };
+
+    let clips = CharacterClip::ALL
+        .map(|clip| (format!("{clip:?}"), set.clip_path(model, clip)))
+        .into_iter()
+        .chain(
+            CharacterAction::ALL
+                .map(|action| (format!("{action:?}"), set.action_path(model, action))),
+        );
+    for (name, clip) in clips {
+        if let Err(err) = check_gltf(folder, &clip, |gltf| gltf.animations().len()) {
+            problems.push(format!("{name} clip of {}: {err}", model.prefix));
+        }
+    }
+}
+
+/// Parses the RON file at `path`, with its path in the error.
+fn parse_ron<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let text = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    ron::from_str(&text).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Parses the glTF file at `path` under `folder`, failing unless it has some of what `count`
+/// counts.
+fn check_gltf(
+    folder: &Path,
+    path: &str,
+    count: impl Fn(&gltf::Gltf) -> usize,
+) -> Result<(), String> {
+    let file = folder.join(path);
+    let bytes = std::fs::read(&file).map_err(|err| format!("{}: {err}", file.display()))?;
+    let gltf =
+        gltf::Gltf::from_slice(&bytes).map_err(|err| format!("{}: {err}", file.display()))?;
+    if count(&gltf) == 0 {
+        return Err(format!("{}: nothing to play or show", file.display()));
+    }
+    Ok(())
+}
@@ -0,0 +1,201 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_model::{StaticGeometry, Weather, WeatherKind};
+
+#[derive(Default)]
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wetness>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    apply_fog,
+                    (emit_rain, update_rain).chain(),
+                    tint_wet_surfaces,
+                ),
+            );
+    }
+}
+
+/// Distance at which things fade into the fog at the thickest, and while it is about to lift.
+const FOG_VISIBILITY: f32 = 25.0;
+const CLEAR_VISIBILITY: f32 = 1000.0;
+/// How much of the thickest fog comes with the heaviest rain.
+const RAIN_HAZE: f32 = 0.6;
+const FOG_COLOR: Color = Color::srgb(0.6, 0.63, 0.68);
+/// Drops falling around the camera at the heaviest rain.
+const MAX_DROPS: f32 = 600.0;
+const DROP_SPEED: f32 = 18.0;
+/// Drops start falling within this distance from the camera, this far above it.
+const RAIN_RADIUS: f32 = 12.0;
+const RAIN_HEIGHT: f32 = 10.0;
+/// Seconds for surfaces to get soaked by the heaviest rain, and to dry back.
+const SOAK_TIME: f32 = 5.0;
+const DRY_TIME: f32 = 30.0;
+/// Color and roughness surfaces take when soaked, tinting the white level geometry.
+const WET_COLOR: Color = Color::srgb(0.55, 0.58, 0.62);
+const DRY_ROUGHNESS: f32 = 0.5;
+const WET_ROUGHNESS: f32 = 0.1;
+
+#[derive(Resource)]
+struct RainAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// How soaked surfaces are, from `0` when dry to `1`, following the rain with some delay.
+#[derive(Resource, Default)]
+struct Wetness(f32);
+
+#[derive(Component)]
+struct RainDrop {
+    age: f32,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(RainAssets {
+        mesh: meshes.add(Cuboid::new(0.01, 0.4, 0.01)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgba(0.7, 0.75, 0.85, 0.4),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Fogs the game cameras in the fog, and hazes them in the rain.
+fn apply_fog(
+    mut commands: Commands,
+    weathers: Query<&Weather>,
+    cameras: Query<Entity, With<Camera3d>>,
+) {
+    let weather = weathers.iter().next().copied().unwrap_or_default();
+    let haze = weather
+        .intensity_of(WeatherKind::Fog)
+        .max(weather.intensity_of(WeatherKind::Rain) * RAIN_HAZE);
+    for camera in &cameras {
+        if haze <= 0.0 {
+            commands.entity(camera).remove::<DistanceFog>();
+            continue;
+        }
+        // Geometric, so that the fog thickens evenly from afar.
+        let visibility = CLEAR_VISIBILITY * (FOG_VISIBILITY / CLEAR_VISIBILITY).powf(haze);
+        commands.entity(camera).insert(DistanceFog {
+            color: FOG_COLOR,
+            falloff: FogFalloff::from_visibility(visibility),
+            ..default()
+        });
+    }
+}
+
+/// Spawns drops above the camera, as many as the rain is heavy.
+fn emit_rain(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<RainAssets>,
+    weathers: Query<&Weather>,
+    camera: Query<&GlobalTransform, With<Camera3d>>,
+    mut pending: Local<f32>,
+    mut seed: Local<u32>,
+) {
+    let rain = weathers
+        .iter()
+        .next()
+        .map_or(0.0, |weather| weather.intensity_of(WeatherKind::Rain));
+    let Ok(camera) = camera.single() else {
+        return;
+    };
+    if rain <= 0.0 {
+        *pending = 0.0;
+        return;
+    }
+
+    // Drops live long enough to fall to as far below the camera as they start above it.
+    let lifetime = 2.0 * RAIN_HEIGHT / DROP_SPEED;
+    *pending += MAX_DROPS * rain / lifetime * time.delta_secs();
+    while *pending >= 1.0 {
+        *pending -= 1.0;
+        let distance = RAIN_RADIUS * next_unit(&mut seed).sqrt();
+        let angle = std::f32::consts::TAU * next_unit(&mut seed);
+        let offset = Vec3::new(angle.cos() * distance, RAIN_HEIGHT, angle.sin() * distance);
+        commands.spawn((
+            RainDrop { age: 0.0 },
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_translation(camera.translation() + offset),
+        ));
+    }
+}
+
+/// Xorshift, good enough to scatter drops.
+fn next_unit(seed: &mut u32) -> f32 {
+    if *seed == 0 {
+        *seed = 0x9e37_79b9;
+    }
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 17;
+    *seed ^= *seed << 5;
+    (*seed >> 8) as f32 / (1u32 << 24) as f32
+}
+
+fn update_rain(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut drops: Query<(Entity, &mut RainDrop, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    let lifetime = 2.0 * RAIN_HEIGHT / DROP_SPEED;
+    for (entity, mut drop, mut transform) in &mut drops {
+        drop.age += dt;
+        if drop.age >= lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation.y -= DROP_SPEED * dt;
+    }
+}
+
+/// Darkens the level geometry and makes it glossy while it is wet.
+fn tint_wet_surfaces(
+    time: Res<Time>,
+    mut wetness: ResMut<Wetness>,
+    weathers: Query<&Weather>,
+    geometries: Query<Ref<MeshMaterial3d<StandardMaterial>>, With<StaticGeometry>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let rain = weathers
+        .iter()
+        .next()
+        .map_or(0.0, |weather| weather.intensity_of(WeatherKind::Rain));
+    let rate = if rain > wetness.0 {
+        SOAK_TIME
+    } else {
+        DRY_TIME
+    };
+    let step = time.delta_secs() / rate;
+    let target = wetness.0 + (rain - wetness.0).clamp(-step, step);
+    let changed = target != wetness.0;
+    wetness.0 = target;
+
+    // Geometry spawned in the meanwhile is as wet as the rest.
+    for material in &geometries {
+        if !changed && !material.is_added() {
+            continue;
+        }
+        if let Some(material) = materials.get_mut(&*material) {
+            material.base_color = Color::WHITE.mix(&WET_COLOR, wetness.0);
+            material.perceptual_roughness = DRY_ROUGHNESS.lerp(WET_ROUGHNESS, wetness.0);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    prelude::*,
+    window::{MonitorSelection, PresentMode, VideoModeSelection, WindowMode, WindowResolution},
+};
+use clap::{Args, ValueEnum};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WindowModeArg {
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+/// Options for the primary window.
+#[derive(Debug, Clone, Args)]
+pub struct WindowArgs {
+    /// Window width in pixels.
+    #[arg(long, global = true, env = "MERLO_WIDTH", default_value_t = 1280)]
+    width: u32,
+
+    /// Window height in pixels.
+    #[arg(long, global = true, env = "MERLO_HEIGHT", default_value_t = 720)]
+    height: u32,
+
+    /// Whether to run windowed, borderless fullscreen, or exclusive fullscreen.
+    #[arg(
+        long,
+        global = true,
+        env = "MERLO_WINDOW_MODE",
+        value_enum,
+        default_value_t = WindowModeArg::Windowed
+    )]
+    window_mode: WindowModeArg,
+
+    /// Disable vertical synchronization.
+    #[arg(long, global = true, env = "MERLO_NO_VSYNC")]
+    no_vsync: bool,
+
+    /// Title of the window.
+    #[arg(long, global = true, env = "MERLO_TITLE", default_value = "Merlo")]
+    title: String,
+}
+
+impl WindowArgs {
+    pub fn window(&self) -> Window {
+        let mode = match self.window_mode {
+            WindowModeArg::Windowed => WindowMode::Windowed,
+            WindowModeArg::Borderless => {
+                WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+            }
+            WindowModeArg::Fullscreen => {
+                WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+            }
+        };
+        let present_mode = if self.no_vsync {
+            PresentMode::AutoNoVsync
+        } else {
+            PresentMode::AutoVsync
+        };
+
+        Window {
+            title: self.title.clone(),
+            resolution: WindowResolution::new(self.width, self.height),
+            mode,
+            present_mode,
+            ..default()
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_model::TriggerZone;
+use merlo_simulation::{
+    colors::ColorScheme,
+    controller::CharacterController,
+    trigger_zone::{ZoneEntered, ZoneExited},
+};
+
+#[derive(Default)]
+pub struct ZonesPlugin;
+
+impl Plugin for ZonesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LocalZones>()
+            .add_systems(Update, (track_local_zones, draw_zones).chain());
+    }
+}
+
+const ZONE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.3);
+
+/// Zones the local character is in, as reported by the server.
+#[derive(Resource, Default)]
+struct LocalZones(Vec<Entity>);
+
+fn track_local_zones(
+    mut entered_reader: MessageReader<ZoneEntered>,
+    mut exited_reader: MessageReader<ZoneExited>,
+    mut local_zones: ResMut<LocalZones>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    zones: Query<&TriggerZone>,
+) {
+    let local_character = controller.single().ok().map(ChildOf::parent);
+    for entered in entered_reader.read() {
+        if Some(entered.character) == local_character {
+            if let Ok(zone) = zones.get(entered.zone) {
+                info!("Entered {}", zone.name);
+            }
+            local_zones.0.push(entered.zone);
+        }
+    }
+    for exited in exited_reader.read() {
+        if Some(exited.character) == local_character {
+            local_zones.0.retain(|&zone| zone != exited.zone);
+        }
+    }
+}
+
+/// Outlines every zone, highlighting those the local character is in.
+fn draw_zones(
+    local_zones: Res<LocalZones>,
+    scheme: Res<ColorScheme>,
+    zones: Query<(Entity, &TriggerZone, &GlobalTransform)>,
+    mut gizmos: Gizmos,
+) {
+    for (entity, zone, transform) in &zones {
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let color = if local_zones.0.contains(&entity) {
+            scheme.selection
+        } else {
+            ZONE_COLOR
+        };
+        gizmos.cuboid(
+            Transform::from_translation(translation)
+                .with_rotation(rotation)
+                .with_scale(zone.size),
+            color,
+        );
+    }
+}
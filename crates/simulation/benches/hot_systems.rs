@@ -0,0 +1,278 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, Instant};
+
+use bevy::{
+    ecs::schedule::{NodeId, ScheduleLabel, Stepping},
+    prelude::*,
+};
+use bevy_replicon::{
+    prelude::*, server::server_tick::ServerTick, shared::backend::server_messages::ServerMessages,
+};
+use bevy_replicon_renet::{
+    RenetChannelsExt,
+    renet::{ConnectionConfig, RenetClient, RenetServer},
+};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use merlo_model::{Health, Player, StaticGeometry};
+use merlo_simulation::{
+    PLAYER_HEALTH, SimulationPlugin,
+    controller::{self, CharacterDimensions, MovementAction},
+    network::{Cli, DEFAULT_PORT},
+    physics::PhysicsArgs,
+};
+
+/// Numbers of characters each system is measured with.
+const CHARACTERS: [usize; 3] = [1, 64, 512];
+/// Meters between characters, standing on a grid.
+const SPACING: f32 = 2.0;
+/// Frames the characters are given to land before measuring.
+const SETTLE_FRAMES: usize = 60;
+/// Network id of the client receiving the replication.
+const CLIENT_ID: u64 = 1;
+
+/// A headless server with `count` characters standing on a flat ground, and a client
+/// connected in memory.
+struct Server {
+    app: App,
+    characters: Vec<Entity>,
+    client: RenetClient,
+}
+
+impl Server {
+    fn new(count: usize) -> Self {
+        let mut app = merlo_simulation::stepped_app(
+            Cli::server(DEFAULT_PORT).loopback(),
+            PhysicsArgs::default(),
+            SimulationPlugin::default().with_demo_scene(false),
+        );
+        app.update();
+
+        let side = (count as f32).sqrt().ceil() as usize;
+        let size = side as f32 * SPACING + SPACING;
+        let world = app.world_mut();
+        world.spawn((
+            Replicated,
+            Transform::from_xyz(0.0, -0.5, 0.0),
+            StaticGeometry::Cuboid {
+                size: Vec3::new(size, 1.0, size),
+            },
+        ));
+        let height = CharacterDimensions::DEFAULT.origin_to_foot();
+        let characters = (0..count)
+            .map(|index| {
+                let (column, row) = ((index % side) as f32, (index / side) as f32);
+                let corner = -(side as f32 - 1.0) * SPACING / 2.0;
+                let translation =
+                    Vec3::new(corner + column * SPACING, height, corner + row * SPACING);
+                world
+                    .spawn((
+                        Replicated,
+                        Transform::from_translation(translation),
+                        Player::default(),
+                        Health::new(PLAYER_HEALTH),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        let channels = world.resource::<RepliconChannels>();
+        let mut client = RenetClient::new(ConnectionConfig {
+            server_channels_config: channels.server_configs(),
+            client_channels_config: channels.client_configs(),
+            ..Default::default()
+        });
+        client.set_connected();
+        world
+            .resource_mut::<RenetServer>()
+            .add_connection(CLIENT_ID);
+        let mut server = Self {
+            app,
+            characters,
+            client,
+        };
+        server.update();
+        // The client skips the protocol check, as it shares the protocol of the server.
+        let world = server.app.world_mut();
+        let clients: Vec<_> = world
+            .query_filtered::<Entity, With<ConnectedClient>>()
+            .iter(world)
+            .collect();
+        for client in clients {
+            world.entity_mut(client).insert(AuthorizedClient);
+        }
+        for _ in 0..SETTLE_FRAMES {
+            server.update();
+        }
+        server
+    }
+
+    fn update(&mut self) {
+        self.app.update();
+        self.exchange();
+    }
+
+    /// Delivers the packets of the server to the client, and drops them.
+    fn exchange(&mut self) {
+        let mut server = self.app.world_mut().resource_mut::<RenetServer>();
+        if let Err(err) = server.process_local_client(CLIENT_ID, &mut self.client) {
+            panic!("cannot exchange packets with the client: {err}");
+        }
+        let channels = self.app.world().resource::<RepliconChannels>();
+        for channel_id in 0..channels.server_channels().len() as u8 {
+            while self.client.receive_message(channel_id).is_some() {}
+        }
+    }
+
+    /// Times `iters` runs of `measure`, each after an untimed `prepare`.
+    fn time(
+        &mut self,
+        iters: u64,
+        mut prepare: impl FnMut(&mut Self),
+        mut measure: impl FnMut(&mut World),
+        mut finish: impl FnMut(&mut Self),
+    ) -> Duration {
+        let mut total = Duration::ZERO;
+        for _ in 0..iters {
+            prepare(self);
+            let start = Instant::now();
+            measure(self.app.world_mut());
+            total += start.elapsed();
+            finish(self);
+        }
+        total
+    }
+}
+
+/// Lets only the systems of `set` run in `schedule` from now on, by stepping the schedule
+/// and always running them.
+fn run_only(world: &mut World, schedule: impl ScheduleLabel + Clone, set: impl SystemSet) {
+    let set = set.intern();
+    let graph = world
+        .resource::<Schedules>()
+        .get(schedule.clone())
+        .map(Schedule::graph);
+    let Some(graph) = graph else {
+        panic!("{schedule:?} should exist");
+    };
+    let Some((key, ..)) = graph
+        .system_sets
+        .iter()
+        .find(|(_, other, _)| *other == &*set)
+    else {
+        panic!("{schedule:?} should have {set:?}");
+    };
+    let mut nodes = vec![NodeId::Set(key)];
+    let mut systems = Vec::new();
+    while let Some(node) = nodes.pop() {
+        for child in graph.hierarchy().graph().neighbors(node) {
+            match child {
+                NodeId::System(_) => systems.push(child),
+                NodeId::Set(_) => nodes.push(child),
+            }
+        }
+    }
+
+    let mut stepping = Stepping::new();
+    stepping.add_schedule(schedule.clone()).enable();
+    for system in systems {
+        stepping.always_run_node(schedule.clone(), system);
+    }
+    world.insert_resource(stepping);
+    run_system(world, Stepping::begin_frame);
+}
+
+fn run_system<M>(world: &mut World, system: impl IntoSystem<(), (), M> + 'static) {
+    if let Err(err) = world.run_system_cached(system) {
+        panic!("cannot run the system: {err}");
+    }
+}
+
+/// Applies a movement input to every character.
+fn movement(c: &mut Criterion) {
+    let mut group = c.benchmark_group("movement");
+    for count in CHARACTERS {
+        let mut server = Server::new(count);
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter_custom(|iters| {
+                server.time(
+                    iters,
+                    |server| {
+                        let world = server.app.world_mut();
+                        for &character in &server.characters {
+                            world.write_message(FromClient {
+                                client_id: ClientId::Server,
+                                message: MovementAction::SetMove(character, Vec3::Z),
+                            });
+                        }
+                    },
+                    |world| run_system(world, controller::movement),
+                    |server| {
+                        server
+                            .app
+                            .world_mut()
+                            .resource_mut::<Messages<FromClient<MovementAction>>>()
+                            .update();
+                    },
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Casts the ground probe of every character.
+fn update_grounded(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update_grounded");
+    for count in CHARACTERS {
+        let mut server = Server::new(count);
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter_custom(|iters| {
+                server.time(
+                    iters,
+                    |_| {},
+                    |world| run_system(world, controller::update_grounded),
+                    |_| {},
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Serializes the mutated transforms of every character for the connected client, without
+/// the rest of [`PostUpdate`], such as the writeback of the physics, nor sending the packets.
+fn replication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("replication");
+    for count in CHARACTERS {
+        let mut server = Server::new(count);
+        run_only(server.app.world_mut(), PostUpdate, ServerSystems::Send);
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter_custom(|iters| {
+                server.time(
+                    iters,
+                    |server| {
+                        let world = server.app.world_mut();
+                        for &character in &server.characters {
+                            if let Some(mut transform) = world.get_mut::<Transform>(character) {
+                                transform.rotate_y(0.01);
+                            }
+                        }
+                        world.resource_mut::<ServerTick>().increment();
+                    },
+                    |world| world.run_schedule(PostUpdate),
+                    |server| {
+                        let world = server.app.world_mut();
+                        for _ in world.resource_mut::<ServerMessages>().drain_sent() {}
+                    },
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, movement, update_grounded, replication);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use bevy_replicon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::controller::{MovementAction, has_server_authority};
+
+pub struct AdminPlugin;
+
+impl Plugin for AdminPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_client_message::<AdminCommand>(Channel::Ordered)
+            .add_systems(
+                Update,
+                (track_controlled_characters, run_admin_commands).run_if(has_server_authority),
+            );
+    }
+}
+
+/// A [`Message`] written by the host to moderate a connected client.
+///
+/// Clients are identified by their server-side entity, so commands are only accepted from the
+/// server itself, never from remote clients.
+#[derive(Message, Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum AdminCommand {
+    /// Disconnects the client.
+    Kick { client: Entity },
+    /// Drops chat messages of the client while muted.
+    Mute { client: Entity, muted: bool },
+    /// Moves the `character` of the host next to the character controlled by the client.
+    TeleportTo { client: Entity, character: Entity },
+}
+
+/// Marks a connected client whose chat messages are not relayed.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Muted;
+
+/// The character a connected client is controlling, as seen by its last movement input.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlledCharacter(pub Entity);
+
+/// Distance from the target character where a teleported character lands.
+const TELEPORT_OFFSET: Vec3 = Vec3::new(1.5, 0.0, 0.0);
+
+fn track_controlled_characters(
+    mut commands: Commands,
+    mut movement_reader: MessageReader<FromClient<MovementAction>>,
+    clients: Query<Option<&ControlledCharacter>, With<ConnectedClient>>,
+) {
+    for event in movement_reader.read() {
+        let ClientId::Client(client) = event.client_id else {
+            continue;
+        };
+        let character = ControlledCharacter(event.message.entity());
+        // Avoid touching the component for every input of the same character.
+        if let Ok(controlled) = clients.get(client)
+            && controlled != Some(&character)
+        {
+            commands.entity(client).insert(character);
+        }
+    }
+}
+
+fn run_admin_commands(
+    mut commands: Commands,
+    mut admin_reader: MessageReader<FromClient<AdminCommand>>,
+    mut disconnect_writer: MessageWriter<DisconnectRequest>,
+    clients: Query<Option<&ControlledCharacter>, With<ConnectedClient>>,
+    mut characters: Query<(&mut Transform, Option<&mut Velocity>)>,
+) {
+    for event in admin_reader.read() {
+        if event.client_id != ClientId::Server {
+            warn!(
+                "Ignoring admin command from non-host client {:?}",
+                event.client_id
+            );
+            continue;
+        }
+
+        match event.message {
+            AdminCommand::Kick { client } => {
+                if clients.contains(client) {
+                    info!("Kicking client {client}");
+                    disconnect_writer.write(DisconnectRequest { client });
+                }
+            }
+            AdminCommand::Mute { client, muted } => {
+                if !clients.contains(client) {
+                    continue;
+                }
+                if muted {
+                    commands.entity(client).insert(Muted);
+                } else {
+                    commands.entity(client).remove::<Muted>();
+                }
+            }
+            AdminCommand::TeleportTo { client, character } => {
+                let Ok(Some(target)) = clients.get(client) else {
+                    continue;
+                };
+                let Ok((target_transform, _)) = characters.get(target.0) else {
+                    continue;
+                };
+                let destination = target_transform.translation + TELEPORT_OFFSET;
+                let Ok((mut transform, velocity)) = characters.get_mut(character) else {
+                    continue;
+                };
+                transform.translation = destination;
+                if let Some(mut velocity) = velocity {
+                    *velocity = Velocity::zero();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_rapier3d::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::{DamageKind, Dead, Health};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin::ControlledCharacter,
+    controller::{CharacterController, ExternalVelocity, has_server_authority},
+    damage::DamageDealt,
+    game_mode,
+};
+
+pub struct AttackPlugin;
+
+impl Plugin for AttackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_client_message::<AttackInput>(Channel::Ordered)
+            .add_mapped_server_message::<AttackStarted>(Channel::Ordered)
+            .add_mapped_server_message::<AttackHit>(Channel::Unordered)
+            .add_systems(Update, keyboard_input)
+            .add_systems(
+                Update,
+                (
+                    start_attacks.run_if(not(game_mode::in_warmup)),
+                    sweep_attacks,
+                )
+                    .chain()
+                    .run_if(has_server_authority),
+            );
+    }
+}
+
+/// Timing and reach of a melee attack, authored to match the swing of its clip.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttackDefinition {
+    /// Seconds from the start of the swing until another attack can start.
+    pub duration: f32,
+    /// Seconds from the start of the swing when the weapon can hit, and when it stops.
+    pub hit_window: (f32, f32),
+    /// Distance in front of the attacker of the center of the swept sphere.
+    pub reach: f32,
+    pub radius: f32,
+    pub damage: f32,
+    /// Horizontal speed in meters per second given to targets away from the attacker.
+    pub knockback: f32,
+}
+
+/// The attack played by the `attack` clip of characters.
+pub const MELEE_ATTACK: AttackDefinition = AttackDefinition {
+    duration: 0.9,
+    hit_window: (0.3, 0.5),
+    reach: 1.0,
+    radius: 0.6,
+    damage: 20.0,
+    knockback: 6.0,
+};
+
+/// A [`Message`] written by a client to attack with its character.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct AttackInput {
+    #[entities]
+    pub character: Entity,
+}
+
+/// A [`Message`] broadcast by the server whenever a character starts an attack,
+/// so that every client plays its swing.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct AttackStarted {
+    #[entities]
+    pub character: Entity,
+}
+
+impl AttackStarted {
+    /// Wraps the attack in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// A [`Message`] broadcast by the server whenever an attack hits, so that clients confirm
+/// the hit with effects and a brief hitstop.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct AttackHit {
+    #[entities]
+    pub attacker: Entity,
+    #[entities]
+    pub target: Entity,
+    /// Where the swing met the target.
+    pub point: Vec3,
+}
+
+impl AttackHit {
+    /// Wraps the hit in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// An attack in progress on the server.
+#[derive(Component)]
+struct Attacking {
+    elapsed: f32,
+    /// Entities already hit by this swing, so that each is hit once.
+    hit: Vec<Entity>,
+}
+
+/// Sends [`AttackInput`] for the controlled character.
+fn keyboard_input(
+    mut attack_writer: MessageWriter<AttackInput>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+) {
+    let Ok(child) = controller.single() else {
+        return;
+    };
+    if keyboard_input.just_pressed(KeyCode::KeyF) {
+        attack_writer.write(AttackInput {
+            character: child.parent(),
+        });
+    }
+}
+
+/// Starts the attacks of clients with the character they control, unless it is dead or
+/// already attacking.
+#[allow(clippy::type_complexity)]
+fn start_attacks(
+    mut commands: Commands,
+    mut attack_reader: MessageReader<FromClient<AttackInput>>,
+    mut attack_writer: MessageWriter<ToClients<AttackStarted>>,
+    clients: Query<&ControlledCharacter>,
+    characters: Query<(), (With<Health>, Without<Dead>, Without<Attacking>)>,
+) {
+    for event in attack_reader.read() {
+        let character = event.message.character;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(character))
+        {
+            warn!(
+                "Ignoring attack of {:?} for character {character} it does not control",
+                event.client_id
+            );
+            continue;
+        }
+        if !characters.contains(character) {
+            continue;
+        }
+        commands.entity(character).insert(Attacking {
+            elapsed: 0.0,
+            hit: Vec::new(),
+        });
+        attack_writer.write(AttackStarted { character }.broadcast());
+    }
+}
+
+/// Damages and knocks back whatever is in front of attacking characters during the hit
+/// window of their swing.
+#[allow(clippy::too_many_arguments)]
+fn sweep_attacks(
+    mut commands: Commands,
+    time: Res<Time>,
+    rapier_context: ReadRapierContext,
+    mut damage_writer: MessageWriter<ToClients<DamageDealt>>,
+    mut hit_writer: MessageWriter<ToClients<AttackHit>>,
+    mut attackers: Query<(Entity, &mut Attacking, &Transform)>,
+    mut targets: Query<(&mut Health, &Transform, Option<&mut ExternalVelocity>), Without<Dead>>,
+) {
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    let attack = MELEE_ATTACK;
+    let sphere = Collider::ball(attack.radius);
+
+    for (attacker, mut attacking, transform) in &mut attackers {
+        attacking.elapsed += time.delta_secs();
+        if attacking.elapsed >= attack.duration {
+            commands.entity(attacker).remove::<Attacking>();
+            continue;
+        }
+        let (start, end) = attack.hit_window;
+        if attacking.elapsed < start || attacking.elapsed > end {
+            continue;
+        }
+
+        let center = transform.translation + transform.rotation * Vec3::Z * attack.reach;
+        let filter = QueryFilter::default().exclude_collider(attacker);
+        let mut hit = Vec::new();
+        rapier_context.intersect_shape(center, Quat::IDENTITY, &*sphere.raw, filter, |entity| {
+            hit.push(entity);
+            true
+        });
+        for target in hit {
+            if attacking.hit.contains(&target) {
+                continue;
+            }
+            let Ok((mut health, target_transform, external)) = targets.get_mut(target) else {
+                continue;
+            };
+            attacking.hit.push(target);
+            health.current = (health.current - attack.damage).max(0.0);
+            damage_writer.write(
+                DamageDealt {
+                    target,
+                    source: Some(attacker),
+                    amount: attack.damage,
+                    kind: DamageKind::Physical,
+                }
+                .broadcast(),
+            );
+
+            // Knocked back characters slow down as their movement is damped.
+            if let Some(mut external) = external {
+                let away = (target_transform.translation - transform.translation)
+                    .with_y(0.0)
+                    .normalize_or(transform.rotation * Vec3::Z);
+                external.0 = away * attack.knockback;
+            }
+            hit_writer.write(
+                AttackHit {
+                    attacker,
+                    target,
+                    point: target_transform.translation.lerp(center, 0.5),
+                }
+                .broadcast(),
+            );
+        }
+    }
+}
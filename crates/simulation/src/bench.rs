@@ -0,0 +1,295 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Sleeping;
+use bevy_replicon::{prelude::*, shared::backend::connected_client::NetworkId};
+use bevy_replicon_renet::renet::{RenetClient, RenetServer};
+use merlo_model::{Doodad, Health, Player, SpawnPoint};
+
+use crate::{
+    PLAYER_HEALTH,
+    controller::{CharacterControllerSet, MovementAction},
+    death,
+    network::{self, Cli},
+    traffic::ReplicationTraffic,
+};
+
+/// Drives a headless server with scripted fake clients for `--bench`, then prints how it
+/// performed.
+pub struct BenchPlugin;
+
+impl Plugin for BenchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BenchStats>()
+            .add_systems(First, start_tick.run_if(is_benchmarking))
+            .add_systems(
+                Update,
+                (
+                    connect_fake_clients,
+                    authorize_fake_clients,
+                    send_fake_inputs,
+                )
+                    .chain()
+                    .before(CharacterControllerSet::Movement)
+                    .run_if(is_benchmarking),
+            )
+            .add_systems(
+                PostUpdate,
+                exchange_fake_packets
+                    .after(ServerSystems::SendPackets)
+                    .run_if(is_benchmarking),
+            )
+            .add_systems(Last, (end_tick, report).chain().run_if(is_benchmarking));
+    }
+}
+
+/// Network ids of fake clients start from here, far from the ones of real clients.
+const FAKE_CLIENT_ID: u64 = u64::MAX - u16::MAX as u64;
+/// Seconds each step of the input script lasts.
+const SCRIPT_STEP: f32 = 1.5;
+/// Steps of the input script, as movement direction, rotation, and whether to jump.
+const SCRIPT: [(Vec3, f32, bool); 4] = [
+    (Vec3::Z, 0.0, false),
+    (Vec3::Z, 0.5, false),
+    (Vec3::Z, 0.0, true),
+    (Vec3::NEG_Z, -0.5, false),
+];
+
+fn is_benchmarking(cli: Res<Cli>) -> bool {
+    cli.bench().is_some()
+}
+
+/// A client connected in memory to the server, whose inputs follow the [`SCRIPT`].
+struct FakeClient {
+    id: u64,
+    connection: RenetClient,
+    /// Client entity spawned by the server once it processes the connection.
+    entity: Option<Entity>,
+    character: Entity,
+}
+
+#[derive(Resource, Default)]
+struct BenchStats {
+    clients: Vec<FakeClient>,
+    /// Whether all the fake clients are connected, and the measures started.
+    running: bool,
+    tick_start: Option<Instant>,
+    tick_times: Vec<Duration>,
+    /// Seconds measured so far, since the fake clients connected.
+    elapsed: f32,
+    /// Bytes of replication messages received by all the fake clients.
+    received_bytes: usize,
+}
+
+/// Connects the fake clients as soon as the server runs, each with its own character.
+fn connect_fake_clients(
+    mut commands: Commands,
+    cli: Res<Cli>,
+    channels: Res<RepliconChannels>,
+    server: Option<ResMut<RenetServer>>,
+    spawn_points: Query<&Transform, With<SpawnPoint>>,
+    mut connected: Local<bool>,
+    mut stats: ResMut<BenchStats>,
+) {
+    let (Some(mut server), Some((_, count))) = (server, cli.bench()) else {
+        return;
+    };
+    if *connected {
+        return;
+    }
+    *connected = true;
+
+    let spawn_points: Vec<_> = spawn_points.iter().map(|t| t.translation).collect();
+    for index in 0..count {
+        let spawn_point = spawn_points
+            .get(index % spawn_points.len().max(1))
+            .copied()
+            .unwrap_or_default();
+        // Side by side, so that characters sharing a spawn point do not overlap.
+        let offset = Vec3::X * (index / spawn_points.len().max(1)) as f32 * 1.5;
+        let character = commands
+            .spawn((
+                Replicated,
+                Transform::from_translation(spawn_point + offset + Vec3::Y * death::SPAWN_HEIGHT),
+                Player::default(),
+                Health::new(PLAYER_HEALTH),
+            ))
+            .id();
+        // Not `RenetServer::new_local_client`, which gives the client the channels of the
+        // server, so that it drops every replication message but the reliable ones.
+        let id = FAKE_CLIENT_ID + index as u64;
+        let mut connection = RenetClient::new(network::connection_config(&channels));
+        connection.set_connected();
+        server.add_connection(id);
+        stats.clients.push(FakeClient {
+            id,
+            connection,
+            entity: None,
+            character,
+        });
+    }
+    stats.running = count == 0;
+    info!("Connecting {count} fake clients");
+}
+
+/// Fake clients skip the protocol check, as they share the protocol of the server.
+fn authorize_fake_clients(
+    mut commands: Commands,
+    clients: Query<(Entity, &NetworkId), Added<ConnectedClient>>,
+    mut stats: ResMut<BenchStats>,
+) {
+    for (entity, network_id) in &clients {
+        let Some(client) = stats
+            .clients
+            .iter_mut()
+            .find(|client| client.id == network_id.get())
+        else {
+            continue;
+        };
+        client.entity = Some(entity);
+        commands.entity(entity).insert(AuthorizedClient);
+        stats.running = stats.clients.iter().all(|client| client.entity.is_some());
+    }
+}
+
+/// Writes the inputs of the fake clients as the server would receive them over the network,
+/// every frame as a gamepad does.
+fn send_fake_inputs(
+    stats: Res<BenchStats>,
+    mut movement_writer: MessageWriter<FromClient<MovementAction>>,
+) {
+    for (index, client) in stats.clients.iter().enumerate() {
+        let Some(entity) = client.entity else {
+            continue;
+        };
+        // Clients are out of step with each other, like real players.
+        let step = ((stats.elapsed + index as f32 * 0.37) / SCRIPT_STEP) as usize;
+        let (direction, rotation, jump) = SCRIPT[step % SCRIPT.len()];
+        let character = client.character;
+        for message in [
+            MovementAction::SetMove(character, direction),
+            MovementAction::SetRotate(character, rotation),
+            MovementAction::SetJump(character, jump),
+        ] {
+            movement_writer.write(FromClient {
+                client_id: ClientId::Client(entity),
+                message,
+            });
+        }
+    }
+}
+
+/// Delivers the packets sent by the server to the fake clients, and their acknowledgements
+/// back to the server.
+///
+/// Fake clients do not acknowledge replication mutations, so the server keeps sending them
+/// as it would to clients behind a lossy connection.
+fn exchange_fake_packets(
+    time: Res<Time>,
+    channels: Res<RepliconChannels>,
+    server: Option<ResMut<RenetServer>>,
+    mut stats: ResMut<BenchStats>,
+) {
+    let Some(mut server) = server else {
+        return;
+    };
+    let stats = &mut *stats;
+    for client in &mut stats.clients {
+        client.connection.update(time.delta());
+        if let Err(err) = server.process_local_client(client.id, &mut client.connection) {
+            error!(
+                "Cannot exchange packets with fake client {}: {err}",
+                client.id
+            );
+            continue;
+        }
+        for channel_id in 0..channels.server_channels().len() as u8 {
+            while let Some(message) = client.connection.receive_message(channel_id) {
+                stats.received_bytes += message.len();
+            }
+        }
+    }
+}
+
+fn start_tick(mut stats: ResMut<BenchStats>) {
+    stats.tick_start = Some(Instant::now());
+}
+
+fn end_tick(time: Res<Time>, mut stats: ResMut<BenchStats>) {
+    // Loading the level is not part of the measure.
+    if let Some(start) = stats.tick_start.take()
+        && stats.running
+    {
+        stats.tick_times.push(start.elapsed());
+        stats.elapsed += time.delta_secs();
+    }
+}
+
+/// Prints the statistics and exits once the benchmark lasted as long as requested.
+#[allow(clippy::too_many_arguments)]
+fn report(
+    cli: Res<Cli>,
+    stats: Res<BenchStats>,
+    traffic: Res<ReplicationTraffic>,
+    entities: Query<()>,
+    replicated: Query<(), With<Replicated>>,
+    players: Query<(), With<Player>>,
+    doodads: Query<Option<&Sleeping>, With<Doodad>>,
+    mut exit_writer: MessageWriter<AppExit>,
+) {
+    let Some((seconds, count)) = cli.bench() else {
+        return;
+    };
+    if stats.elapsed < seconds || stats.tick_times.is_empty() {
+        return;
+    }
+
+    let mut tick_times = stats.tick_times.clone();
+    tick_times.sort();
+    let percentile = |q: f32| {
+        let index = ((tick_times.len() - 1) as f32 * q).round() as usize;
+        tick_times[index].as_secs_f64() * 1000.0
+    };
+    println!(
+        "Benchmark of {:.1} s with {count} fake clients, {} ticks",
+        stats.elapsed,
+        tick_times.len()
+    );
+    println!(
+        "Tick time (ms): p50 {:.3}, p90 {:.3}, p99 {:.3}, max {:.3}",
+        percentile(0.5),
+        percentile(0.9),
+        percentile(0.99),
+        percentile(1.0)
+    );
+    let per_second = stats.received_bytes as f32 / stats.elapsed;
+    println!(
+        "Replication: {} bytes, {:.0} bytes/s, {:.0} bytes/s per client",
+        stats.received_bytes,
+        per_second,
+        per_second / count.max(1) as f32
+    );
+    for (name, component) in traffic.last_second() {
+        println!(
+            "  {name}: {} updates, {} bytes in the last second",
+            component.sent_updates, component.sent_bytes
+        );
+    }
+    println!(
+        "Entities: {} total, {} replicated, {} players, {} doodads ({} asleep)",
+        entities.iter().count(),
+        replicated.iter().count(),
+        players.iter().count(),
+        doodads.iter().count(),
+        doodads
+            .iter()
+            .flatten()
+            .filter(|sleeping| sleeping.sleeping)
+            .count()
+    );
+    exit_writer.write(AppExit::Success);
+}
@@ -0,0 +1,39 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_replicon::prelude::*;
+use merlo_model::CameraPath;
+use serde::{Deserialize, Serialize};
+
+use crate::traffic::TrafficAppExt;
+
+pub struct CameraPathPlugin;
+
+impl Plugin for CameraPathPlugin {
+    fn build(&self, app: &mut App) {
+        app.replicate_tracked::<CameraPath>()
+            .add_mapped_server_message::<PlayCameraPath>(Channel::Ordered);
+    }
+}
+
+/// A [`Message`] asking clients to fly their camera along a [`CameraPath`] entity.
+///
+/// The server broadcasts it, e.g. for kill-cams, while it can also be written locally to
+/// play a path on this client only.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct PlayCameraPath {
+    #[entities]
+    pub path: Entity,
+}
+
+impl PlayCameraPath {
+    /// Wraps the request in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_rapier3d::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::{Carried, Dead, Doodad};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin::ControlledCharacter,
+    controller::{CharacterController, has_server_authority},
+};
+
+pub struct CarryPlugin;
+
+impl Plugin for CarryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ThrowCharge>()
+            .add_mapped_client_message::<InteractInput>(Channel::Ordered)
+            .add_mapped_client_message::<ThrowInput>(Channel::Ordered)
+            .add_observer(hold_carried)
+            .add_observer(release_carried)
+            .add_systems(Update, keyboard_input)
+            .add_systems(
+                Update,
+                (grab_or_drop, throw_carried, follow_carriers)
+                    .chain()
+                    .run_if(has_server_authority),
+            );
+    }
+}
+
+/// Farthest distance from a character to a doodad it can grab.
+const GRAB_DISTANCE: f32 = 2.0;
+/// Where a carried doodad is held, in the local space of the character.
+const CARRY_OFFSET: Vec3 = Vec3::new(0.0, 0.2, 1.2);
+/// Seconds of charging a throw up to the farthest one.
+pub const MAX_CHARGE: f32 = 1.0;
+/// Speeds of a throw without charge and fully charged, in meters per second.
+const MIN_THROW_SPEED: f32 = 4.0;
+const MAX_THROW_SPEED: f32 = 14.0;
+/// Upward component of the direction of throws, relative to forward.
+const THROW_LIFT: f32 = 0.5;
+
+/// A [`Message`] written by a client to grab the doodad in front of its character, or to
+/// drop the one it carries.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct InteractInput {
+    #[entities]
+    pub character: Entity,
+}
+
+/// A [`Message`] written by a client to throw the doodad carried by its character.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ThrowInput {
+    #[entities]
+    pub character: Entity,
+    /// Seconds the throw was charged for, clamped to [`MAX_CHARGE`] by the server.
+    pub charge: f32,
+}
+
+/// Seconds the local player has been charging a throw, while holding its key.
+#[derive(Resource, Debug, Default)]
+pub struct ThrowCharge(Option<f32>);
+
+impl ThrowCharge {
+    pub fn seconds(&self) -> Option<f32> {
+        self.0
+    }
+}
+
+/// Velocity given to a doodad thrown by a character facing along `rotation`, on top of
+/// the velocity of the character itself.
+pub fn throw_velocity(rotation: Quat, charge: f32) -> Vec3 {
+    let direction = (rotation * Vec3::Z + Vec3::Y * THROW_LIFT).normalize();
+    let charge = (charge / MAX_CHARGE).clamp(0.0, 1.0);
+    direction * MIN_THROW_SPEED.lerp(MAX_THROW_SPEED, charge)
+}
+
+/// Sends [`InteractInput`] for the controlled character, and [`ThrowInput`] once the
+/// throw key is released after charging.
+fn keyboard_input(
+    time: Res<Time>,
+    mut interact_writer: MessageWriter<InteractInput>,
+    mut throw_writer: MessageWriter<ThrowInput>,
+    mut throw_charge: ResMut<ThrowCharge>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+) {
+    let Ok(child) = controller.single() else {
+        throw_charge.0 = None;
+        return;
+    };
+    if keyboard_input.just_pressed(KeyCode::KeyR) {
+        interact_writer.write(InteractInput {
+            character: child.parent(),
+        });
+    }
+    if keyboard_input.just_pressed(KeyCode::KeyT) {
+        throw_charge.0 = Some(0.0);
+    } else if let Some(charge) = &mut throw_charge.0 {
+        *charge += time.delta_secs();
+    }
+    if keyboard_input.just_released(KeyCode::KeyT)
+        && let Some(charge) = throw_charge.0.take()
+    {
+        throw_writer.write(ThrowInput {
+            character: child.parent(),
+            charge,
+        });
+    }
+}
+
+/// Carried doodads follow their carrier instead of the simulation, on the server as well
+/// as on clients, where the replicated transform moves them.
+fn hold_carried(add: On<Add, Carried>, mut commands: Commands) {
+    commands
+        .entity(add.entity)
+        .insert(RigidBody::KinematicPositionBased);
+}
+
+/// Released doodads fall again, unless they were despawned along with [`Carried`].
+fn release_carried(remove: On<Remove, Carried>, mut commands: Commands) {
+    commands
+        .entity(remove.entity)
+        .try_insert(RigidBody::Dynamic);
+}
+
+/// Drops the doodad carried by the character of a client, or grabs the closest doodad in
+/// front of it.
+#[allow(clippy::type_complexity)]
+fn grab_or_drop(
+    mut commands: Commands,
+    mut interact_reader: MessageReader<FromClient<InteractInput>>,
+    clients: Query<&ControlledCharacter>,
+    characters: Query<(&Transform, Option<&Velocity>), Without<Dead>>,
+    carried: Query<(Entity, &Carried)>,
+    doodads: Query<(Entity, &Transform), (With<Doodad>, Without<Carried>)>,
+) {
+    for event in interact_reader.read() {
+        let character = event.message.character;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(character))
+        {
+            warn!(
+                "Ignoring interaction of {:?} for character {character} it does not control",
+                event.client_id
+            );
+            continue;
+        }
+        let Ok((transform, velocity)) = characters.get(character) else {
+            continue;
+        };
+
+        if let Some((doodad, _)) = carried.iter().find(|(_, carried)| carried.by == character) {
+            // Thrown along with the momentum of the character.
+            let mut entity = commands.entity(doodad);
+            entity.remove::<Carried>();
+            if let Some(velocity) = velocity {
+                entity.insert(Velocity::linear(velocity.linvel));
+            }
+            continue;
+        }
+
+        let forward = transform.rotation * Vec3::Z;
+        let closest = doodads
+            .iter()
+            .map(|(doodad, doodad_transform)| {
+                let offset = doodad_transform.translation - transform.translation;
+                (doodad, offset)
+            })
+            .filter(|(_, offset)| offset.length() <= GRAB_DISTANCE && offset.dot(forward) > 0.0)
+            .min_by(|(_, a), (_, b)| a.length_squared().total_cmp(&b.length_squared()));
+        if let Some((doodad, _)) = closest {
+            commands.entity(doodad).insert(Carried { by: character });
+        }
+    }
+}
+
+/// Throws the doodads carried by the characters of clients forward and slightly up, faster
+/// the longer the throw was charged.
+fn throw_carried(
+    mut commands: Commands,
+    mut throw_reader: MessageReader<FromClient<ThrowInput>>,
+    clients: Query<&ControlledCharacter>,
+    characters: Query<(&Transform, Option<&Velocity>), Without<Dead>>,
+    carried: Query<(Entity, &Carried)>,
+) {
+    for event in throw_reader.read() {
+        let ThrowInput { character, charge } = event.message;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(character))
+        {
+            warn!(
+                "Ignoring throw of {:?} for character {character} it does not control",
+                event.client_id
+            );
+            continue;
+        }
+        let Ok((transform, velocity)) = characters.get(character) else {
+            continue;
+        };
+        let Some((doodad, _)) = carried.iter().find(|(_, carried)| carried.by == character) else {
+            continue;
+        };
+        let carrier_velocity = velocity.map_or(Vec3::ZERO, |velocity| velocity.linvel);
+        commands
+            .entity(doodad)
+            .remove::<Carried>()
+            .insert(Velocity::linear(
+                carrier_velocity + throw_velocity(transform.rotation, charge),
+            ));
+    }
+}
+
+/// Keeps carried doodads in front of their carrier, dropping them when it dies or is gone.
+fn follow_carriers(
+    mut commands: Commands,
+    characters: Query<&Transform, (Without<Dead>, Without<Carried>)>,
+    mut carried: Query<(Entity, &Carried, &mut Transform)>,
+) {
+    for (doodad, carried, mut transform) in &mut carried {
+        let Ok(carrier) = characters.get(carried.by) else {
+            commands.entity(doodad).remove::<Carried>();
+            continue;
+        };
+        transform.translation = carrier.transform_point(CARRY_OFFSET);
+        transform.rotation = carrier.rotation;
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::ScoreEntry;
+use serde::{Deserialize, Serialize};
+
+use crate::{admin::Muted, controller::has_server_authority, network::Cli, scoreboard::ScoreOwner};
+
+/// Maximum number of characters of a chat message, longer messages are truncated.
+pub const MAX_CHAT_LEN: usize = 256;
+
+pub struct ChatPlugin;
+
+impl Plugin for ChatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_client_message::<ChatInput>(Channel::Ordered)
+            .add_server_message::<ChatMessage>(Channel::Ordered)
+            .add_systems(Update, relay_chat.run_if(has_server_authority));
+    }
+}
+
+/// A [`Message`] written by a client to say something in the chat. The server tells who
+/// said it from the client it came from.
+#[derive(Message, Serialize, Deserialize, Debug, Clone)]
+pub struct ChatInput {
+    pub text: String,
+}
+
+impl ChatInput {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+/// A [`Message`] broadcast by the server for every chat line.
+#[derive(Message, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChatMessage {
+    pub sender: String,
+    pub text: String,
+    /// Seconds since the Unix epoch, as seen by the server.
+    pub timestamp: u64,
+}
+
+impl ChatMessage {
+    /// A chat line said now by `sender`, truncated to [`MAX_CHAT_LEN`] characters.
+    pub fn now(sender: impl Into<String>, text: &str) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        Self {
+            sender: sender.into(),
+            text: text.chars().take(MAX_CHAT_LEN).collect(),
+            timestamp,
+        }
+    }
+}
+
+/// Broadcasts chat inputs of unmuted clients to every client, stamped with the server time
+/// and the name the client joined with.
+fn relay_chat(
+    mut chat_reader: MessageReader<FromClient<ChatInput>>,
+    mut chat_writer: MessageWriter<ToClients<ChatMessage>>,
+    muted: Query<(), With<Muted>>,
+    entries: Query<(&ScoreOwner, &ScoreEntry)>,
+    cli: Res<Cli>,
+) {
+    for event in chat_reader.read() {
+        if let ClientId::Client(client) = event.client_id
+            && muted.contains(client)
+        {
+            continue;
+        }
+
+        let text = event.message.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let entry = entries.iter().find(|(owner, _)| owner.0 == event.client_id);
+        let sender = match (entry, event.client_id) {
+            (Some((_, entry)), _) => entry.name.clone(),
+            (None, ClientId::Server) => cli.name().to_owned(),
+            (None, ClientId::Client(client)) => {
+                warn!("Ignoring chat from {client}, which has not joined yet");
+                continue;
+            }
+        };
+        chat_writer.write(ToClients {
+            mode: SendMode::Broadcast,
+            message: ChatMessage::now(sender, text),
+        });
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_model::Team;
+#[cfg(feature = "client-visuals")]
+use merlo_model::{Doodad, SpawnPoint};
+
+pub struct ColorSchemePlugin;
+
+impl Plugin for ColorSchemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorScheme>();
+        #[cfg(feature = "client-visuals")]
+        app.add_systems(
+            Update,
+            recolor_materials.run_if(resource_changed::<ColorScheme>),
+        );
+    }
+}
+
+/// Colors used to tell entities, teams, and selections apart.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ColorScheme {
+    pub doodad: Color,
+    pub spawn_point: Color,
+    pub selection: Color,
+    pub red_team: Color,
+    pub blue_team: Color,
+}
+
+impl ColorScheme {
+    pub const STANDARD: Self = Self {
+        doodad: Color::srgb(124.0 / 255.0, 144.0 / 255.0, 1.0),
+        spawn_point: Color::srgb(1.0, 200.0 / 255.0, 80.0 / 255.0),
+        selection: Color::srgb(1.0, 200.0 / 255.0, 80.0 / 255.0),
+        red_team: Color::srgb(0.9, 0.2, 0.2),
+        blue_team: Color::srgb(0.2, 0.4, 0.9),
+    };
+
+    /// Colors from the Okabe-Ito palette, distinguishable with the common forms of color blindness.
+    pub const COLORBLIND_SAFE: Self = Self {
+        doodad: Color::srgb(86.0 / 255.0, 180.0 / 255.0, 233.0 / 255.0),
+        spawn_point: Color::srgb(240.0 / 255.0, 228.0 / 255.0, 66.0 / 255.0),
+        selection: Color::srgb(240.0 / 255.0, 228.0 / 255.0, 66.0 / 255.0),
+        red_team: Color::srgb(230.0 / 255.0, 159.0 / 255.0, 0.0),
+        blue_team: Color::srgb(0.0, 114.0 / 255.0, 178.0 / 255.0),
+    };
+
+    pub fn team(&self, team: Team) -> Color {
+        match team {
+            Team::None => Color::WHITE,
+            Team::Red => self.red_team,
+            Team::Blue => self.blue_team,
+        }
+    }
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// Applies a new color scheme to the materials of already spawned entities.
+#[cfg(feature = "client-visuals")]
+fn recolor_materials(
+    scheme: Res<ColorScheme>,
+    doodads: Query<&MeshMaterial3d<StandardMaterial>, With<Doodad>>,
+    spawn_points: Query<&MeshMaterial3d<StandardMaterial>, With<SpawnPoint>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let recolored = doodads
+        .iter()
+        .map(|material| (material, scheme.doodad))
+        .chain(
+            spawn_points
+                .iter()
+                .map(|material| (material, scheme.spawn_point)),
+        );
+    for (material, color) in recolored {
+        if let Some(material) = materials.get_mut(material) {
+            material.base_color = color;
+        }
+    }
+}
@@ -0,0 +1,534 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
+
+use bevy::{
+    app::PluginGroupBuilder,
+    log::{
+        BoxedLayer, Level, LogPlugin,
+        tracing_subscriber::{Layer, fmt},
+    },
+    prelude::*,
+};
+use clap::{Arg, ArgMatches, CommandFactory, FromArgMatches, ValueEnum, error::ErrorKind};
+use serde::Deserialize;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+
+use crate::physics::PhysicsTimestep;
+
+/// File the configuration is read from, unless given with `--config`.
+pub const DEFAULT_CONFIG_PATH: &str = "merlo.toml";
+/// Number of daily log files kept, the oldest being deleted.
+const MAX_LOG_FILES: usize = 7;
+/// Seconds between checks of the configuration file for changes.
+const RELOAD_INTERVAL: f32 = 1.0;
+/// Longest password, as it is sent along the connection request of a client.
+pub const MAX_PASSWORD_LEN: usize = 256;
+
+/// Prefix of the environment variables setting the options of the command line, such as
+/// `MERLO_PORT` for `--port`, and the entries of the file, such as `MERLO_TICK_RATE`.
+pub const ENV_PREFIX: &str = "MERLO_";
+
+/// Settings of the server and the client read from a TOML file, where missing entries keep
+/// their default. Environment variables take precedence over the file, and options given on
+/// the command line over both.
+///
+/// The file is watched while running, and the input, debug, and persistence settings are
+/// applied when it changes, see [`ConfigReloaded`]. The others take effect on restart.
+#[derive(Resource, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    pub network: NetworkConfig,
+    pub physics: PhysicsConfig,
+    pub assets: AssetsConfig,
+    pub logging: LoggingConfig,
+    pub input: InputConfig,
+    pub debug: DebugConfig,
+    pub persistence: PersistenceConfig,
+    /// File the configuration is read from, watched for changes, if any.
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// Ticks per second of the server, which is how often it steps the physics, moves the
+    /// characters, and replicates the world. Deterministic physics keep their own rate.
+    pub tick_rate: Option<f64>,
+    /// Number of remote clients the server accepts at once.
+    pub max_clients: usize,
+    /// Password clients have to give to join the server, and which clients give.
+    ///
+    /// It keeps strangers out of a server, not eavesdroppers, as it is sent unencrypted.
+    pub password: Option<String>,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            tick_rate: None,
+            max_clients: 1,
+            password: None,
+        }
+    }
+}
+
+/// Defaults of the physics options of the command line.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PhysicsConfig {
+    /// Downward acceleration of gravity in meters per second squared.
+    pub gravity: Option<f32>,
+    /// Step physics by a fixed timestep, as with `--deterministic`.
+    pub deterministic: bool,
+    /// How physics advance at each step, as with `--timestep`.
+    pub timestep: Option<PhysicsTimestep>,
+    /// Steps each physics step is split into, as with `--substeps`.
+    pub substeps: Option<u32>,
+    /// Iterations of the solver at each step, as with `--solver-iterations`.
+    pub solver_iterations: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AssetsConfig {
+    /// Folder of the assets, relative to the executable, instead of `assets`.
+    pub path: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggingConfig {
+    /// Most verbose level logged, `info` by default.
+    pub level: Option<LogLevel>,
+    /// Filter of the logs by module, such as `wgpu=error,merlo_simulation=debug`, replacing
+    /// the default one of Bevy.
+    pub filter: Option<String>,
+    /// File the logs are also written to, rolled over daily by appending the date to its
+    /// name, so that they survive restarts.
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct InputConfig {
+    /// How fast the character turns per pixel the mouse moves while looking around.
+    pub mouse_sensitivity: f32,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            mouse_sensitivity: 0.125,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DebugConfig {
+    /// Render the colliders of the physics, as toggled with F4.
+    pub physics: bool,
+}
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PersistenceConfig {
+    /// Seconds between autosaves of a dedicated server, unless given with `--autosave`.
+    pub autosave: Option<f32>,
+}
+
+#[derive(Deserialize, ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => Level::ERROR,
+            LogLevel::Warn => Level::WARN,
+            LogLevel::Info => Level::INFO,
+            LogLevel::Debug => Level::DEBUG,
+            LogLevel::Trace => Level::TRACE,
+        }
+    }
+}
+
+/// The value of the environment variable `MERLO_{name}`, if set.
+fn env<T>(name: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let key = format!("{ENV_PREFIX}{name}");
+    match std::env::var(&key) {
+        Ok(value) => Ok(Some(
+            value
+                .parse()
+                .map_err(|err| format!("invalid {key}: {err}"))?,
+        )),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(err) => Err(format!("invalid {key}: {err}").into()),
+    }
+}
+
+impl ConfigFile {
+    pub fn load(path: &Path) -> Result<Self> {
+        let config: Self = toml::from_str(&std::fs::read_to_string(path)?)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(password) = &self.network.password
+            && password.len() > MAX_PASSWORD_LEN
+        {
+            return Err(format!("password longer than {MAX_PASSWORD_LEN} bytes").into());
+        }
+        if let Some(seconds) = self.persistence.autosave
+            && !(seconds.is_finite() && seconds > 0.0)
+        {
+            return Err(format!("autosave interval {seconds} is not positive").into());
+        }
+        Ok(())
+    }
+
+    /// The file at `path` with the environment variables, or only the latter when it does
+    /// not exist.
+    fn read(path: &Path) -> Result<Self> {
+        let config = if path.exists() {
+            Self::load(path)?
+        } else {
+            Self::default()
+        };
+        config.with_env()
+    }
+
+    /// Takes the settings of `reloaded` which are safe to change while running, warning about
+    /// the others which changed since `previous` was read.
+    fn reload(&mut self, previous: &Self, reloaded: &Self) {
+        if reloaded.network != previous.network
+            || reloaded.physics != previous.physics
+            || reloaded.assets != previous.assets
+            || reloaded.logging != previous.logging
+        {
+            warn!("Changes to the network, physics, assets, and logging take effect on restart");
+        }
+        self.input = reloaded.input.clone();
+        self.debug = reloaded.debug.clone();
+        self.persistence = reloaded.persistence.clone();
+    }
+
+    /// Replaces the entries of the file with the environment variables setting them, such
+    /// as `MERLO_PASSWORD` for the password of the network. The ones of the assets and the
+    /// logging are options of the command line, such as `MERLO_LOG_LEVEL` for `--log-level`.
+    pub fn with_env(mut self) -> Result<Self> {
+        if let Some(tick_rate) = env("TICK_RATE")? {
+            self.network.tick_rate = Some(tick_rate);
+        }
+        if let Some(max_clients) = env("MAX_CLIENTS")? {
+            self.network.max_clients = max_clients;
+        }
+        if let Some(password) = env("PASSWORD")? {
+            self.network.password = Some(password);
+        }
+        if let Some(filter) = env("LOG_FILTER")? {
+            self.logging.filter = Some(filter);
+        }
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Parses the command line of `A`, with extra `--config FILE`, `--assets DIR`,
+    /// `--log-level LEVEL`, and `--log-file FILE` options, taking the defaults of its
+    /// options from the environment variables, then from the configuration file. The file
+    /// is optional unless given explicitly, and exits like a command line error when it is
+    /// invalid. `MERLO_MODE` picks the mode when not given, such as
+    /// `MERLO_MODE=server` for a dedicated server in a container.
+    pub fn parse_with<A: CommandFactory + FromArgMatches>() -> (A, Self) {
+        let mut command = A::command().arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("FILE")
+                .value_parser(clap::value_parser!(PathBuf))
+                .env(format!("{ENV_PREFIX}CONFIG"))
+                .global(true)
+                .help(format!(
+                    "TOML configuration of the server and the client [default: {DEFAULT_CONFIG_PATH}]"
+                )),
+        )
+        .arg(
+            Arg::new("assets")
+                .long("assets")
+                .value_name("DIR")
+                .env(format!("{ENV_PREFIX}ASSETS"))
+                .global(true)
+                .help("Folder of the assets, instead of the one of the configuration"),
+        )
+        .arg(
+            Arg::new("log_level")
+                .long("log-level")
+                .value_name("LEVEL")
+                .value_parser(clap::value_parser!(LogLevel))
+                .env(format!("{ENV_PREFIX}LOG_LEVEL"))
+                .global(true)
+                .help("Most verbose level logged, instead of the one of the configuration"),
+        )
+        .arg(
+            Arg::new("log_file")
+                .long("log-file")
+                .value_name("FILE")
+                .value_parser(clap::value_parser!(PathBuf))
+                .env(format!("{ENV_PREFIX}LOG_FILE"))
+                .global(true)
+                .help("File the logs are also written to, rolled over daily"),
+        );
+        let mut args: Vec<OsString> = std::env::args_os().collect();
+        if let Some(mode) = std::env::var_os(format!("{ENV_PREFIX}MODE"))
+            && !command
+                .get_subcommands()
+                .any(|subcommand| args.iter().skip(1).any(|arg| *arg == subcommand.get_name()))
+        {
+            args.push(mode);
+        }
+
+        // Looks for the file before parsing, so that the help shows the defaults it sets.
+        let mut explicit = None;
+        let mut rest = args.iter().skip(1);
+        while let Some(arg) = rest.next() {
+            if arg == "--config" {
+                explicit = rest.next().map(PathBuf::from);
+            } else if let Some(path) = arg.to_str().and_then(|arg| arg.strip_prefix("--config=")) {
+                explicit = Some(PathBuf::from(path));
+            }
+        }
+        let explicit =
+            explicit.or_else(|| std::env::var_os(format!("{ENV_PREFIX}CONFIG")).map(PathBuf::from));
+        let path = explicit
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_PATH));
+        let config = if explicit.is_some() || path.exists() {
+            Self::load(&path)
+                .map_err(|err| format!("invalid configuration {}: {err}", path.display()))
+        } else {
+            Ok(Self::default())
+        };
+        let config = config.map(|config| Self {
+            path: Some(path),
+            ..config
+        });
+        let config = config.and_then(|config| config.with_env().map_err(|err| err.to_string()));
+        let mut config = match config {
+            Ok(config) => config,
+            Err(err) => command.error(ErrorKind::InvalidValue, err).exit(),
+        };
+
+        // Environment variables of the options take precedence over these defaults.
+        command = config.physics.set_defaults(command);
+        let matches = command.get_matches_from(args);
+        config.assets.set_from(&matches);
+        config.logging.set_from(&matches);
+        match A::from_arg_matches(&matches) {
+            Ok(args) => (args, config),
+            Err(err) => err.exit(),
+        }
+    }
+
+    /// Sets up the assets and the logging of the default plugins. The log file is read from
+    /// the [`ConfigFile`] resource, which has to be inserted before adding them.
+    pub fn configure(&self, plugins: PluginGroupBuilder) -> PluginGroupBuilder {
+        let plugins = match &self.assets.path {
+            Some(path) => plugins.set(AssetPlugin {
+                file_path: path.clone(),
+                ..default()
+            }),
+            None => plugins,
+        };
+        let defaults = LogPlugin::default();
+        plugins.set(LogPlugin {
+            level: self.logging.level.map_or(defaults.level, Level::from),
+            filter: self.logging.filter.clone().unwrap_or(defaults.filter),
+            custom_layer: log_file_layer,
+            ..defaults
+        })
+    }
+}
+
+impl PhysicsConfig {
+    /// Makes the values of the file the defaults of the physics options, when the command
+    /// has them.
+    fn set_defaults(&self, mut command: clap::Command) -> clap::Command {
+        let has_arg = |command: &clap::Command, id: &str| {
+            command.get_arguments().any(|arg| arg.get_id() == id)
+        };
+        if let Some(gravity) = self.gravity
+            && has_arg(&command, "gravity")
+        {
+            command = command.mut_arg("gravity", |arg| arg.default_value(gravity.to_string()));
+        }
+        if self.deterministic && has_arg(&command, "deterministic") {
+            command = command.mut_arg("deterministic", |arg| arg.default_value("true"));
+        }
+        if let Some(timestep) = self
+            .timestep
+            .and_then(|timestep| timestep.to_possible_value())
+            && has_arg(&command, "timestep")
+        {
+            command = command.mut_arg("timestep", |arg| {
+                arg.default_value(timestep.get_name().to_owned())
+            });
+        }
+        if let Some(substeps) = self.substeps
+            && has_arg(&command, "substeps")
+        {
+            command = command.mut_arg("substeps", |arg| arg.default_value(substeps.to_string()));
+        }
+        if let Some(iterations) = self.solver_iterations
+            && has_arg(&command, "solver_iterations")
+        {
+            command = command.mut_arg("solver_iterations", |arg| {
+                arg.default_value(iterations.to_string())
+            });
+        }
+        command
+    }
+}
+
+impl AssetsConfig {
+    /// Takes the folder given on the command line, if any.
+    fn set_from(&mut self, matches: &ArgMatches) {
+        if let Some(path) = matches.get_one::<String>("assets") {
+            self.path = Some(path.clone());
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Takes the level and the file given on the command line, if any.
+    fn set_from(&mut self, matches: &ArgMatches) {
+        if let Some(&level) = matches.get_one::<LogLevel>("log_level") {
+            self.level = Some(level);
+        }
+        if let Some(file) = matches.get_one::<PathBuf>("log_file") {
+            self.file = Some(file.clone());
+        }
+    }
+}
+
+/// Writes the logs to the file of the configuration too, if it has one.
+fn log_file_layer(app: &mut App) -> Option<BoxedLayer> {
+    let path = app
+        .world()
+        .get_resource::<ConfigFile>()?
+        .logging
+        .file
+        .clone()?;
+    let directory = path
+        .parent()
+        .filter(|directory| !directory.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let prefix = path.file_name()?.to_string_lossy().into_owned();
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(prefix)
+        .max_log_files(MAX_LOG_FILES)
+        .build(directory);
+    match appender {
+        Ok(appender) => Some(fmt::layer().with_writer(appender).with_ansi(false).boxed()),
+        Err(err) => {
+            // Logging is not set up yet.
+            eprintln!("Cannot log to {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+/// A [`Message`] written once the settings of the [`ConfigFile`] resource which are safe to
+/// change while running are reloaded from the file.
+#[derive(Message, Debug, Clone, Copy, Default)]
+pub struct ConfigReloaded;
+
+/// Watches the file of the [`ConfigFile`] resource, reloading it when it changes.
+pub struct ConfigReloadPlugin;
+
+impl Plugin for ConfigReloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ConfigReloaded>()
+            .add_systems(Startup, init_config_watch)
+            .add_systems(Update, reload_config.run_if(resource_exists::<ConfigWatch>));
+    }
+}
+
+/// The file of the configuration, when it was last modified, if it exists, and what it
+/// read then.
+#[derive(Resource)]
+struct ConfigWatch {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    read: ConfigFile,
+    timer: Timer,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn init_config_watch(mut commands: Commands, config: Res<ConfigFile>) {
+    if let Some(path) = &config.path {
+        commands.insert_resource(ConfigWatch {
+            modified: modified(path),
+            read: ConfigFile::read(path).unwrap_or_default(),
+            path: path.clone(),
+            timer: Timer::from_seconds(RELOAD_INTERVAL, TimerMode::Repeating),
+        });
+    }
+}
+
+/// Reloads the configuration every time its file is modified, keeping the current settings
+/// when it is removed or invalid.
+fn reload_config(
+    mut watch: ResMut<ConfigWatch>,
+    time: Res<Time<Real>>,
+    mut config: ResMut<ConfigFile>,
+    mut reloaded_writer: MessageWriter<ConfigReloaded>,
+) {
+    if !watch.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let modified = modified(&watch.path);
+    if modified == watch.modified {
+        return;
+    }
+    watch.modified = modified;
+    if modified.is_none() {
+        return;
+    }
+    match ConfigFile::load(&watch.path).and_then(ConfigFile::with_env) {
+        Ok(reloaded) => {
+            info!("Reloaded the configuration {}", watch.path.display());
+            config.reload(&watch.read, &reloaded);
+            watch.read = reloaded;
+            reloaded_writer.write(ConfigReloaded);
+        }
+        Err(err) => error!(
+            "Cannot reload the configuration {}: {err}",
+            watch.path.display()
+        ),
+    }
+}
@@ -3,18 +3,28 @@
 // SPDX-License-Identifier: MIT
 
 use bevy::{
-    ecs::{entity::MapEntities, query::QueryData},
+    ecs::{
+        entity::MapEntities,
+        intern::Interned,
+        query::QueryData,
+        schedule::{ScheduleLabel, SystemSet},
+    },
     input::mouse::MouseMotion,
     prelude::*,
 };
 use bevy_rapier3d::prelude::*;
 use bevy_replicon::prelude::*;
+use merlo_model::{Dead, Match};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    config::ConfigFile, game_mode, physics::PhysicsSettings, replication::ReplicateVelocity,
+};
+
 pub struct CharacterControllerPlugin;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
-enum CharacterControllerSet {
+pub(crate) enum CharacterControllerSet {
     Input,
     Grounded,
     Movement,
@@ -23,47 +33,72 @@ enum CharacterControllerSet {
 
 impl Plugin for CharacterControllerPlugin {
     fn build(&self, app: &mut App) {
+        let schedule = movement_schedule(app.world());
         // Inputs are produced as client messages: on a connected client they are sent over the
         // network, and on server/single-player they are emitted locally as `FromClient`.
         app.add_mapped_client_message::<MovementAction>(Channel::Ordered)
             .configure_sets(
                 Update,
+                CharacterControllerSet::Input.before(CharacterControllerSet::Grounded),
+            )
+            .configure_sets(
+                schedule,
                 (
-                    CharacterControllerSet::Input,
                     CharacterControllerSet::Grounded,
                     CharacterControllerSet::Movement,
                     CharacterControllerSet::Damping,
                 )
-                    .chain(),
+                    .chain()
+                    .after(PhysicsSet::Writeback),
             )
             .add_systems(
                 Update,
                 (keyboard_input, gamepad_input, mouse_input).in_set(CharacterControllerSet::Input),
             )
             .add_systems(
-                Update,
+                schedule,
                 update_grounded.in_set(CharacterControllerSet::Grounded),
             )
             .add_systems(
-                Update,
+                schedule,
                 movement
                     .in_set(CharacterControllerSet::Movement)
                     .run_if(has_server_authority),
+            )
+            .add_systems(
+                schedule,
+                apply_movement_damping
+                    .in_set(CharacterControllerSet::Damping)
+                    .run_if(has_server_authority),
             );
     }
 }
 
+/// Where characters move: after each step of rapier in [`FixedUpdate`] when its
+/// [`PhysicsSettings`] step by a fixed timestep, for the next step, otherwise once per frame
+/// in [`Update`]. Inputs are always read in [`Update`].
+pub(crate) fn movement_schedule(world: &World) -> Interned<dyn ScheduleLabel> {
+    if world
+        .get_resource::<PhysicsSettings>()
+        .is_some_and(PhysicsSettings::is_fixed)
+    {
+        FixedUpdate.intern()
+    } else {
+        Update.intern()
+    }
+}
+
 /// Returns whether this process should run authoritative simulation.
 ///
 /// In Replicon, `ClientState::Disconnected` means "this app is not acting as a network client",
 /// which includes dedicated server and single-player. Connected remote clients are in
 /// `Connecting`/`Connected`, so they should not apply movement locally and must only send input.
-fn has_server_authority(client_state: Res<State<ClientState>>) -> bool {
+pub(crate) fn has_server_authority(client_state: Res<State<ClientState>>) -> bool {
     *client_state == ClientState::Disconnected
 }
 
 /// A [`Message`] written for a movement input action.
-#[derive(Message, MapEntities, Serialize, Deserialize, Clone, Copy)]
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
 pub enum MovementAction {
     AddMove(#[entities] Entity, Vec3),
     SetMove(#[entities] Entity, Vec3),
@@ -74,6 +109,21 @@ pub enum MovementAction {
     SetJump(#[entities] Entity, bool),
 }
 
+impl MovementAction {
+    /// The character this action applies to.
+    pub fn entity(&self) -> Entity {
+        match *self {
+            MovementAction::AddMove(entity, _)
+            | MovementAction::SetMove(entity, _)
+            | MovementAction::SetSpeed(entity, _)
+            | MovementAction::RotateRight(entity, _)
+            | MovementAction::RotateLeft(entity, _)
+            | MovementAction::SetRotate(entity, _)
+            | MovementAction::SetJump(entity, _) => entity,
+        }
+    }
+}
+
 /// Replicated movement state used by clients for animation and presentation.
 #[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub struct CharacterMovementState {
@@ -147,6 +197,27 @@ pub struct Grounded;
 #[derive(Component)]
 pub struct MovementAcceleration(f32);
 
+/// How fast the [`ExternalVelocity`] of a character fades, per second.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MovementDampingFactor(pub f32);
+
+impl MovementDampingFactor {
+    pub const DEFAULT: Self = Self(8.0);
+}
+
+impl Default for MovementDampingFactor {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Horizontal velocity pushing a character on top of its own movement, such as from being
+/// knocked back, fading with its [`MovementDampingFactor`].
+///
+/// Movement replaces the velocity of characters every frame, so pushes go here instead.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ExternalVelocity(pub Vec3);
+
 /// The strength of a jump.
 #[derive(Component)]
 pub struct JumpImpulse(f32);
@@ -157,14 +228,71 @@ pub struct JumpImpulse(f32);
 #[derive(Component)]
 pub struct MaxSlopeAngle(f32);
 
+/// Optional movement mode where the horizontal displacement of the character comes
+/// from its animation rather than from its speed, so that feet do not slide.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct RootMotion {
+    /// Displacement of the root bone since last consumed by the movement, in the local
+    /// space of the character.
+    pub delta: Vec3,
+}
+
+/// Size of the capsule of a character, from which its collider, the probe looking for the
+/// ground under its feet, and the offset of its scene are all derived.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct CharacterDimensions {
+    pub radius: f32,
+    /// From the soles to the top of the head.
+    pub height: f32,
+}
+
+impl CharacterDimensions {
+    pub const DEFAULT: Self = Self {
+        radius: 0.5,
+        height: 3.0,
+    };
+
+    pub fn collider(&self) -> Collider {
+        let half_segment = (self.height / 2.0 - self.radius).max(0.0);
+        Collider::capsule_y(half_segment, self.radius)
+    }
+
+    /// Distance from the origin of the character, at the center of its capsule, down to
+    /// its soles.
+    pub const fn origin_to_foot(&self) -> f32 {
+        self.height / 2.0
+    }
+
+    /// Offset of the scene of the character, whose origin is between its feet.
+    pub fn scene_offset(&self) -> Vec3 {
+        Vec3::NEG_Y * self.origin_to_foot()
+    }
+
+    /// Where rays looking for the ground start, right above the soles of the character
+    /// when its origin is at `translation`.
+    pub fn probe_origin(&self, translation: Vec3) -> Vec3 {
+        const CLEARANCE: f32 = 0.01;
+        translation - Vec3::Y * (self.origin_to_foot() - CLEARANCE)
+    }
+}
+
+impl Default for CharacterDimensions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// A bundle that contains the components needed for a basic
 /// physics-driven character controller.
 #[derive(Bundle)]
 pub struct CharacterPhysicsBundle {
     physics: CharacterPhysics,
+    dimensions: CharacterDimensions,
     collider: Collider,
     body: RigidBody,
     velocity: Velocity,
+    replicate_velocity: ReplicateVelocity,
+    external_velocity: ExternalVelocity,
     locked_axes: LockedAxes,
     gravity_scale: GravityScale,
     movement_state: CharacterMovementState,
@@ -176,6 +304,7 @@ pub struct CharacterPhysicsBundle {
 #[derive(Bundle)]
 pub struct MovementBundle {
     acceleration: MovementAcceleration,
+    damping: MovementDampingFactor,
     jump_impulse: JumpImpulse,
     max_slope_angle: MaxSlopeAngle,
 }
@@ -184,10 +313,16 @@ impl MovementBundle {
     pub const fn new(acceleration: f32, jump_impulse: f32, max_slope_angle: f32) -> Self {
         Self {
             acceleration: MovementAcceleration(acceleration),
+            damping: MovementDampingFactor::DEFAULT,
             jump_impulse: JumpImpulse(jump_impulse),
             max_slope_angle: MaxSlopeAngle(max_slope_angle),
         }
     }
+
+    pub const fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = MovementDampingFactor(damping);
+        self
+    }
 }
 
 impl Default for MovementBundle {
@@ -197,12 +332,15 @@ impl Default for MovementBundle {
 }
 
 impl CharacterPhysicsBundle {
-    pub fn new(collider: Collider, gravity_scale: f32) -> Self {
+    pub fn new(dimensions: CharacterDimensions, gravity_scale: f32) -> Self {
         Self {
             physics: CharacterPhysics,
-            collider,
+            dimensions,
+            collider: dimensions.collider(),
             body: RigidBody::Dynamic,
             velocity: Velocity::default(),
+            replicate_velocity: ReplicateVelocity,
+            external_velocity: ExternalVelocity::default(),
             locked_axes: LockedAxes::ROTATION_LOCKED_X | LockedAxes::ROTATION_LOCKED_Z,
             gravity_scale: GravityScale(gravity_scale),
             movement_state: CharacterMovementState::default(),
@@ -220,6 +358,11 @@ impl CharacterPhysicsBundle {
         self.movement = MovementBundle::new(acceleration, jump_impulse, max_slope_angle);
         self
     }
+
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.movement = self.movement.with_damping(damping);
+        self
+    }
 }
 
 /// Returns the currently controlled physics entity, if any.
@@ -342,6 +485,7 @@ fn mouse_input(
     mut movement_writer: MessageWriter<MovementAction>,
     mut mouse_reader: MessageReader<MouseMotion>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
+    config: Res<ConfigFile>,
     child: Query<&ChildOf, With<CharacterController>>,
     has_physics: Query<Has<CharacterPhysics>>,
 ) {
@@ -368,40 +512,52 @@ fn mouse_input(
         movement_writer.write(MovementAction::SetRotate(entity, 0.0));
     }
 
-    let sensitivity = 0.125;
+    let sensitivity = config.input.mouse_sensitivity;
     movement_writer.write(MovementAction::SetRotate(entity, -delta.x * sensitivity));
 }
 
+/// Components of a character read and written by [`update_grounded`].
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct GroundProbeData {
+    entity: Entity,
+    transform: &'static Transform,
+    dimensions: &'static CharacterDimensions,
+    max_slope_angle: Option<&'static MaxSlopeAngle>,
+    movement_state: &'static mut CharacterMovementState,
+}
+
 /// Updates the [`Grounded`] status for character controllers.
-fn update_grounded(
+///
+/// The ground probes only read the physics world, so they are cast in parallel batches
+/// over the characters, which keeps servers with hundreds of them from waiting on each ray.
+pub fn update_grounded(
     rapier_context: ReadRapierContext,
-    query: Query<(Entity, &Transform, Option<&MaxSlopeAngle>), With<CharacterPhysics>>,
-    mut movement_states: Query<&mut CharacterMovementState>,
+    mut query: Query<GroundProbeData, With<CharacterPhysics>>,
 ) {
     let Ok(rapier_context) = rapier_context.single() else {
         return;
     };
 
-    // Tuned for the default capsule used in `main.rs` (radius=0.0, half_height=0.5).
-    const PROBE_ORIGIN_TO_FOOT: f32 = 1.5;
     const PROBE_DISTANCE: f32 = 0.5;
 
-    for (entity, transform, max_slope_angle) in &query {
-        let origin = transform.translation - Vec3::Y * (PROBE_ORIGIN_TO_FOOT - 0.01);
+    let _span = info_span!("grounding", characters = query.iter().len()).entered();
+    query.par_iter_mut().for_each(|mut data| {
+        let origin = data.dimensions.probe_origin(data.transform.translation);
         let dir = -Vec3::Y;
-        let filter = QueryFilter::default().exclude_collider(entity);
+        let filter = QueryFilter::default()
+            .exclude_collider(data.entity)
+            .exclude_sensors();
 
         let grounded = rapier_context
             .cast_ray_and_get_normal(origin, dir, PROBE_DISTANCE, true, filter)
-            .is_some_and(|(_, intersection)| match max_slope_angle {
+            .is_some_and(|(_, intersection)| match data.max_slope_angle {
                 Some(angle) => intersection.normal.angle_between(Vec3::Y).abs() <= angle.0,
                 None => true,
             });
 
-        if let Ok(mut movement_state) = movement_states.get_mut(entity) {
-            movement_state.grounded = grounded;
-        }
-    }
+        data.movement_state.grounded = grounded;
+    });
 }
 
 /// Peculiar helper class for the character rotation.
@@ -413,37 +569,43 @@ struct CharacterRotation {
     rotation: f32,
 }
 
+/// Components of a character read and written by [`movement`].
 #[derive(QueryData)]
 #[query_data(mutable)]
-struct MovementData {
+pub struct MovementData {
     movement_acceleration: &'static MovementAcceleration,
     transform: &'static Transform,
     jump_impulse: &'static JumpImpulse,
     movement_state: &'static mut CharacterMovementState,
     velocity: &'static mut Velocity,
     rotation: &'static mut CharacterRotation,
+    root_motion: Option<&'static mut RootMotion>,
+    dead: Has<Dead>,
 }
 
 /// Applies movement from client input messages.
 ///
 /// This runs only when [`has_server_authority`] is true, so movement is applied on server and
 /// single-player, while connected clients only send input.
-fn movement(
+pub fn movement(
+    time: Res<Time>,
     mut movement_reader: MessageReader<FromClient<MovementAction>>,
     mut controllers: Query<MovementData>,
+    matches: Query<&Match>,
 ) {
     // Reset horizontal movement and rotation.
     // This allows us to have discrete movement input each frame,
     // which is easier to work with and feels better than continuous acceleration.
+    // The rotation of the mouse is kept until set again, as it is sent once per frame, and
+    // a frame may run several fixed steps.
     for mut data in &mut controllers {
         data.velocity.linvel.x = 0.0;
         data.velocity.linvel.z = 0.0;
         data.velocity.angvel.y = 0.0;
-
-        data.rotation.rotation = 0.0;
     }
 
     // Collect all inputs for this frame.
+    let collect_span = info_span!("input_collection", actions = movement_reader.len()).entered();
     for event in movement_reader.read() {
         match &event.message {
             MovementAction::AddMove(entity, direction) => {
@@ -491,8 +653,19 @@ fn movement(
         }
     }
 
-    // Then apply movement based on the final state.
+    collect_span.exit();
+
+    // Then apply movement based on the final state, while dead characters stay still, and
+    // everyone does during the warmup.
+    let _span = info_span!("movement").entered();
+    let frozen = game_mode::in_warmup(matches);
     for mut data in &mut controllers {
+        if data.dead || frozen {
+            if let Some(root_motion) = data.root_motion.as_deref_mut() {
+                root_motion.delta = Vec3::ZERO;
+            }
+            continue;
+        }
         let direction = data.movement_state.direction.clamp_length_max(1.0);
         let mut world = data.transform.rotation * direction;
         world = world.normalize_or_zero();
@@ -508,6 +681,17 @@ fn movement(
         // If not flying, do not apply vertical movement from input, to allow gravity and jumping to work naturally.
         data.velocity.linvel.z = world.z * data.movement_acceleration.0 * speed;
 
+        // With root motion, the animation decides how far the character moves instead.
+        let transform = *data.transform;
+        if let Some(root_motion) = data.root_motion.as_deref_mut() {
+            let delta = std::mem::take(&mut root_motion.delta);
+            if data.movement_state.is_moving() && time.delta_secs() > 0.0 {
+                let root_velocity = transform.rotation * delta / time.delta_secs();
+                data.velocity.linvel.x = root_velocity.x;
+                data.velocity.linvel.z = root_velocity.z;
+            }
+        }
+
         if data.rotation.rotation == 0.0 {
             data.movement_state.apply_right_left_rotation();
         } else {
@@ -521,3 +705,25 @@ fn movement(
         }
     }
 }
+
+/// Speed below which the external velocity of a character stops.
+const MIN_EXTERNAL_SPEED: f32 = 0.01;
+
+/// Adds the external velocity of characters on top of the velocity of their movement,
+/// fading it by their damping.
+fn apply_movement_damping(
+    time: Res<Time>,
+    mut characters: Query<(&MovementDampingFactor, &mut ExternalVelocity, &mut Velocity)>,
+) {
+    for (damping, mut external, mut velocity) in &mut characters {
+        if external.0 == Vec3::ZERO {
+            continue;
+        }
+        velocity.linvel.x += external.0.x;
+        velocity.linvel.z += external.0.z;
+        external.0 *= (-damping.0 * time.delta_secs()).exp();
+        if external.0.length() < MIN_EXTERNAL_SPEED {
+            external.0 = Vec3::ZERO;
+        }
+    }
+}
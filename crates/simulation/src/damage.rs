@@ -0,0 +1,39 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_replicon::prelude::*;
+use merlo_model::DamageKind;
+use serde::{Deserialize, Serialize};
+
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_server_message::<DamageDealt>(Channel::Ordered);
+    }
+}
+
+/// A [`Message`] broadcast by the server whenever an entity takes damage,
+/// so that clients can give feedback about it.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct DamageDealt {
+    #[entities]
+    pub target: Entity,
+    /// Character dealing the damage, if any.
+    #[entities]
+    pub source: Option<Entity>,
+    pub amount: f32,
+    pub kind: DamageKind,
+}
+
+impl DamageDealt {
+    /// Wraps the damage in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_rapier3d::prelude::Velocity;
+use bevy_replicon::prelude::*;
+use merlo_model::{Dead, Health, Match, Player, SpawnPoint};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin::ControlledCharacter,
+    controller::{CharacterDimensions, has_server_authority},
+    game_mode,
+};
+
+pub struct DeathPlugin;
+
+impl Plugin for DeathPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_server_message::<Died>(Channel::Ordered)
+            .add_mapped_server_message::<Respawned>(Channel::Ordered)
+            .add_mapped_server_message::<SpawnChoices>(Channel::Ordered)
+            .add_mapped_client_message::<ChooseSpawnPoint>(Channel::Ordered)
+            .add_systems(
+                Update,
+                (kill_characters, choose_spawn_points, respawn_characters)
+                    .chain()
+                    .run_if(has_server_authority),
+            );
+    }
+}
+
+/// How long a character stays dead before being respawned, unless the game mode says
+/// otherwise.
+pub(crate) const RESPAWN_DELAY: Duration = Duration::from_secs(5);
+/// Height of the origin of a character of the default size above the spawn point it
+/// stands on.
+pub(crate) const SPAWN_HEIGHT: f32 = CharacterDimensions::DEFAULT.origin_to_foot();
+/// Distance from living characters within which a spawn point cannot be chosen.
+const SPAWN_CLEARANCE: f32 = 2.0;
+
+/// A [`Message`] broadcast by the server when the health of a character runs out.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct Died {
+    #[entities]
+    pub character: Entity,
+}
+
+impl Died {
+    /// Wraps the death in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// A [`Message`] broadcast by the server when a dead character is back at a spawn point.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct Respawned {
+    #[entities]
+    pub character: Entity,
+}
+
+impl Respawned {
+    /// Wraps the respawn in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// A [`Message`] sent by the server to the client controlling a dead character, with the
+/// spawn points it is allowed to choose to respawn at.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone)]
+pub struct SpawnChoices {
+    #[entities]
+    pub character: Entity,
+    #[entities]
+    pub spawn_points: Vec<Entity>,
+}
+
+/// A [`Message`] written by a client to respawn its dead character at one of the spawn
+/// points it was offered, which the server checks again before moving the character there.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ChooseSpawnPoint {
+    #[entities]
+    pub character: Entity,
+    #[entities]
+    pub spawn_point: Entity,
+}
+
+/// The spawn point chosen for a dead character, on the server.
+#[derive(Component)]
+struct ChosenSpawnPoint(Entity);
+
+/// Time left before the server respawns a dead character.
+#[derive(Component)]
+struct RespawnTimer(Timer);
+
+/// Moves the character to a spawn point with full health right away, dead or not.
+pub(crate) fn respawn(commands: &mut Commands, character: Entity) {
+    commands
+        .entity(character)
+        .insert(RespawnTimer(Timer::new(Duration::ZERO, TimerMode::Once)));
+}
+
+/// Whether a character may respawn at `spawn_point`, with no living character close to it.
+fn is_clear<'a>(spawn_point: &Transform, mut living: impl Iterator<Item = &'a Transform>) -> bool {
+    living
+        .all(|transform| transform.translation.distance(spawn_point.translation) > SPAWN_CLEARANCE)
+}
+
+/// Kills characters out of health, offering their clients a choice of spawn points when
+/// more than one is clear.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn kill_characters(
+    mut commands: Commands,
+    mut died_writer: MessageWriter<ToClients<Died>>,
+    mut choices_writer: MessageWriter<ToClients<SpawnChoices>>,
+    matches: Query<&Match>,
+    clients: Query<(Entity, &ControlledCharacter)>,
+    spawn_points: Query<(Entity, &Transform), With<SpawnPoint>>,
+    living: Query<(Entity, &Transform), (With<Player>, Without<Dead>)>,
+    characters: Query<(Entity, &Health), (With<Player>, Without<Dead>, Changed<Health>)>,
+) {
+    let delay = game_mode::rules_of(&matches).respawn_delay();
+    for (character, health) in &characters {
+        if health.current > 0.0 {
+            continue;
+        }
+        commands
+            .entity(character)
+            .insert((Dead, RespawnTimer(Timer::new(delay, TimerMode::Once))));
+        died_writer.write(Died { character }.broadcast());
+
+        // The character is not dead yet for the query, but it does not stand in the way.
+        let others = || {
+            living
+                .iter()
+                .filter(move |&(other, _)| other != character)
+                .map(|(_, transform)| transform)
+        };
+        let allowed: Vec<_> = spawn_points
+            .iter()
+            .filter(|(_, spawn_point)| is_clear(spawn_point, others()))
+            .map(|(spawn_point, _)| spawn_point)
+            .collect();
+        if allowed.len() < 2 {
+            continue;
+        }
+        // Characters controlled by no remote client belong to the host.
+        let client_id = clients
+            .iter()
+            .find(|(_, controlled)| controlled.0 == character)
+            .map_or(ClientId::Server, |(client, _)| ClientId::Client(client));
+        choices_writer.write(ToClients {
+            mode: SendMode::Direct(client_id),
+            message: SpawnChoices {
+                character,
+                spawn_points: allowed,
+            },
+        });
+    }
+}
+
+/// Remembers the spawn points chosen by clients for their dead characters.
+fn choose_spawn_points(
+    mut commands: Commands,
+    mut choice_reader: MessageReader<FromClient<ChooseSpawnPoint>>,
+    clients: Query<&ControlledCharacter>,
+    spawn_points: Query<(), With<SpawnPoint>>,
+    dead: Query<(), With<RespawnTimer>>,
+) {
+    for event in choice_reader.read() {
+        let ChooseSpawnPoint {
+            character,
+            spawn_point,
+        } = event.message;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(character))
+        {
+            warn!(
+                "Ignoring spawn point of {character} from {:?}, which does not control it",
+                event.client_id
+            );
+            continue;
+        }
+        if !dead.contains(character) || !spawn_points.contains(spawn_point) {
+            continue;
+        }
+        commands
+            .entity(character)
+            .insert(ChosenSpawnPoint(spawn_point));
+    }
+}
+
+/// Moves dead characters to a spawn point with full health once their timer runs out,
+/// at the one chosen by their client while still clear, or else taking spawn points in turn.
+#[allow(clippy::type_complexity)]
+fn respawn_characters(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut next_spawn_point: Local<usize>,
+    mut respawned_writer: MessageWriter<ToClients<Respawned>>,
+    spawn_points: Query<&Transform, (With<SpawnPoint>, Without<RespawnTimer>)>,
+    living: Query<&Transform, (With<Player>, Without<Dead>, Without<RespawnTimer>)>,
+    mut characters: Query<(
+        Entity,
+        &mut RespawnTimer,
+        &mut Transform,
+        &mut Health,
+        Option<&CharacterDimensions>,
+        Option<&mut Velocity>,
+        Option<&ChosenSpawnPoint>,
+    )>,
+) {
+    for (character, mut timer, mut transform, mut health, dimensions, velocity, chosen) in
+        &mut characters
+    {
+        if !timer.0.tick(time.delta()).just_finished() {
+            continue;
+        }
+        let chosen = chosen
+            .and_then(|chosen| spawn_points.get(chosen.0).ok())
+            .filter(|spawn_point| is_clear(spawn_point, living.iter()));
+        // Without any spawn point, characters come back where they died.
+        let spawn_count = spawn_points.iter().len();
+        let spawn_point = chosen.or_else(|| {
+            let spawn_point = spawn_points
+                .iter()
+                .nth(*next_spawn_point % spawn_count.max(1));
+            *next_spawn_point += 1;
+            spawn_point
+        });
+        if let Some(spawn_point) = spawn_point {
+            let height = dimensions.map_or(SPAWN_HEIGHT, CharacterDimensions::origin_to_foot);
+            transform.translation = spawn_point.translation + Vec3::Y * height;
+        }
+        if let Some(mut velocity) = velocity {
+            *velocity = Velocity::zero();
+        }
+        health.current = health.max;
+        commands
+            .entity(character)
+            .remove::<(Dead, RespawnTimer, ChosenSpawnPoint)>();
+        respawned_writer.write(Respawned { character }.broadcast());
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::{Duration, Instant, SystemTime},
+};
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::ConnectedClient;
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+
+use crate::network::{Cli, ServerInfo};
+
+/// Type of the mDNS service servers advertise themselves as on the local network.
+pub const SERVICE_TYPE: &str = "_merlo._udp.local.";
+/// How long `--discover` looks for servers before giving up.
+pub const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(3);
+const WITHDRAW_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Advertises servers listening for remote clients over mDNS, so that clients find them on
+/// networks which block broadcasts.
+pub struct DiscoveryPlugin;
+
+impl Plugin for DiscoveryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostStartup,
+            advertise_server.run_if(resource_exists::<ServerInfo>),
+        )
+        .add_systems(
+            Update,
+            update_advertised_players.run_if(resource_exists::<Advertisement>),
+        )
+        .add_systems(
+            Last,
+            withdraw_advertisement
+                .run_if(on_message::<AppExit>.and(resource_exists::<Advertisement>)),
+        );
+    }
+}
+
+/// A server found on the local network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredServer {
+    /// Name of the player hosting it.
+    pub name: String,
+    pub addr: SocketAddr,
+    /// Number of clients connected when it was found.
+    pub players: usize,
+    /// Version of the game it runs.
+    pub version: String,
+}
+
+/// Looks for servers on the local network for `timeout`, returning the ones it found.
+pub fn discover(timeout: Duration) -> Result<Vec<DiscoveredServer>> {
+    let daemon = ServiceDaemon::new()?;
+    let receiver = daemon.browse(SERVICE_TYPE)?;
+    let deadline = Instant::now() + timeout;
+    let mut servers = Vec::new();
+    while let Ok(event) = receiver.recv_deadline(deadline) {
+        let ServiceEvent::ServiceResolved(service) = event else {
+            continue;
+        };
+        // Loopback addresses only reach servers on the same machine.
+        let addresses = service.get_addresses_v4();
+        let Some(&ip) = addresses
+            .iter()
+            .find(|ip| !ip.is_loopback())
+            .or_else(|| addresses.iter().next())
+        else {
+            continue;
+        };
+        let property = |key| {
+            service
+                .txt_properties
+                .get_property_val_str(key)
+                .unwrap_or_default()
+                .to_owned()
+        };
+        let server = DiscoveredServer {
+            name: property("name"),
+            addr: SocketAddr::new(IpAddr::V4(ip), service.get_port()),
+            players: property("players").parse().unwrap_or_default(),
+            version: property("version"),
+        };
+        // Servers are resolved again as more of their addresses are found.
+        match servers
+            .iter_mut()
+            .find(|(fullname, _)| *fullname == service.fullname)
+        {
+            Some((_, found)) => *found = server,
+            None => servers.push((service.fullname.clone(), server)),
+        }
+    }
+    daemon.shutdown()?;
+    Ok(servers.into_iter().map(|(_, server)| server).collect())
+}
+
+/// The mDNS service of the server, re-registered whenever clients connect or disconnect.
+#[derive(Resource)]
+struct Advertisement {
+    daemon: ServiceDaemon,
+    name: String,
+    host: String,
+    port: u16,
+    players: usize,
+    fullname: String,
+}
+
+impl Advertisement {
+    fn register(&mut self) -> Result<()> {
+        let properties = [
+            ("name", self.name.clone()),
+            ("players", self.players.to_string()),
+            ("version", env!("CARGO_PKG_VERSION").to_owned()),
+        ];
+        let instance = format!("{}-{}", self.name, self.port);
+        let service = ServiceInfo::new(
+            SERVICE_TYPE,
+            &instance,
+            &self.host,
+            (),
+            self.port,
+            &properties[..],
+        )?
+        .enable_addr_auto();
+        self.fullname = service.get_fullname().to_owned();
+        self.daemon.register(service)?;
+        Ok(())
+    }
+}
+
+fn advertise_server(
+    mut commands: Commands,
+    cli: Res<Cli>,
+    server: Res<ServerInfo>,
+    clients: Query<(), With<ConnectedClient>>,
+) {
+    let advertisement = ServiceDaemon::new()
+        .map_err(BevyError::from)
+        .and_then(|daemon| {
+            // Tells this host apart from others advertising on the same port.
+            let started = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+            let mut advertisement = Advertisement {
+                daemon,
+                name: cli.name().to_owned(),
+                host: format!("merlo-{:x}.local.", started.as_millis()),
+                port: server.addr.port(),
+                players: clients.iter().len(),
+                fullname: String::new(),
+            };
+            advertisement.register()?;
+            Ok(advertisement)
+        });
+    match advertisement {
+        Ok(advertisement) => {
+            info!("Advertising the server as {}", advertisement.fullname);
+            commands.insert_resource(advertisement);
+        }
+        Err(err) => warn!("Cannot advertise the server on the local network: {err}"),
+    }
+}
+
+fn update_advertised_players(
+    mut advertisement: ResMut<Advertisement>,
+    clients: Query<(), With<ConnectedClient>>,
+) {
+    let count = clients.iter().len();
+    if count == advertisement.players {
+        return;
+    }
+    advertisement.players = count;
+    if let Err(err) = advertisement.register() {
+        warn!("Cannot update the advertisement of the server: {err}");
+    }
+}
+
+/// Tells the clients on the local network that the server is gone, waiting shortly for the
+/// daemon to send it before exiting.
+fn withdraw_advertisement(advertisement: Res<Advertisement>) {
+    match advertisement.daemon.unregister(&advertisement.fullname) {
+        Ok(status) => _ = status.recv_timeout(WITHDRAW_TIMEOUT),
+        Err(err) => warn!("Cannot withdraw the advertisement of the server: {err}"),
+    }
+}
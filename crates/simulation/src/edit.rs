@@ -0,0 +1,286 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::collections::VecDeque;
+
+use bevy::{
+    ecs::{entity::MapEntities, message::MessageCursor},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use bevy_rapier3d::prelude::Velocity;
+use bevy_replicon::prelude::*;
+use merlo_model::{Health, Player};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    controller::has_server_authority,
+    persistence::{SavedEntity, SavedFilter},
+};
+
+/// Number of edits each client can undo.
+const MAX_HISTORY: usize = 100;
+/// Distance from an entity where its duplicate is placed, so that they do not overlap.
+const DUPLICATE_OFFSET: Vec3 = Vec3::new(1.0, 0.0, 1.0);
+
+pub struct EditPlugin;
+
+impl Plugin for EditPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_client_message::<EditRequest>(Channel::Ordered)
+            .init_resource::<EditHistories>()
+            .add_observer(forget_history)
+            .add_systems(Update, apply_edit_requests.run_if(has_server_authority));
+    }
+}
+
+/// A property of a replicated entity which can be edited.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Property {
+    Transform(Transform),
+    Health(Health),
+}
+
+/// A [`Message`] written by a client to edit the world from the editor.
+///
+/// Edits are recorded on the server for every client, so that undoing and redoing them
+/// goes through the server too, and is replicated like any other edit. Like admin commands,
+/// they are only accepted from the server itself, never from remote clients.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum EditRequest {
+    /// Sets a property of an entity, such as its transform to move it.
+    Set {
+        #[entities]
+        entity: Entity,
+        property: Property,
+    },
+    /// Despawns an entity.
+    Delete {
+        #[entities]
+        entity: Entity,
+    },
+    /// Spawns a copy of an entity next to it.
+    Duplicate {
+        #[entities]
+        entity: Entity,
+    },
+    /// Reverts the last edit of the client.
+    Undo,
+    /// Applies again the last edit undone by the client.
+    Redo,
+}
+
+/// An edit of the world, which turns into the edit reverting it once applied.
+pub(crate) enum Edit {
+    Set {
+        entity: Entity,
+        property: Property,
+    },
+    Despawn(Entity),
+    Duplicate(Entity),
+    /// Spawns back an entity, which the other edits still know as `entity`.
+    Respawn {
+        entity: Entity,
+        saved: Box<SavedEntity>,
+    },
+}
+
+impl Edit {
+    fn entity_mut(&mut self) -> &mut Entity {
+        match self {
+            Edit::Set { entity, .. }
+            | Edit::Despawn(entity)
+            | Edit::Duplicate(entity)
+            | Edit::Respawn { entity, .. } => entity,
+        }
+    }
+
+    /// Applies the edit, returning the one reverting it, or `None` if its entity is gone.
+    fn apply(self, world: &mut World, histories: &mut EditHistories) -> Option<Edit> {
+        match self {
+            Edit::Set { entity, property } => {
+                let mut entity_mut = world.get_entity_mut(entity).ok()?;
+                let previous = match property {
+                    Property::Transform(transform) => {
+                        let previous = *entity_mut.get::<Transform>()?;
+                        entity_mut.insert(transform);
+                        // Moved bodies should not keep falling or rolling as they were.
+                        if let Some(mut velocity) = entity_mut.get_mut::<Velocity>() {
+                            *velocity = Velocity::zero();
+                        }
+                        Property::Transform(previous)
+                    }
+                    Property::Health(health) => {
+                        let previous = *entity_mut.get::<Health>()?;
+                        entity_mut.insert(health);
+                        Property::Health(previous)
+                    }
+                };
+                Some(Edit::Set {
+                    entity,
+                    property: previous,
+                })
+            }
+            Edit::Despawn(entity) => {
+                let saved = Box::new(SavedEntity::of(world.get_entity(entity).ok()?));
+                world.despawn(entity);
+                Some(Edit::Respawn { entity, saved })
+            }
+            Edit::Duplicate(entity) => {
+                let mut saved = duplicate(SavedEntity::of(world.get_entity(entity).ok()?));
+                if let Some(transform) = &mut saved.transform {
+                    transform.translation += DUPLICATE_OFFSET;
+                }
+                let duplicated = saved.spawn(&mut world.commands());
+                world.flush();
+                Some(Edit::Despawn(duplicated))
+            }
+            Edit::Respawn { entity, saved } => {
+                let respawned = saved.spawn(&mut world.commands());
+                world.flush();
+                histories.remap(entity, respawned);
+                Some(Edit::Despawn(respawned))
+            }
+        }
+    }
+}
+
+/// Keeps the components of `saved` which can be copied to a new entity, leaving out the
+/// ones of characters and of the entities the world has only one of.
+fn duplicate(saved: SavedEntity) -> SavedEntity {
+    SavedEntity {
+        transform: saved.transform,
+        health: saved.health,
+        doodad: saved.doodad,
+        lamp: saved.lamp,
+        spawn_point: saved.spawn_point,
+        static_geometry: saved.static_geometry,
+        platform_path: saved.platform_path,
+        trigger_zone: saved.trigger_zone,
+        pickup_spawner: saved.pickup_spawner,
+        ..default()
+    }
+}
+
+/// Edits done and undone by a client, the latest last.
+#[derive(Default)]
+struct EditHistory {
+    undo: VecDeque<Edit>,
+    redo: VecDeque<Edit>,
+}
+
+/// The edit histories of every client, on the server.
+#[derive(Resource, Default)]
+pub struct EditHistories(HashMap<ClientId, EditHistory>);
+
+impl EditHistories {
+    /// Records an edit done by `client_id` as the edit reverting it, which forgets the edits
+    /// it had undone.
+    pub(crate) fn record(&mut self, client_id: ClientId, revert: Edit) {
+        let history = self.0.entry(client_id).or_default();
+        history.redo.clear();
+        if history.undo.len() == MAX_HISTORY {
+            history.undo.pop_front();
+        }
+        history.undo.push_back(revert);
+    }
+
+    /// Reverts the last edit of `client_id`, or applies again the last one it undid.
+    fn step(&mut self, world: &mut World, client_id: ClientId, undo: bool) {
+        let Some(history) = self.0.get_mut(&client_id) else {
+            return;
+        };
+        let stack = if undo {
+            &mut history.undo
+        } else {
+            &mut history.redo
+        };
+        let Some(edit) = stack.pop_back() else {
+            return;
+        };
+        let Some(revert) = edit.apply(world, self) else {
+            debug!("Dropping the edit of {client_id:?}, as its entity is gone");
+            return;
+        };
+        let history = self.0.entry(client_id).or_default();
+        if undo {
+            history.redo.push_back(revert);
+        } else {
+            history.undo.push_back(revert);
+        }
+    }
+
+    /// Points the edits of `entity` to the entity spawning it back.
+    fn remap(&mut self, entity: Entity, respawned: Entity) {
+        let edits = self
+            .0
+            .values_mut()
+            .flat_map(|history| history.undo.iter_mut().chain(&mut history.redo));
+        for edit in edits {
+            let edited = edit.entity_mut();
+            if *edited == entity {
+                *edited = respawned;
+            }
+        }
+    }
+}
+
+/// Applies the edits of clients one at a time, so that each edit sees the previous ones.
+fn apply_edit_requests(
+    world: &mut World,
+    mut cursor: Local<MessageCursor<FromClient<EditRequest>>>,
+) {
+    let requests: Vec<_> = cursor
+        .read(world.resource::<Messages<FromClient<EditRequest>>>())
+        .cloned()
+        .collect();
+    if requests.is_empty() {
+        return;
+    }
+    let mut editable = world.query_filtered::<Has<Player>, SavedFilter>();
+    world.resource_scope(|world, mut histories: Mut<EditHistories>| {
+        for FromClient { client_id, message } in requests {
+            if client_id != ClientId::Server {
+                warn!("Ignoring {message:?} from non-host client {client_id:?}");
+                continue;
+            }
+
+            let edit = match message {
+                EditRequest::Set { entity, property } if editable.get(world, entity).is_ok() => {
+                    Edit::Set { entity, property }
+                }
+                // Characters are neither deleted nor duplicated, as clients control them.
+                EditRequest::Delete { entity }
+                    if matches!(editable.get(world, entity), Ok(false)) =>
+                {
+                    Edit::Despawn(entity)
+                }
+                EditRequest::Duplicate { entity }
+                    if matches!(editable.get(world, entity), Ok(false)) =>
+                {
+                    Edit::Duplicate(entity)
+                }
+                EditRequest::Undo => {
+                    histories.step(world, client_id, true);
+                    continue;
+                }
+                EditRequest::Redo => {
+                    histories.step(world, client_id, false);
+                    continue;
+                }
+                _ => {
+                    warn!("Ignoring {message:?} from {client_id:?} of a non-editable entity");
+                    continue;
+                }
+            };
+            if let Some(revert) = edit.apply(world, &mut histories) {
+                histories.record(client_id, revert);
+            }
+        }
+    });
+}
+
+fn forget_history(remove: On<Remove, ConnectedClient>, mut histories: ResMut<EditHistories>) {
+    histories.0.remove(&ClientId::Client(remove.entity));
+}
@@ -0,0 +1,95 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_replicon::prelude::*;
+use merlo_model::Emote;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin::ControlledCharacter,
+    controller::{CharacterController, has_server_authority},
+};
+
+pub struct EmotePlugin;
+
+impl Plugin for EmotePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_client_message::<EmoteInput>(Channel::Ordered)
+            .add_mapped_server_message::<EmotePlayed>(Channel::Ordered)
+            .add_systems(Update, keyboard_input)
+            .add_systems(Update, relay_emotes.run_if(has_server_authority));
+    }
+}
+
+/// A [`Message`] written by a client to perform an emote with its character.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct EmoteInput {
+    #[entities]
+    pub character: Entity,
+    pub emote: Emote,
+}
+
+/// A [`Message`] broadcast by the server whenever a character performs an emote.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct EmotePlayed {
+    #[entities]
+    pub character: Entity,
+    pub emote: Emote,
+}
+
+impl EmotePlayed {
+    /// Wraps the emote in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// Sends [`EmoteInput`] for the controlled character with the number keys.
+fn keyboard_input(
+    mut emote_writer: MessageWriter<EmoteInput>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+) {
+    let Ok(child) = controller.single() else {
+        return;
+    };
+    let emotes = [
+        (KeyCode::Digit1, Emote::Wave),
+        (KeyCode::Digit2, Emote::Cheer),
+        (KeyCode::Digit3, Emote::Point),
+    ];
+    for (key, emote) in emotes {
+        if keyboard_input.just_pressed(key) {
+            emote_writer.write(EmoteInput {
+                character: child.parent(),
+                emote,
+            });
+        }
+    }
+}
+
+/// Broadcasts emotes of clients performed with the character they control.
+fn relay_emotes(
+    mut emote_reader: MessageReader<FromClient<EmoteInput>>,
+    mut emote_writer: MessageWriter<ToClients<EmotePlayed>>,
+    clients: Query<&ControlledCharacter>,
+) {
+    for event in emote_reader.read() {
+        let EmoteInput { character, emote } = event.message;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(character))
+        {
+            warn!(
+                "Ignoring emote of {:?} for character {character} it does not control",
+                event.client_id
+            );
+            continue;
+        }
+        emote_writer.write(EmotePlayed { character, emote }.broadcast());
+    }
+}
@@ -0,0 +1,456 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use bevy::{ecs::entity::EntityHashMap, prelude::*};
+use bevy_rapier3d::prelude::Velocity;
+use bevy_replicon::prelude::*;
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use merlo_model::{
+    Carried, Dead, Doodad, GameModeKind, Health, It, Match, Player, RoundPhase, ScoreEntry,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin::ControlledCharacter,
+    attack::AttackHit,
+    controller::has_server_authority,
+    damage::DamageDealt,
+    death::{self, Died},
+    kill_plane::DoodadHome,
+    scoreboard::ScoreOwner,
+};
+
+/// Runs the rounds of the [`Match`] following the rules of its [`GameMode`].
+pub struct GameModePlugin;
+
+impl Plugin for GameModePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_server_message::<PhaseChanged>(Channel::Ordered)
+            .add_systems(
+                Update,
+                (
+                    award_kills,
+                    award_seconds,
+                    advance_rounds,
+                    reset_level,
+                    pass_it.run_if(in_game_mode(GameModeKind::Tag)),
+                )
+                    .chain()
+                    .run_if(has_server_authority),
+            );
+    }
+}
+
+/// How long characters wait at their spawn points before a round starts, unless the game
+/// mode says otherwise.
+const WARMUP: Duration = Duration::from_secs(10);
+/// How long the result of a round is shown before the warmup of the next one.
+const INTERMISSION: Duration = Duration::from_secs(10);
+const KINDS: [GameModeKind; 3] = [
+    GameModeKind::Sandbox,
+    GameModeKind::Tag,
+    GameModeKind::Deathmatch,
+];
+
+/// Rules of a game mode, consulted by the round flow every mode shares.
+pub trait GameMode: Sync {
+    /// Name of the mode, as given to `--mode`.
+    fn name(&self) -> &'static str;
+
+    /// How long characters wait at their spawn points before a round starts.
+    fn warmup(&self) -> Duration {
+        WARMUP
+    }
+
+    /// How long rounds last, or `None` when they last until someone reaches the score limit.
+    fn round_length(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Score ending the round as soon as a player reaches it.
+    fn score_limit(&self) -> Option<i32> {
+        None
+    }
+
+    /// How long dead characters wait before respawning.
+    fn respawn_delay(&self) -> Duration {
+        death::RESPAWN_DELAY
+    }
+
+    /// Points for killing the character of another player.
+    fn kill_points(&self) -> i32 {
+        0
+    }
+
+    /// Points a player earns every second of a round, depending on whether their character
+    /// is [`It`].
+    fn points_per_second(&self, _it: bool) -> i32 {
+        0
+    }
+}
+
+struct Sandbox;
+
+impl GameMode for Sandbox {
+    fn name(&self) -> &'static str {
+        "sandbox"
+    }
+
+    fn warmup(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+struct Tag;
+
+impl GameMode for Tag {
+    fn name(&self) -> &'static str {
+        "tag"
+    }
+
+    fn round_length(&self) -> Option<Duration> {
+        Some(Duration::from_secs(120))
+    }
+
+    fn points_per_second(&self, it: bool) -> i32 {
+        if it { 0 } else { 1 }
+    }
+}
+
+struct Deathmatch;
+
+impl GameMode for Deathmatch {
+    fn name(&self) -> &'static str {
+        "deathmatch"
+    }
+
+    fn round_length(&self) -> Option<Duration> {
+        Some(Duration::from_secs(300))
+    }
+
+    fn score_limit(&self) -> Option<i32> {
+        Some(10)
+    }
+
+    fn respawn_delay(&self) -> Duration {
+        Duration::from_secs(3)
+    }
+
+    fn kill_points(&self) -> i32 {
+        1
+    }
+}
+
+/// The rules of a game mode.
+pub fn rules(kind: GameModeKind) -> &'static dyn GameMode {
+    match kind {
+        GameModeKind::Sandbox => &Sandbox,
+        GameModeKind::Tag => &Tag,
+        GameModeKind::Deathmatch => &Deathmatch,
+    }
+}
+
+/// The rules of the match being played, or of the sandbox when there is none.
+pub fn rules_of(matches: &Query<&Match>) -> &'static dyn GameMode {
+    rules(
+        matches
+            .iter()
+            .next()
+            .map(|game| game.mode)
+            .unwrap_or_default(),
+    )
+}
+
+/// Parses the name of a game mode on the command line, listing the valid ones in the help.
+pub fn parser() -> impl TypedValueParser<Value = GameModeKind> {
+    PossibleValuesParser::new(KINDS.map(|kind| rules(kind).name())).map(|name| {
+        KINDS
+            .into_iter()
+            .find(|&kind| rules(kind).name() == name)
+            .unwrap_or_default()
+    })
+}
+
+/// A [`Message`] broadcast by the server when the match moves on to another phase.
+#[derive(Message, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct PhaseChanged {
+    pub round: u32,
+    pub phase: RoundPhase,
+}
+
+impl PhaseChanged {
+    /// Wraps the change in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// Whether characters are waiting for the round to start, without moving nor fighting.
+pub fn in_warmup(matches: Query<&Match>) -> bool {
+    matches.iter().any(|game| game.phase == RoundPhase::Warmup)
+}
+
+/// A run condition for systems of a single game mode.
+pub fn in_game_mode(kind: GameModeKind) -> impl Fn(Query<&Match>) -> bool {
+    move |matches| matches.iter().any(|game| game.mode == kind)
+}
+
+/// The player controlling `character`: the client moving it, or else the host.
+fn controller_of(character: Entity, clients: &Query<(Entity, &ControlledCharacter)>) -> ClientId {
+    clients
+        .iter()
+        .find(|(_, controlled)| controlled.0 == character)
+        .map_or(ClientId::Server, |(client, _)| ClientId::Client(client))
+}
+
+fn add_points(entries: &mut Query<(&ScoreOwner, &mut ScoreEntry)>, owner: ClientId, points: i32) {
+    if points == 0 {
+        return;
+    }
+    for (_, mut entry) in entries
+        .iter_mut()
+        .filter(|(entry_owner, _)| entry_owner.0 == owner)
+    {
+        entry.score += points;
+    }
+}
+
+/// Gives points to whoever dealt the last blow to a dying character.
+#[allow(clippy::too_many_arguments)]
+fn award_kills(
+    mut damage_reader: MessageReader<DamageDealt>,
+    mut died_reader: MessageReader<Died>,
+    mut last_attackers: Local<EntityHashMap<Entity>>,
+    matches: Query<&Match>,
+    players: Query<(), With<Player>>,
+    clients: Query<(Entity, &ControlledCharacter)>,
+    mut entries: Query<(&ScoreOwner, &mut ScoreEntry)>,
+) {
+    for damage in damage_reader.read() {
+        if let Some(source) = damage.source
+            && source != damage.target
+            && players.contains(damage.target)
+        {
+            last_attackers.insert(damage.target, source);
+        }
+    }
+    let playing = matches.iter().any(|game| game.phase == RoundPhase::Active);
+    let points = rules_of(&matches).kill_points();
+    for died in died_reader.read() {
+        let Some(killer) = last_attackers.remove(&died.character) else {
+            continue;
+        };
+        let owner = controller_of(killer, &clients);
+        // Characters nobody controls all count as the host, who gains nothing from them.
+        if playing && owner != controller_of(died.character, &clients) {
+            add_points(&mut entries, owner, points);
+        }
+    }
+}
+
+/// Gives points every second to every player, once for all the characters they control.
+#[allow(clippy::type_complexity)]
+fn award_seconds(
+    time: Res<Time>,
+    mut elapsed: Local<Duration>,
+    matches: Query<&Match>,
+    characters: Query<(Entity, Has<It>), (With<Player>, Without<Dead>)>,
+    clients: Query<(Entity, &ControlledCharacter)>,
+    mut entries: Query<(&ScoreOwner, &mut ScoreEntry)>,
+) {
+    if !matches.iter().any(|game| game.phase == RoundPhase::Active) {
+        *elapsed = Duration::ZERO;
+        return;
+    }
+    *elapsed += time.delta();
+    if *elapsed < Duration::from_secs(1) {
+        return;
+    }
+    *elapsed -= Duration::from_secs(1);
+
+    let mut owners: Vec<(ClientId, bool)> = Vec::new();
+    for (character, it) in &characters {
+        let owner = controller_of(character, &clients);
+        match owners.iter_mut().find(|(other, _)| *other == owner) {
+            Some((_, owner_it)) => *owner_it |= it,
+            None => owners.push((owner, it)),
+        }
+    }
+    let rules = rules_of(&matches);
+    for (owner, it) in owners {
+        add_points(&mut entries, owner, rules.points_per_second(it));
+    }
+}
+
+/// The name of the only player with the highest score, if any.
+fn leader(entries: &Query<(&ScoreOwner, &mut ScoreEntry)>) -> Option<String> {
+    let best = entries.iter().map(|(_, entry)| entry.score).max()?;
+    let mut leaders = entries.iter().filter(|(_, entry)| entry.score == best);
+    match (leaders.next(), leaders.next()) {
+        (Some((_, entry)), None) => Some(entry.name.clone()),
+        _ => None,
+    }
+}
+
+fn whole_seconds(duration: Duration) -> u32 {
+    duration.as_secs_f32().ceil() as u32
+}
+
+/// Moves the match on to the next phase once the current one is over: from the warmup to
+/// the round, to the post-round once its time is up or someone reaches the score limit, and
+/// back to the warmup of the next round.
+fn advance_rounds(
+    time: Res<Time>,
+    mut elapsed: Local<Duration>,
+    mut phase_writer: MessageWriter<ToClients<PhaseChanged>>,
+    mut matches: Query<&mut Match>,
+    entries: Query<(&ScoreOwner, &mut ScoreEntry)>,
+) {
+    let Ok(mut game) = matches.single_mut() else {
+        return;
+    };
+    let rules = rules(game.mode);
+    *elapsed += time.delta();
+
+    let next = match game.phase {
+        RoundPhase::Warmup if *elapsed >= rules.warmup() => Some(RoundPhase::Active),
+        RoundPhase::Active => {
+            let limit_reached = rules
+                .score_limit()
+                .is_some_and(|limit| entries.iter().any(|(_, entry)| entry.score >= limit));
+            let time_up = rules
+                .round_length()
+                .is_some_and(|length| *elapsed >= length);
+            (limit_reached || time_up).then_some(RoundPhase::PostRound)
+        }
+        RoundPhase::PostRound if *elapsed >= INTERMISSION => Some(RoundPhase::Warmup),
+        _ => None,
+    };
+    if let Some(phase) = next {
+        *elapsed = Duration::ZERO;
+        match phase {
+            RoundPhase::Warmup => {
+                game.round += 1;
+                game.winner = None;
+            }
+            RoundPhase::Active => info!("Round {} started", game.round),
+            RoundPhase::PostRound => {
+                game.winner = leader(&entries);
+                match &game.winner {
+                    Some(winner) => info!("Round {} won by {winner}", game.round),
+                    None => info!("Round {} ended in a draw", game.round),
+                }
+            }
+        }
+        game.phase = phase;
+        phase_writer.write(
+            PhaseChanged {
+                round: game.round,
+                phase,
+            }
+            .broadcast(),
+        );
+    }
+
+    let length = match game.phase {
+        RoundPhase::Warmup => Some(rules.warmup()),
+        RoundPhase::Active => rules.round_length(),
+        RoundPhase::PostRound => Some(INTERMISSION),
+    };
+    let seconds_left = length.map(|length| whole_seconds(length.saturating_sub(*elapsed)));
+    // Replicated once per second, not every frame.
+    if game.seconds_left != seconds_left {
+        game.seconds_left = seconds_left;
+    }
+}
+
+/// Brings the level back to how the round before found it, once the warmup of the next
+/// round starts: scores to zero, characters at the spawn points, and doodads where they
+/// were placed.
+#[allow(clippy::type_complexity)]
+fn reset_level(
+    mut commands: Commands,
+    mut phase_reader: MessageReader<PhaseChanged>,
+    mut entries: Query<&mut ScoreEntry>,
+    players: Query<Entity, With<Player>>,
+    its: Query<Entity, With<It>>,
+    mut doodads: Query<
+        (
+            Entity,
+            &DoodadHome,
+            &mut Transform,
+            &mut Health,
+            Option<&mut Velocity>,
+        ),
+        With<Doodad>,
+    >,
+) {
+    if !phase_reader
+        .read()
+        .any(|changed| changed.phase == RoundPhase::Warmup)
+    {
+        return;
+    }
+    for mut entry in &mut entries {
+        // Avoid replicating the entries already at zero.
+        if entry.score != 0 {
+            entry.score = 0;
+        }
+    }
+    for player in &players {
+        death::respawn(&mut commands, player);
+    }
+    for it in &its {
+        commands.entity(it).remove::<It>();
+    }
+    for (doodad, home, mut transform, mut health, velocity) in &mut doodads {
+        commands.entity(doodad).remove::<Carried>();
+        *transform = home.0;
+        health.current = health.max;
+        if let Some(mut velocity) = velocity {
+            *velocity = Velocity::zero();
+        }
+    }
+}
+
+/// Makes sure somebody is it while playing, and passes it on to whoever it hits.
+fn pass_it(
+    mut commands: Commands,
+    mut hit_reader: MessageReader<AttackHit>,
+    matches: Query<&Match>,
+    its: Query<Entity, With<It>>,
+    players: Query<Entity, (With<Player>, Without<Dead>)>,
+) {
+    let Ok(game) = matches.single() else {
+        return;
+    };
+    if game.phase != RoundPhase::Active {
+        return;
+    }
+
+    let mut it = its.iter().next();
+    for hit in hit_reader.read() {
+        if Some(hit.attacker) == it && players.contains(hit.target) {
+            commands.entity(hit.attacker).remove::<It>();
+            commands.entity(hit.target).insert(It);
+            it = Some(hit.target);
+        }
+    }
+
+    if it.is_none() {
+        // Taking turns, round after round.
+        let mut players: Vec<_> = players.iter().collect();
+        players.sort();
+        if let Some(&player) =
+            players.get((game.round as usize).saturating_sub(1) % players.len().max(1))
+        {
+            commands.entity(player).insert(It);
+        }
+    }
+}
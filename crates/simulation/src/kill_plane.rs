@@ -0,0 +1,74 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use merlo_model::{Dead, Doodad, Health, Level, Player};
+
+use crate::controller::has_server_authority;
+
+pub struct KillPlanePlugin;
+
+impl Plugin for KillPlanePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(remember_doodad_home).add_systems(
+            Update,
+            (kill_fallen_characters, reset_fallen_doodads).run_if(has_server_authority),
+        );
+    }
+}
+
+/// Where a doodad was placed, on the server, to bring it back when it falls off the world.
+#[derive(Component)]
+pub(crate) struct DoodadHome(pub(crate) Transform);
+
+fn remember_doodad_home(
+    add: On<Add, Doodad>,
+    mut commands: Commands,
+    transforms: Query<&Transform>,
+) {
+    if let Ok(transform) = transforms.get(add.entity) {
+        commands.entity(add.entity).insert(DoodadHome(*transform));
+    }
+}
+
+/// The kill height of the level, which single player goes without.
+fn kill_height(levels: &Query<&Level>) -> f32 {
+    levels
+        .single()
+        .map_or(Level::DEFAULT_KILL_HEIGHT, |level| level.kill_height)
+}
+
+/// Empties the health of characters falling below the kill height, so that they die and
+/// respawn at a spawn point like any other death.
+#[allow(clippy::type_complexity)]
+fn kill_fallen_characters(
+    levels: Query<&Level>,
+    mut characters: Query<(Entity, &Transform, &mut Health), (With<Player>, Without<Dead>)>,
+) {
+    let kill_height = kill_height(&levels);
+    for (character, transform, mut health) in &mut characters {
+        if transform.translation.y < kill_height && health.current > 0.0 {
+            info!("{character} fell off the world");
+            health.current = 0.0;
+        }
+    }
+}
+
+/// Puts doodads falling below the kill height back where they were placed, at rest.
+fn reset_fallen_doodads(
+    levels: Query<&Level>,
+    mut doodads: Query<(&DoodadHome, &mut Transform, Option<&mut Velocity>)>,
+) {
+    let kill_height = kill_height(&levels);
+    for (home, mut transform, velocity) in &mut doodads {
+        if transform.translation.y >= kill_height {
+            continue;
+        }
+        *transform = home.0;
+        if let Some(mut velocity) = velocity {
+            *velocity = Velocity::zero();
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::Replicated;
+use merlo_model::{Level, PickupSpawner, PlatformPath, StaticGeometry, TriggerZone, WeatherKind};
+use serde::Deserialize;
+
+use crate::spawn::Archetype;
+
+/// The level played when the server is not given one.
+const DEFAULT_LEVEL: &str = include_str!("../levels/default.ron");
+
+/// Something placed in a [`LevelFile`].
+#[derive(Deserialize, Debug, Clone)]
+pub struct Placement<T> {
+    pub kind: T,
+    pub translation: Vec3,
+    #[serde(default)]
+    pub rotation: Quat,
+}
+
+impl<T> Placement<T> {
+    fn transform(&self) -> Transform {
+        Transform::from_translation(self.translation).with_rotation(self.rotation)
+    }
+}
+
+/// Geometry moving along a path, starting from its first waypoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct PlatformPlacement {
+    pub kind: StaticGeometry,
+    pub path: PlatformPath,
+}
+
+/// A level authored in a RON file, spawned by the server as replicated entities so
+/// that clients reconstruct the same level.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct LevelFile {
+    pub name: String,
+    /// Name of the sky under `skies/`.
+    pub sky: String,
+    /// Height below which things fall off the world, or the default one when `None`.
+    pub kill_height: Option<f32>,
+    /// Weather the level starts with, before the server moves on to the next ones.
+    pub weather: WeatherKind,
+    pub geometry: Vec<Placement<StaticGeometry>>,
+    pub platforms: Vec<PlatformPlacement>,
+    pub zones: Vec<Placement<TriggerZone>>,
+    pub pickups: Vec<Placement<PickupSpawner>>,
+    /// Doodads, lamps, and spawn points.
+    pub entities: Vec<Placement<Archetype>>,
+}
+
+impl LevelFile {
+    /// Reads the level at `path`, or the default level when `None`.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let level = match path {
+            Some(path) => ron::from_str(&std::fs::read_to_string(path)?)?,
+            None => ron::from_str(DEFAULT_LEVEL)?,
+        };
+        Ok(level)
+    }
+
+    pub fn level(&self) -> Level {
+        let default = Level::default();
+        Level {
+            name: non_empty(&self.name).unwrap_or(default.name),
+            sky: non_empty(&self.sky).unwrap_or(default.sky),
+            kill_height: self.kill_height.unwrap_or(default.kill_height),
+        }
+    }
+
+    /// Positions of the spawn points of the level.
+    pub fn spawn_points(&self) -> impl Iterator<Item = Vec3> {
+        self.entities
+            .iter()
+            .filter(|entity| entity.kind == Archetype::SpawnPoint)
+            .map(|entity| entity.translation)
+    }
+
+    /// Spawns the geometry and the entities of the level.
+    pub fn spawn(&self, commands: &mut Commands) {
+        for geometry in &self.geometry {
+            commands.spawn((Replicated, geometry.transform(), geometry.kind.clone()));
+        }
+        for platform in &self.platforms {
+            let start = platform.path.waypoints.first().copied().unwrap_or_default();
+            commands.spawn((
+                Replicated,
+                Transform::from_translation(start),
+                platform.kind.clone(),
+                platform.path.clone(),
+            ));
+        }
+        for zone in &self.zones {
+            commands.spawn((Replicated, zone.transform(), zone.kind.clone()));
+        }
+        for pickup in &self.pickups {
+            commands.spawn((Replicated, pickup.transform(), pickup.kind));
+        }
+        for entity in &self.entities {
+            entity.kind.spawn(commands, entity.transform());
+        }
+        info!(
+            "Spawned level {} with {} geometries, {} platforms, {} zones, {} pickups, and {} entities",
+            self.name,
+            self.geometry.len(),
+            self.platforms.len(),
+            self.zones.len(),
+            self.pickups.len(),
+            self.entities.len()
+        );
+    }
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    (!text.is_empty()).then(|| text.to_owned())
+}
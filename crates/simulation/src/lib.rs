@@ -1,59 +1,450 @@
+pub mod admin;
+pub mod attack;
+pub mod bench;
+pub mod camera_path;
+pub mod carry;
+pub mod chat;
+pub mod colors;
+pub mod config;
 pub mod controller;
+pub mod damage;
+pub mod death;
+pub mod discovery;
+pub mod edit;
+pub mod emote;
+pub mod game_mode;
+pub mod kill_plane;
+pub mod level;
+pub mod manifest;
+pub mod move_to;
+pub mod navigation;
 pub mod network;
+pub mod persistence;
+pub mod physics;
+pub mod pickup;
+pub mod platform;
+pub mod players;
+pub mod prediction;
+pub mod replay;
+pub mod replication;
+pub mod scatter;
+pub mod scoreboard;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+pub mod shooting;
+pub mod sleeping;
+pub mod spawn;
+pub mod terrain;
+pub mod time_of_day;
+pub mod traffic;
+pub mod trigger_zone;
+#[cfg(feature = "client-visuals")]
+pub mod visuals;
+pub mod weather;
 
-use bevy::prelude::*;
+use std::time::Duration;
+
+use bevy::{
+    app::{PluginGroupBuilder, PluginsState},
+    platform::collections::HashMap,
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+#[cfg(feature = "client-visuals")]
+use bevy::{
+    render::{RenderPlugin, settings::WgpuSettings},
+    winit::WinitPlugin,
+};
+#[cfg(feature = "scene-colliders")]
+use bevy_rapier3d::prelude::AsyncSceneCollider;
 use bevy_rapier3d::prelude::{Collider, RigidBody, Velocity};
 use bevy_replicon::{
     RepliconPlugins,
-    prelude::{AppRuleExt, ClientState, Replicated, RepliconChannels},
+    prelude::{ClientState, Replicated, RepliconChannels, ServerSystems},
 };
 use bevy_replicon_renet::RepliconRenetPlugins;
-use merlo_model::{Doodad, Player};
+use merlo_model::{
+    Carried, CharacterModel, Dead, Doodad, Gravity, Health, It, Lamp, Level, Match, Pickup,
+    PickupIncoming, PickupSpawner, PlatformPath, Player, SpawnPoint, StaticGeometry, TimeOfDay,
+    TriggerZone, Weather,
+};
+
+use crate::{
+    config::ConfigFile,
+    controller::has_server_authority,
+    level::LevelFile,
+    manifest::AssetManifest,
+    network::{Cli, NetworkMode},
+    persistence::WorldSave,
+    physics::PhysicsArgs,
+    replication::{QuantizedVelocity, ReplicateVelocity, TransformThresholds},
+    scoreboard::PlayerJoin,
+    traffic::TrafficAppExt,
+};
+
+/// Health of player characters spawned by the server.
+pub const PLAYER_HEALTH: f32 = 100.0;
+/// Number of player characters spawned by the server.
+const PLAYER_COUNT: usize = 2;
+/// Health of doodads spawned by the server.
+pub const DOODAD_HEALTH: f32 = 50.0;
+
+/// Spawns the world of a game when hosting it, given the command line it was started with.
+pub type WorldSpawner = fn(&mut Commands, &Cli);
+
+/// Networking, character controllers, and gameplay, with a demo world spawned by the server.
+/// Games bringing their own world turn the demo off and spawn theirs with a [`WorldSpawner`].
+#[derive(Clone, Copy)]
+pub struct SimulationPlugin {
+    demo_scene: bool,
+    spawner: Option<WorldSpawner>,
+    transform_thresholds: TransformThresholds,
+}
+
+impl Default for SimulationPlugin {
+    fn default() -> Self {
+        Self {
+            demo_scene: true,
+            spawner: None,
+            transform_thresholds: TransformThresholds::default(),
+        }
+    }
+}
+
+impl SimulationPlugin {
+    /// Whether the server spawns the level, the default one unless given with `--level`,
+    /// along with the characters of the demo.
+    pub fn with_demo_scene(mut self, demo_scene: bool) -> Self {
+        self.demo_scene = demo_scene;
+        self
+    }
+
+    /// Calls `spawner` on startup when hosting, after spawning the demo scene if any.
+    /// It is not called when restoring a saved world, which already holds what it spawned.
+    pub fn with_spawner(mut self, spawner: WorldSpawner) -> Self {
+        self.spawner = Some(spawner);
+        self
+    }
 
-use crate::network::{Cli, NetworkMode};
+    /// How far replicated transforms move or turn before the server sends them again.
+    pub fn with_transform_thresholds(mut self, thresholds: TransformThresholds) -> Self {
+        self.transform_thresholds = thresholds;
+        self
+    }
+}
 
-#[derive(Default)]
-pub struct SimulationPlugin;
+/// How the server spawns its world, as configured on the [`SimulationPlugin`].
+#[derive(Resource)]
+struct WorldConfig {
+    demo_scene: bool,
+    spawner: Option<WorldSpawner>,
+}
 
 impl Plugin for SimulationPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Cli>()
-            .add_plugins(RepliconPlugins)
-            .add_plugins(RepliconRenetPlugins)
-            .add_systems(Startup, setup)
-            .add_systems(OnEnter(ClientState::Connecting), display_connection_message)
-            .add_systems(OnExit(ClientState::Connected), show_disconnected_message)
-            .replicate::<Transform>()
-            // Replicate velocity component to stabilize character movement across the network.
-            .replicate::<Velocity>()
-            .replicate::<controller::CharacterMovementState>()
-            .replicate::<Player>()
-            .replicate::<Doodad>()
-            .add_observer(init_player_mesh)
-            .add_observer(init_doodad_mesh);
+        // Doodad assets are built from the manifest right away.
+        if !app.world().contains_resource::<AssetManifest>() {
+            let assets = app
+                .get_added_plugins::<AssetPlugin>()
+                .first()
+                .map_or(AssetPlugin::default().file_path, |plugin| {
+                    plugin.file_path.clone()
+                });
+            app.insert_resource(AssetManifest::load_or_default(&assets));
+        }
+        app.init_resource::<Cli>().init_resource::<ConfigFile>();
+        network::init_tick_rate(app);
+        app.insert_resource(WorldConfig {
+            demo_scene: self.demo_scene,
+            spawner: self.spawner,
+        })
+        .add_plugins(RepliconPlugins)
+        .add_plugins(RepliconRenetPlugins)
+        .add_plugins(config::ConfigReloadPlugin)
+        .add_plugins(discovery::DiscoveryPlugin)
+        .add_plugins(replication::ReplicationPlugin {
+            transform_thresholds: self.transform_thresholds,
+        })
+        .add_plugins(spawn::SpawnPlugin)
+        .add_plugins(edit::EditPlugin)
+        .add_plugins(chat::ChatPlugin)
+        .add_plugins(admin::AdminPlugin)
+        .add_plugins(players::PlayersPlugin)
+        .add_plugins(scoreboard::ScoreboardPlugin)
+        .add_plugins(damage::DamagePlugin)
+        .add_plugins(death::DeathPlugin)
+        .add_plugins(game_mode::GameModePlugin)
+        .add_plugins(kill_plane::KillPlanePlugin)
+        .add_plugins(emote::EmotePlugin)
+        .add_plugins(attack::AttackPlugin)
+        .add_plugins(shooting::ShootingPlugin)
+        .add_plugins(carry::CarryPlugin)
+        .add_plugins(camera_path::CameraPathPlugin)
+        .add_plugins(colors::ColorSchemePlugin)
+        .add_plugins(time_of_day::TimeOfDayPlugin)
+        .add_plugins(weather::WeatherPlugin)
+        .add_plugins(traffic::TrafficPlugin)
+        .add_plugins(persistence::PersistencePlugin)
+        .add_plugins(navigation::NavigationPlugin)
+        .add_plugins(move_to::MoveToPlugin)
+        .add_plugins(trigger_zone::TriggerZonePlugin)
+        .add_plugins(pickup::PickupPlugin)
+        .add_plugins(platform::PlatformPlugin)
+        .add_plugins(prediction::PredictionPlugin)
+        .add_plugins(terrain::TerrainPlugin)
+        .add_plugins(scatter::ScatterPlugin)
+        .add_plugins(bench::BenchPlugin)
+        .add_plugins(replay::ReplayPlugin)
+        .add_plugins(sleeping::SleepingPlugin)
+        .init_resource::<DoodadColliders>()
+        .add_systems(Startup, (setup, network::init_send_rate))
+        .add_systems(Update, network::check_password.run_if(has_server_authority))
+        // Skipping ticks skips replication along with the server messages, which replicon
+        // buffers until the next one.
+        .configure_sets(
+            FixedPostUpdate,
+            ServerSystems::IncrementTick.run_if(network::replication_due),
+        )
+        .add_systems(
+            FixedPostUpdate,
+            network::tick_send_rate
+                .run_if(resource_exists::<network::SendRate>)
+                .before(ServerSystems::IncrementTick),
+        )
+        .add_systems(OnEnter(ClientState::Connecting), display_connection_message)
+        .add_systems(OnExit(ClientState::Connected), show_disconnected_message)
+        // Replicate velocity component to stabilize character movement across the network.
+        .replicate_tracked_filtered_as::<Velocity, QuantizedVelocity, With<ReplicateVelocity>>()
+        .replicate_tracked::<controller::CharacterMovementState>()
+        .replicate_tracked::<controller::CharacterDimensions>()
+        .replicate_tracked::<Player>()
+        .replicate_tracked::<CharacterModel>()
+        .replicate_tracked::<Doodad>()
+        .replicate_tracked::<Carried>()
+        .replicate_tracked::<Lamp>()
+        .replicate_tracked::<SpawnPoint>()
+        .replicate_tracked::<StaticGeometry>()
+        .replicate_tracked::<PlatformPath>()
+        .replicate_tracked::<TriggerZone>()
+        .replicate_tracked::<PickupSpawner>()
+        .replicate_tracked::<PickupIncoming>()
+        .replicate_tracked::<Pickup>()
+        .replicate_tracked::<Level>()
+        .replicate_tracked::<TimeOfDay>()
+        .replicate_tracked::<Weather>()
+        .replicate_tracked::<Gravity>()
+        .replicate_tracked::<Health>()
+        .replicate_tracked::<Dead>()
+        .replicate_tracked::<Match>()
+        .replicate_tracked::<It>()
+        .add_observer(init_player)
+        .add_observer(init_doodad)
+        .add_observer(init_static_geometry);
+        #[cfg(feature = "client-visuals")]
+        app.add_plugins(visuals::VisualsPlugin);
+        #[cfg(feature = "scripting")]
+        app.add_plugins(scripting::ScriptingPlugin);
     }
 }
 
-fn setup(mut commands: Commands, cli: Res<Cli>, channels: Res<RepliconChannels>) -> Result<()> {
-    if network::init(&mut commands, &cli, &channels)? == NetworkMode::Server {
-        spawn_server_entities(&mut commands);
+/// The `DefaultPlugins` of a [`headless_app`], without a window nor a renderer, for apps
+/// adding the simulation themselves. Built without the `client-visuals` feature, bevy has
+/// neither of them to begin with.
+pub fn headless_plugins() -> PluginGroupBuilder {
+    let plugins = DefaultPlugins.build();
+    #[cfg(feature = "client-visuals")]
+    let plugins = plugins
+        .set(WindowPlugin {
+            primary_window: None,
+            exit_condition: bevy::window::ExitCondition::DontExit,
+            ..default()
+        })
+        .set(RenderPlugin {
+            render_creation: WgpuSettings {
+                backends: None,
+                ..default()
+            }
+            .into(),
+            ..default()
+        })
+        .disable::<WinitPlugin>();
+    plugins
+}
+
+/// An app running only the simulation, without a window nor a renderer, for benchmarks
+/// and replays. It comes without a runner, which is up to the caller.
+pub fn headless_app(cli: Cli, physics: PhysicsArgs) -> App {
+    headless_app_with(
+        ConfigFile::default(),
+        cli,
+        physics,
+        SimulationPlugin::default(),
+    )
+}
+
+/// A [`headless_app`] with its own configuration and [`SimulationPlugin`], such as a
+/// headless server, or one spawning the world of a test.
+pub fn headless_app_with(
+    config: ConfigFile,
+    cli: Cli,
+    physics: PhysicsArgs,
+    simulation: SimulationPlugin,
+) -> App {
+    let plugins = config.configure(headless_plugins());
+    let mut app = App::new();
+    app.insert_resource(config)
+        .add_plugins(plugins)
+        .insert_resource(cli)
+        .add_plugins(simulation)
+        .add_plugins(physics::PhysicsPlugin::new(physics))
+        .add_plugins(controller::CharacterControllerPlugin)
+        .add_systems(
+            Update,
+            exit_on_fatal_error.run_if(resource_exists::<FatalError>),
+        );
+    app
+}
+
+/// A [`headless_app_with`] whose frames last exactly the fixed timestep of deterministic
+/// physics, stepping them once per frame, with its plugins finished so that it is ready to
+/// update. Replays, tests, and benchmarks run on it.
+pub fn stepped_app(cli: Cli, physics: PhysicsArgs, simulation: SimulationPlugin) -> App {
+    let mut app = headless_app_with(
+        ConfigFile::default(),
+        cli,
+        physics.deterministic(),
+        simulation,
+    );
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1.0 / physics::DETERMINISTIC_RATE,
+    )));
+    while app.plugins_state() == PluginsState::Adding {
+        bevy::tasks::tick_global_task_pools_on_main_thread();
+    }
+    app.finish();
+    app.cleanup();
+    app
+}
+
+fn setup(
+    mut commands: Commands,
+    cli: Res<Cli>,
+    config: Res<WorldConfig>,
+    config_file: Res<ConfigFile>,
+    channels: Res<RepliconChannels>,
+    mut join_writer: MessageWriter<PlayerJoin>,
+) {
+    let started = start(
+        &mut commands,
+        &cli,
+        &config,
+        &config_file,
+        &channels,
+        &mut join_writer,
+    );
+    if let Err(error) = started {
+        error!("{}", error.message);
+        commands.insert_resource(error);
+    }
+}
+
+/// Starts the network and spawns the world, failing when either cannot start.
+fn start(
+    commands: &mut Commands,
+    cli: &Cli,
+    config: &WorldConfig,
+    config_file: &ConfigFile,
+    channels: &RepliconChannels,
+    join_writer: &mut MessageWriter<PlayerJoin>,
+) -> Result<(), FatalError> {
+    let mode = network::init(commands, cli, &config_file.network, channels).map_err(|err| {
+        FatalError::new(
+            FatalError::NETWORK,
+            format!("Cannot start the network: {err}"),
+        )
+    })?;
+    if !cfg!(feature = "scripting") && !cli.scripts().is_empty() {
+        warn!("Ignoring the scripts, as built without the `scripting` feature");
+    }
+    let world_error = |err: BevyError| {
+        FatalError::new(FatalError::WORLD, format!("Cannot load the world: {err}"))
+    };
+    // Clients receive the level from the server.
+    if let Some(path) = cli.load() {
+        WorldSave::load(path).map_err(world_error)?.spawn(commands);
+    } else if mode != NetworkMode::Client {
+        if config.demo_scene {
+            let level = LevelFile::load(cli.level()).map_err(world_error)?;
+            level.spawn(commands);
+            if mode == NetworkMode::Server {
+                spawn_server_entities(commands, cli, &level);
+            }
+        }
+        if let Some(spawner) = config.spawner {
+            spawner(commands, cli);
+        }
+    }
+    if mode == NetworkMode::Server {
+        commands.spawn((Replicated, Match::new(cli.game_mode())));
+    }
+    // Remote clients join once connected, local players join right away.
+    if mode != NetworkMode::Client {
+        join_writer.write(PlayerJoin::new(cli.name()));
     }
     Ok(())
 }
 
-fn spawn_server_entities(commands: &mut Commands) {
-    commands.spawn((
-        Replicated,
-        Transform::from_xyz(0.0, 1.5, 2.0),
-        Player::default(),
-    ));
+/// An error which leaves the app unable to play, such as the server failing to bind its
+/// port, rather than running half set up. Apps with a UI show it, the others exit with its
+/// code through [`exit_on_fatal_error`].
+#[derive(Resource, Debug, Clone)]
+pub struct FatalError {
+    pub message: String,
+    /// Code to exit with, telling apart what failed.
+    pub code: u8,
+}
+
+impl FatalError {
+    /// Exit code of the network failing to start, such as when its port is taken.
+    pub const NETWORK: u8 = 2;
+    /// Exit code of the level or the saved world failing to load.
+    pub const WORLD: u8 = 3;
+
+    pub fn new(code: u8, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into().trim_end().to_owned(),
+            code,
+        }
+    }
+}
+
+/// Exits with the code of the [`FatalError`], for apps without a UI to show it in.
+pub fn exit_on_fatal_error(error: Res<FatalError>, mut exit_writer: MessageWriter<AppExit>) {
+    exit_writer.write(AppExit::from_code(error.code));
+}
+
+/// Spawns the characters of the players at the spawn points of the level, or at its
+/// origin when it has none.
+fn spawn_server_entities(commands: &mut Commands, cli: &Cli, level: &LevelFile) {
     commands.spawn((
         Replicated,
-        Transform::from_xyz(0.0, 1.5, 0.0),
-        Player::default(),
+        level.level(),
+        TimeOfDay::new(cli.day_length()),
+        Weather::new(level.weather),
     ));
-    commands.spawn((Replicated, Transform::from_xyz(0.0, 1.0, 0.0), Doodad));
-    commands.spawn((Replicated, Transform::from_xyz(1.0, 0.5, 0.0), Doodad));
+    let spawn_points: Vec<_> = level.spawn_points().collect();
+    for index in 0..PLAYER_COUNT {
+        let spawn_point = spawn_points
+            .get(index % spawn_points.len().max(1))
+            .copied()
+            .unwrap_or_default();
+        commands.spawn((
+            Replicated,
+            Transform::from_translation(spawn_point + Vec3::Y * death::SPAWN_HEIGHT),
+            Player::default(),
+            Health::new(PLAYER_HEALTH),
+        ));
+    }
 }
 
 fn display_connection_message() {
@@ -64,32 +455,95 @@ fn show_disconnected_message() {
     info!("Disconnected from server");
 }
 
-const CHARACTER_PATH: &str = "character-large-male.glb";
+fn init_player(
+    add: On<Add, Player>,
+    mut commands: Commands,
+    dimensions: Query<&controller::CharacterDimensions>,
+) {
+    let dimensions = dimensions.get(add.entity).copied().unwrap_or_default();
+    commands.entity(add.entity).insert(
+        controller::CharacterPhysicsBundle::new(dimensions, 2.0).with_movement(
+            60.0,
+            8.0,
+            30.0_f32.to_radians(),
+        ),
+    );
+}
+
+/// Colliders shared by every doodad of a kind.
+#[derive(Resource)]
+struct DoodadColliders(HashMap<Doodad, Collider>);
 
-fn init_player_mesh(add: On<Add, Player>, mut commands: Commands, asset_server: Res<AssetServer>) {
-    let scene: Handle<Scene> = asset_server.load(format!("{}#Scene0", CHARACTER_PATH));
-    commands
-        .entity(add.entity)
-        .insert(
-            controller::CharacterPhysicsBundle::new(Collider::capsule_y(1.0, 0.5), 2.0)
-                .with_movement(60.0, 8.0, 30.0_f32.to_radians()),
+impl FromWorld for DoodadColliders {
+    fn from_world(world: &mut World) -> Self {
+        let manifest = world
+            .get_resource::<AssetManifest>()
+            .cloned()
+            .unwrap_or_default();
+        Self(
+            [Doodad::Cube, Doodad::Sphere]
+                .into_iter()
+                .map(|doodad| (doodad, manifest.doodad(doodad).collider()))
+                .collect(),
         )
-        .with_children(|commands| {
-            commands.spawn((SceneRoot(scene), Transform::from_xyz(0.0, -1.5, 0.0)));
-        });
+    }
 }
 
-fn init_doodad_mesh(
+fn init_doodad(
     add: On<Add, Doodad>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    doodads: Query<&Doodad>,
+    colliders: Res<DoodadColliders>,
 ) {
-    // Cube 1
-    commands.entity(add.entity).insert((
-        RigidBody::Dynamic,
-        Collider::cuboid(0.5, 0.5, 0.5),
-        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
-        MeshMaterial3d(materials.add(Color::srgb_u8(124, 144, 255))),
-    ));
+    let Ok(doodad) = doodads.get(add.entity) else {
+        return;
+    };
+    let Some(collider) = colliders.0.get(doodad) else {
+        return;
+    };
+    commands
+        .entity(add.entity)
+        .insert((RigidBody::Dynamic, collider.clone(), ReplicateVelocity));
+}
+
+fn init_static_geometry(
+    add: On<Add, StaticGeometry>,
+    mut commands: Commands,
+    geometries: Query<&StaticGeometry>,
+    platforms: Query<(), With<PlatformPath>>,
+    asset_server: Res<AssetServer>,
+) {
+    let Ok(geometry) = geometries.get(add.entity) else {
+        return;
+    };
+    let mut entity = commands.entity(add.entity);
+    // Platforms are moved by their velocity, pushing what they touch.
+    if platforms.contains(add.entity) {
+        entity.insert(RigidBody::KinematicVelocityBased);
+    } else {
+        entity.insert(RigidBody::Fixed);
+    }
+    let collider = match geometry {
+        StaticGeometry::Cylinder { radius, height } => Collider::cylinder(height / 2.0, *radius),
+        StaticGeometry::Cuboid { size } => {
+            Collider::cuboid(size.x / 2.0, size.y / 2.0, size.z / 2.0)
+        }
+        // The scene is spawned on servers too, as its colliders are built from its meshes.
+        #[cfg(feature = "scene-colliders")]
+        StaticGeometry::Scene(path) => {
+            let scene = asset_server.load(GltfAssetLabel::Scene(0).from_asset(path.clone()));
+            entity.insert((SceneRoot(scene), AsyncSceneCollider::default()));
+            return;
+        }
+        #[cfg(not(feature = "scene-colliders"))]
+        StaticGeometry::Scene(path) => {
+            warn!("No collider for {path}, as built without the `scene-colliders` feature");
+            return;
+        }
+        StaticGeometry::Heightfield { heightmap, .. } => {
+            entity.insert(terrain::load_heightmap(&asset_server, heightmap));
+            return;
+        }
+    };
+    entity.insert(collider);
 }
@@ -0,0 +1,117 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::path::{Path, PathBuf};
+
+use bevy::{asset::io::file::FileAssetReader, platform::collections::HashMap, prelude::*};
+use bevy_rapier3d::prelude::Collider;
+use merlo_model::{CharacterModel, Doodad};
+use serde::Deserialize;
+
+/// File of the manifest under the assets.
+const MANIFEST_FILE: &str = "manifest.ron";
+
+/// Shape of a doodad, giving both its mesh and its collider.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum DoodadShape {
+    Cuboid { size: Vec3 },
+    Sphere { radius: f32 },
+}
+
+impl DoodadShape {
+    fn default_of(doodad: Doodad) -> Self {
+        match doodad {
+            Doodad::Cube => DoodadShape::Cuboid { size: Vec3::ONE },
+            Doodad::Sphere => DoodadShape::Sphere { radius: 0.5 },
+        }
+    }
+
+    pub fn mesh(self) -> Mesh {
+        match self {
+            DoodadShape::Cuboid { size } => Cuboid::from_size(size).into(),
+            DoodadShape::Sphere { radius } => Sphere::new(radius).into(),
+        }
+    }
+
+    pub fn collider(self) -> Collider {
+        match self {
+            DoodadShape::Cuboid { size } => {
+                Collider::cuboid(size.x / 2.0, size.y / 2.0, size.z / 2.0)
+            }
+            DoodadShape::Sphere { radius } => Collider::ball(radius),
+        }
+    }
+}
+
+/// Assets the simulation and the presentation build entities from, read from
+/// `assets/manifest.ron` at startup. Missing entries keep their default.
+///
+/// Games may insert their own manifest before adding the [`SimulationPlugin`], which then
+/// leaves it as it is.
+///
+/// [`SimulationPlugin`]: crate::SimulationPlugin
+#[derive(Resource, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct AssetManifest {
+    /// Model of the characters not given one, whose prefix also names their animation clips.
+    pub character: CharacterModel,
+    pub doodads: HashMap<Doodad, DoodadShape>,
+}
+
+impl Default for AssetManifest {
+    fn default() -> Self {
+        Self {
+            character: CharacterModel::default(),
+            doodads: [Doodad::Cube, Doodad::Sphere]
+                .into_iter()
+                .map(|doodad| (doodad, DoodadShape::default_of(doodad)))
+                .collect(),
+        }
+    }
+}
+
+impl AssetManifest {
+    /// Where the manifest is looked for, next to the other assets in the `assets` folder.
+    pub fn path(assets: &str) -> PathBuf {
+        FileAssetReader::get_base_path()
+            .join(assets)
+            .join(MANIFEST_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(ron::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Reads the manifest under the `assets` folder, or falls back to the default one when
+    /// there is none or it cannot be read.
+    pub fn load_or_default(assets: &str) -> Self {
+        let path = Self::path(assets);
+        if !path.exists() {
+            return Self::default();
+        }
+        match Self::load(&path) {
+            Ok(manifest) => {
+                info!("Loaded the asset manifest {}", path.display());
+                manifest
+            }
+            Err(err) => {
+                error!("Cannot load the asset manifest {}: {err}", path.display());
+                Self::default()
+            }
+        }
+    }
+
+    /// Model of a character, or the default one when it has none.
+    pub fn character_model(&self, model: Option<&CharacterModel>) -> CharacterModel {
+        model.unwrap_or(&self.character).clone()
+    }
+
+    /// Shape of a kind of doodad, falling back to the default one when not listed.
+    pub fn doodad(&self, doodad: Doodad) -> DoodadShape {
+        self.doodads
+            .get(&doodad)
+            .copied()
+            .unwrap_or_else(|| DoodadShape::default_of(doodad))
+    }
+}
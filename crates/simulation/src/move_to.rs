@@ -0,0 +1,119 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::collections::VecDeque;
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_replicon::prelude::*;
+use merlo_model::Dead;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin::ControlledCharacter,
+    controller::{MovementAction, has_server_authority},
+    navigation::NavMesh,
+};
+
+pub struct MoveToPlugin;
+
+impl Plugin for MoveToPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_client_message::<MoveTo>(Channel::Ordered)
+            .add_systems(
+                Update,
+                (start_moving, follow_paths)
+                    .chain()
+                    .run_if(has_server_authority),
+            );
+    }
+}
+
+/// Distance from a waypoint on the ground where it counts as reached.
+const ARRIVAL_DISTANCE: f32 = 0.3;
+/// Turn rate toward the next waypoint, per radian of the angle left to turn.
+const STEERING: f32 = 2.0;
+
+/// A [`Message`] written by a client to walk its character to a point on the ground.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct MoveTo {
+    #[entities]
+    pub character: Entity,
+    pub target: Vec3,
+}
+
+/// Waypoints a character is walking along on the server, on the ground.
+#[derive(Component, Debug)]
+struct MovePath(VecDeque<Vec3>);
+
+/// Finds a path for the characters of clients to the point they clicked, replacing
+/// any path they were following.
+fn start_moving(
+    mut commands: Commands,
+    mut move_reader: MessageReader<FromClient<MoveTo>>,
+    nav_mesh: Res<NavMesh>,
+    clients: Query<&ControlledCharacter>,
+    characters: Query<&Transform, Without<Dead>>,
+) {
+    for event in move_reader.read() {
+        let MoveTo { character, target } = event.message;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(character))
+        {
+            warn!(
+                "Ignoring move of {:?} for character {character} it does not control",
+                event.client_id
+            );
+            continue;
+        }
+        let Ok(transform) = characters.get(character) else {
+            continue;
+        };
+        let path = nav_mesh.find_path(transform.translation, target);
+        if path.is_empty() {
+            debug!("No path for {character} to {target}");
+            continue;
+        }
+        commands.entity(character).insert(MovePath(path.into()));
+    }
+}
+
+/// Steers characters along their path with the same [`MovementAction`]s as the input of
+/// players, turning toward the next waypoint while moving toward it.
+fn follow_paths(
+    mut commands: Commands,
+    mut movement_writer: MessageWriter<FromClient<MovementAction>>,
+    mut characters: Query<(Entity, &Transform, &mut MovePath, Has<Dead>)>,
+) {
+    let mut send = |message| {
+        movement_writer.write(FromClient {
+            client_id: ClientId::Server,
+            message,
+        });
+    };
+    for (character, transform, mut path, dead) in &mut characters {
+        // Feet are well below the origin of a character, but only the horizontal
+        // distance matters on slopes and steps.
+        while let Some(&waypoint) = path.0.front()
+            && waypoint.xz().distance(transform.translation.xz()) < ARRIVAL_DISTANCE
+        {
+            path.0.pop_front();
+        }
+        let Some(&waypoint) = path.0.front().filter(|_| !dead) else {
+            send(MovementAction::SetMove(character, Vec3::ZERO));
+            send(MovementAction::SetRotate(character, 0.0));
+            commands.entity(character).remove::<MovePath>();
+            continue;
+        };
+
+        let to_waypoint = (waypoint - transform.translation).with_y(0.0);
+        let local = (transform.rotation.inverse() * to_waypoint).normalize_or_zero();
+        // Forward is +Z and turning left is positive, toward +X.
+        let angle = local.x.atan2(local.z);
+        send(MovementAction::SetMove(character, local));
+        send(MovementAction::SetRotate(
+            character,
+            (angle * STEERING).clamp(-1.0, 1.0),
+        ));
+    }
+}
@@ -0,0 +1,321 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use merlo_model::StaticGeometry;
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavMesh>()
+            .add_systems(Update, (invalidate_nav_mesh, bake_nav_mesh).chain());
+    }
+}
+
+/// Side of the square cells of the navigation grid in meters.
+const CELL_SIZE: f32 = 0.5;
+/// Cells along each side of the grid at most, larger levels are clipped around the origin.
+const MAX_CELLS: usize = 512;
+/// Height a character can step up or down between neighboring cells.
+const MAX_STEP: f32 = 0.5;
+/// Steepest walkable slope, as the minimum vertical component of the ground normal.
+const MIN_GROUND_NORMAL_Y: f32 = 0.7;
+/// Shape of a standing character, whose origin is `AGENT_HEIGHT` above its soles,
+/// slightly shrunk so that it does not touch the ground it stands on.
+const AGENT_HEIGHT: f32 = 1.5;
+const AGENT_HALF_HEIGHT: f32 = 0.9;
+const AGENT_RADIUS: f32 = 0.4;
+
+/// Walkable ground of the level, baked from the static colliders by probing the ground
+/// on a grid of cells, where a standing character fits without touching any wall.
+#[derive(Resource, Default)]
+pub struct NavMesh {
+    /// Corner of the grid with the lowest coordinates, on the XZ plane.
+    origin: Vec2,
+    width: usize,
+    depth: usize,
+    /// Ground height of every cell, row by row along X, or `None` where not walkable.
+    heights: Vec<Option<f32>>,
+    /// Whether the static colliders changed since the last bake.
+    dirty: bool,
+}
+
+impl NavMesh {
+    /// Whether the grid was baked with some walkable ground.
+    pub fn is_baked(&self) -> bool {
+        self.heights.iter().any(Option::is_some)
+    }
+
+    /// Points to walk along from `from` to `to`, on the ground and ending at `to`,
+    /// or empty when no walkable path connects them.
+    pub fn find_path(&self, from: Vec3, to: Vec3) -> Vec<Vec3> {
+        let (Some(start), Some(goal)) = (self.nearest_walkable(from), self.nearest_walkable(to))
+        else {
+            return Vec::new();
+        };
+        let Some(cells) = self.search(start, goal) else {
+            return Vec::new();
+        };
+
+        let mut points: Vec<_> = cells.into_iter().map(|cell| self.point(cell)).collect();
+        if points.is_empty() {
+            points.push(self.point(goal));
+        }
+        if let Some(last) = points.last_mut() {
+            *last = to.with_y(last.y);
+        }
+        // Skip the points which can be walked past in a straight line.
+        let mut path = Vec::new();
+        let mut current = from.with_y(self.point(start).y);
+        let mut next = 0;
+        while next < points.len() {
+            let farthest = (next..points.len())
+                .rev()
+                .find(|&index| self.is_straight(current, points[index]))
+                .unwrap_or(next);
+            current = points[farthest];
+            path.push(current);
+            next = farthest + 1;
+        }
+        path
+    }
+
+    fn cell(&self, position: Vec3) -> Option<usize> {
+        let local = (position.xz() - self.origin) / CELL_SIZE;
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+        let (x, z) = (local.x as usize, local.y as usize);
+        (x < self.width && z < self.depth).then_some(z * self.width + x)
+    }
+
+    /// Center of a cell, on its ground.
+    fn point(&self, cell: usize) -> Vec3 {
+        let (x, z) = (cell % self.width, cell / self.width);
+        let center = self.origin + (Vec2::new(x as f32, z as f32) + 0.5) * CELL_SIZE;
+        Vec3::new(center.x, self.heights[cell].unwrap_or_default(), center.y)
+    }
+
+    /// The walkable cell closest to `position`, looking a few cells around it.
+    fn nearest_walkable(&self, position: Vec3) -> Option<usize> {
+        const SEARCH_RADIUS: f32 = 2.0;
+        let cells = (SEARCH_RADIUS / CELL_SIZE) as i32;
+        (-cells..=cells)
+            .flat_map(|dz| (-cells..=cells).map(move |dx| (dx, dz)))
+            .filter_map(|(dx, dz)| {
+                let offset = Vec3::new(dx as f32, 0.0, dz as f32) * CELL_SIZE;
+                self.cell(position + offset)
+            })
+            .filter(|&cell| self.heights[cell].is_some())
+            .min_by(|&a, &b| {
+                let distance = |cell| self.point(cell).xz().distance_squared(position.xz());
+                distance(a).total_cmp(&distance(b))
+            })
+    }
+
+    /// Walkable cells next to `cell` and the distance to each, cutting corners only
+    /// when both cells beside the diagonal are walkable too.
+    fn neighbors(&self, cell: usize) -> impl Iterator<Item = (usize, f32)> + '_ {
+        let (x, z) = ((cell % self.width) as isize, (cell / self.width) as isize);
+        let height = self.heights[cell].unwrap_or_default();
+        let index = move |dx: isize, dz: isize| {
+            let (x, z) = (x + dx, z + dz);
+            let inside = x >= 0 && z >= 0 && (x as usize) < self.width && (z as usize) < self.depth;
+            inside.then(|| z as usize * self.width + x as usize)
+        };
+        let reachable = move |neighbor: Option<usize>| {
+            neighbor.filter(|&neighbor| {
+                self.heights[neighbor].is_some_and(|other| (other - height).abs() <= MAX_STEP)
+            })
+        };
+        [
+            (-1, 0),
+            (1, 0),
+            (0, -1),
+            (0, 1),
+            (-1, -1),
+            (1, -1),
+            (-1, 1),
+            (1, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dz)| {
+            let neighbor = reachable(index(dx, dz))?;
+            let diagonal = dx != 0 && dz != 0;
+            if diagonal && (reachable(index(dx, 0)).is_none() || reachable(index(0, dz)).is_none())
+            {
+                return None;
+            }
+            let distance = if diagonal {
+                std::f32::consts::SQRT_2
+            } else {
+                1.0
+            };
+            Some((neighbor, distance * CELL_SIZE))
+        })
+    }
+
+    /// A* from `start` to `goal`, returning the cells from the one after `start` to `goal`.
+    fn search(&self, start: usize, goal: usize) -> Option<Vec<usize>> {
+        let heuristic = |cell: usize| self.point(cell).xz().distance(self.point(goal).xz());
+        let mut costs = vec![f32::INFINITY; self.heights.len()];
+        let mut previous = vec![usize::MAX; self.heights.len()];
+        // Costs are never negative, so their bits sort like the costs themselves.
+        let mut open = BinaryHeap::from([Reverse((heuristic(start).to_bits(), start))]);
+        costs[start] = 0.0;
+
+        while let Some(Reverse((_, cell))) = open.pop() {
+            if cell == goal {
+                let mut cells = Vec::new();
+                let mut cell = goal;
+                while cell != start {
+                    cells.push(cell);
+                    cell = previous[cell];
+                }
+                cells.reverse();
+                return Some(cells);
+            }
+            for (neighbor, distance) in self.neighbors(cell) {
+                let cost = costs[cell] + distance;
+                if cost < costs[neighbor] {
+                    costs[neighbor] = cost;
+                    previous[neighbor] = cell;
+                    let estimate = cost + heuristic(neighbor);
+                    open.push(Reverse((estimate.to_bits(), neighbor)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Whether walking straight from `from` to `to` stays on walkable ground.
+    fn is_straight(&self, from: Vec3, to: Vec3) -> bool {
+        let steps = (from.xz().distance(to.xz()) / (CELL_SIZE * 0.5)).ceil() as usize;
+        let mut height = from.y;
+        (1..=steps.max(1)).all(|step| {
+            let point = from.lerp(to, step as f32 / steps.max(1) as f32);
+            let Some(ground) = self.cell(point).and_then(|cell| self.heights[cell]) else {
+                return false;
+            };
+            let walkable = (ground - height).abs() <= MAX_STEP;
+            height = ground;
+            walkable
+        })
+    }
+}
+
+/// Whether an entity belongs to a fixed body, such as the colliders of a scene.
+fn is_static(entity: Entity, bodies: &Query<&RigidBody>, parents: &Query<&ChildOf>) -> bool {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find_map(|entity| bodies.get(entity).ok())
+        == Some(&RigidBody::Fixed)
+}
+
+/// Bakes again whenever static colliders are added to the physics world, or level
+/// geometry is removed.
+fn invalidate_nav_mesh(
+    mut nav_mesh: ResMut<NavMesh>,
+    colliders: Query<Entity, Added<RapierColliderHandle>>,
+    mut removed: RemovedComponents<StaticGeometry>,
+    bodies: Query<&RigidBody>,
+    parents: Query<&ChildOf>,
+) {
+    if removed.read().count() > 0
+        || colliders
+            .iter()
+            .any(|entity| is_static(entity, &bodies, &parents))
+    {
+        nav_mesh.dirty = true;
+    }
+}
+
+/// Probes the ground of every cell within the bounds of the static colliders.
+fn bake_nav_mesh(mut nav_mesh: ResMut<NavMesh>, rapier_context: ReadRapierContext) {
+    if !nav_mesh.dirty {
+        return;
+    }
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    nav_mesh.dirty = false;
+
+    let bodies = &rapier_context.rigidbody_set.bodies;
+    let bounds = rapier_context
+        .colliders
+        .colliders
+        .iter()
+        .filter(|(_, collider)| {
+            !collider.is_sensor()
+                && collider
+                    .parent()
+                    .and_then(|parent| bodies.get(parent))
+                    .is_none_or(|body| body.is_fixed())
+        })
+        .map(|(_, collider)| {
+            let aabb = collider.compute_aabb();
+            (
+                Vec3::new(aabb.mins.x, aabb.mins.y, aabb.mins.z),
+                Vec3::new(aabb.maxs.x, aabb.maxs.y, aabb.maxs.z),
+            )
+        })
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)));
+    let Some((min, max)) = bounds else {
+        *nav_mesh = NavMesh::default();
+        return;
+    };
+
+    let half_size = MAX_CELLS as f32 * CELL_SIZE / 2.0;
+    let min_xz = min.xz().max(Vec2::splat(-half_size));
+    let max_xz = max.xz().min(Vec2::splat(half_size));
+    let cells = ((max_xz - min_xz) / CELL_SIZE).ceil().max(Vec2::ZERO);
+    let (width, depth) = (cells.x as usize, cells.y as usize);
+
+    let filter = QueryFilter::only_fixed().exclude_sensors();
+    let agent = Collider::capsule_y(AGENT_HALF_HEIGHT, AGENT_RADIUS);
+    let top = max.y + 1.0;
+    let mut heights = Vec::with_capacity(width * depth);
+    for z in 0..depth {
+        for x in 0..width {
+            let center = min_xz + (Vec2::new(x as f32, z as f32) + 0.5) * CELL_SIZE;
+            let origin = Vec3::new(center.x, top, center.y);
+            let ground = rapier_context
+                .cast_ray_and_get_normal(origin, Vec3::NEG_Y, top - min.y, true, filter)
+                .filter(|(_, hit)| hit.normal.y >= MIN_GROUND_NORMAL_Y)
+                .map(|(_, hit)| hit.point.y);
+            let height = ground.filter(|&ground| {
+                let position = Vec3::new(center.x, ground + AGENT_HEIGHT, center.y);
+                let mut blocked = false;
+                rapier_context.intersect_shape(
+                    position,
+                    Quat::IDENTITY,
+                    &*agent.raw,
+                    filter,
+                    |_| {
+                        blocked = true;
+                        false
+                    },
+                );
+                !blocked
+            });
+            heights.push(height);
+        }
+    }
+
+    *nav_mesh = NavMesh {
+        origin: min_xz,
+        width,
+        depth,
+        heights,
+        dirty: false,
+    };
+    debug!(
+        "Baked a navigation grid of {width}x{depth} cells, {} walkable",
+        nav_mesh.heights.iter().flatten().count()
+    );
+}
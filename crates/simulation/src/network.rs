@@ -4,23 +4,39 @@
 
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket},
+    path::{Path, PathBuf},
     time::SystemTime,
 };
 
 use bevy::prelude::*;
-use bevy_replicon::prelude::RepliconChannels;
+use bevy_replicon::{
+    prelude::{ConnectedClient, DisconnectRequest, RepliconChannels},
+    shared::backend::connected_client::NetworkId,
+};
 use bevy_replicon_renet::{
     RenetChannelsExt,
     netcode::{
-        ClientAuthentication, NetcodeClientTransport, NetcodeServerTransport, ServerAuthentication,
-        ServerConfig,
+        ClientAuthentication, NETCODE_USER_DATA_BYTES, NetcodeClientTransport,
+        NetcodeServerTransport, ServerAuthentication, ServerConfig,
     },
     renet::{ConnectionConfig, RenetClient, RenetServer},
 };
 use clap::Parser;
+use merlo_model::GameModeKind;
+
+use crate::{
+    config::{ConfigFile, NetworkConfig},
+    discovery::{self, DISCOVERY_TIMEOUT},
+    game_mode,
+};
 
-const DEFAULT_PORT: u16 = 5000;
+/// Port servers listen on and clients connect to, unless given with `--port`.
+pub const DEFAULT_PORT: u16 = 5000;
 const PROTOCOL_ID: u64 = 0;
+const DEFAULT_DAY_LENGTH: f32 = 600.0;
+const DEFAULT_NAME: &str = "Player";
+const DEFAULT_BENCH_CLIENTS: usize = 8;
+pub(crate) const DEFAULT_AUTOSAVE_KEEP: usize = 3;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NetworkMode {
@@ -33,98 +49,539 @@ pub enum NetworkMode {
 #[derive(Parser, PartialEq, Resource)]
 pub enum Cli {
     /// Play locally.
-    Singleplayer {},
+    Singleplayer {
+        /// Name shown to other players.
+        #[arg(short, long, env = "MERLO_NAME", default_value = DEFAULT_NAME)]
+        name: String,
+    },
     /// Create a server that acts as both player and host.
     Server {
-        #[arg(short, long, default_value_t = DEFAULT_PORT)]
+        /// UDP port to listen on, or 0 to pick a free one, see [`ServerInfo`].
+        #[arg(short, long, env = "MERLO_PORT", default_value_t = DEFAULT_PORT)]
         port: u16,
+
+        /// Ticks per second stepping the physics and replicating the world, instead of the
+        /// one of the configuration.
+        #[arg(long, env = "MERLO_TICK_RATE", value_name = "HZ", value_parser = parse_rate)]
+        tick_rate: Option<f64>,
+
+        /// Replications per second sent to the clients, at most one per tick, trading
+        /// responsiveness for bandwidth. Every tick is sent by default.
+        #[arg(long, env = "MERLO_SEND_RATE", value_name = "HZ", value_parser = parse_rate)]
+        send_rate: Option<f64>,
+
+        /// Length of a full day/night cycle in seconds.
+        #[arg(long, env = "MERLO_DAY_LENGTH", default_value_t = DEFAULT_DAY_LENGTH)]
+        day_length: f32,
+
+        /// RON file of the level to play, instead of the default level.
+        #[arg(long, env = "MERLO_LEVEL")]
+        level: Option<PathBuf>,
+
+        /// World saved by a previous session to restore, instead of starting the level.
+        #[arg(long, env = "MERLO_LOAD", conflicts_with = "level")]
+        load: Option<PathBuf>,
+
+        /// Rules of the match.
+        #[arg(
+            long,
+            env = "MERLO_GAME_MODE",
+            default_value = "sandbox",
+            value_parser = game_mode::parser()
+        )]
+        mode: GameModeKind,
+
+        /// Number of doodads to scatter over the ground of the level.
+        #[arg(
+            long,
+            env = "MERLO_SCATTER",
+            default_value_t = 0,
+            conflicts_with = "load"
+        )]
+        scatter: usize,
+
+        /// Seed of the scattering, placing the same doodads on every run.
+        #[arg(long, env = "MERLO_SEED", default_value_t = 0)]
+        seed: u64,
+
+        /// Run without a window for this many seconds, with fake clients connected in
+        /// memory, then print how the server performed.
+        #[arg(long, env = "MERLO_BENCH", value_name = "SECONDS")]
+        bench: Option<f32>,
+
+        /// Number of fake clients sending scripted inputs during a benchmark.
+        #[arg(
+            long,
+            env = "MERLO_BENCH_CLIENTS",
+            default_value_t = DEFAULT_BENCH_CLIENTS,
+            requires = "bench"
+        )]
+        bench_clients: usize,
+
+        /// Save the world every this many seconds, next to the file it is saved to.
+        #[arg(
+            long,
+            env = "MERLO_AUTOSAVE",
+            value_name = "SECONDS",
+            conflicts_with = "bench"
+        )]
+        autosave: Option<f32>,
+
+        /// Number of autosaves kept, the oldest being replaced by the newest.
+        #[arg(
+            long,
+            env = "MERLO_AUTOSAVE_KEEP",
+            default_value_t = DEFAULT_AUTOSAVE_KEEP,
+            requires = "autosave"
+        )]
+        autosave_keep: usize,
+
+        /// File to record the movement inputs of every player to, when shutting down.
+        #[arg(long, env = "MERLO_RECORD", conflicts_with = "bench")]
+        record: Option<PathBuf>,
+
+        /// Rhai script customizing the server, may be given more than once or separated by
+        /// commas. Needs the `scripting` feature.
+        #[arg(
+            long = "script",
+            env = "MERLO_SCRIPTS",
+            value_name = "FILE",
+            value_delimiter = ','
+        )]
+        scripts: Vec<PathBuf>,
+
+        /// Name shown to other players.
+        #[arg(short, long, env = "MERLO_NAME", default_value = DEFAULT_NAME)]
+        name: String,
+
+        /// Whether clients connect in memory, as set by [`Cli::loopback`].
+        #[arg(skip)]
+        loopback: bool,
+    },
+    /// Replay recorded inputs without a window, and check where the characters end up.
+    Replay {
+        /// File written by a server with `--record`.
+        recording: PathBuf,
+
+        /// Accept where the characters end up as the expected snapshot of the recording.
+        #[arg(long)]
+        bless: bool,
+
+        /// Level of the recording, read from the recording itself.
+        #[arg(skip)]
+        level: Option<PathBuf>,
     },
+    /// Check that the assets referenced by the manifest exist and load, without a window.
+    ValidateAssets,
     /// Connect to a host.
     Client {
-        #[arg(short, long, default_value_t = Ipv4Addr::LOCALHOST.into())]
+        #[arg(short, long, env = "MERLO_IP", default_value_t = Ipv4Addr::LOCALHOST.into())]
         ip: IpAddr,
 
-        #[arg(short, long, default_value_t = DEFAULT_PORT)]
+        #[arg(short, long, env = "MERLO_PORT", default_value_t = DEFAULT_PORT)]
         port: u16,
+
+        /// Connect to the first server found on the local network, instead of the given
+        /// address.
+        #[arg(long, env = "MERLO_DISCOVER", conflicts_with_all = ["ip", "port"])]
+        discover: bool,
+
+        /// Name shown to other players.
+        #[arg(short, long, env = "MERLO_NAME", default_value = DEFAULT_NAME)]
+        name: String,
+
+        /// Whether to connect in memory, as set by [`Cli::loopback`].
+        #[arg(skip)]
+        loopback: bool,
     },
 }
 
+/// Playing locally, for apps which do not parse their command line.
 impl Default for Cli {
     fn default() -> Self {
-        Self::parse()
+        Self::singleplayer()
+    }
+}
+
+impl Cli {
+    /// Playing locally, as `merlo singleplayer` does without options.
+    pub fn singleplayer() -> Self {
+        Cli::Singleplayer {
+            name: DEFAULT_NAME.to_owned(),
+        }
+    }
+
+    /// Hosting a game on `port`, as `merlo server --port PORT` does without other options.
+    pub fn server(port: u16) -> Self {
+        Cli::Server {
+            port,
+            tick_rate: None,
+            send_rate: None,
+            day_length: DEFAULT_DAY_LENGTH,
+            level: None,
+            load: None,
+            mode: GameModeKind::default(),
+            scatter: 0,
+            seed: 0,
+            bench: None,
+            bench_clients: DEFAULT_BENCH_CLIENTS,
+            autosave: None,
+            autosave_keep: DEFAULT_AUTOSAVE_KEEP,
+            record: None,
+            scripts: Vec::new(),
+            name: DEFAULT_NAME.to_owned(),
+            loopback: false,
+        }
+    }
+
+    /// Joining the game hosted at `addr`, as `merlo client --ip IP --port PORT` does.
+    pub fn client(addr: SocketAddr) -> Self {
+        Cli::Client {
+            ip: addr.ip(),
+            port: addr.port(),
+            discover: false,
+            name: DEFAULT_NAME.to_owned(),
+            loopback: false,
+        }
+    }
+
+    /// The same player, shown to others as `name`. Replays keep their own name.
+    pub fn with_name(mut self, new_name: impl Into<String>) -> Self {
+        if let Cli::Singleplayer { name } | Cli::Server { name, .. } | Cli::Client { name, .. } =
+            &mut self
+        {
+            *name = new_name.into();
+        }
+        self
+    }
+
+    /// The same server or client, without sockets, exchanging packets in memory with apps
+    /// of the same process instead, such as in tests.
+    pub fn loopback(mut self) -> Self {
+        if let Cli::Server { loopback, .. } | Cli::Client { loopback, .. } = &mut self {
+            *loopback = true;
+        }
+        self
+    }
+
+    /// Length of a full day/night cycle in seconds.
+    pub fn day_length(&self) -> f32 {
+        match *self {
+            Cli::Server { day_length, .. } => day_length,
+            _ => DEFAULT_DAY_LENGTH,
+        }
+    }
+
+    /// Game mode of the match hosted by the server.
+    pub fn game_mode(&self) -> GameModeKind {
+        match *self {
+            Cli::Server { mode, .. } => mode,
+            _ => GameModeKind::default(),
+        }
+    }
+
+    /// File of the level to play, if not the default one.
+    pub fn level(&self) -> Option<&Path> {
+        match self {
+            Cli::Server { level, .. } | Cli::Replay { level, .. } => level.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// File of the world to restore, if any.
+    pub fn load(&self) -> Option<&Path> {
+        match self {
+            Cli::Server { load, .. } => load.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Number of doodads to scatter and the seed to place them with.
+    pub fn scatter(&self) -> Option<(usize, u64)> {
+        match *self {
+            Cli::Server { scatter, seed, .. } if scatter > 0 => Some((scatter, seed)),
+            _ => None,
+        }
+    }
+
+    /// Seconds to benchmark the server for and the number of fake clients, if benchmarking.
+    pub fn bench(&self) -> Option<(f32, usize)> {
+        match *self {
+            Cli::Server {
+                bench: Some(seconds),
+                bench_clients,
+                ..
+            } => Some((seconds, bench_clients)),
+            _ => None,
+        }
+    }
+
+    /// Ticks per second given on the command line, if any.
+    pub fn tick_rate(&self) -> Option<f64> {
+        match *self {
+            Cli::Server { tick_rate, .. } => tick_rate,
+            _ => None,
+        }
+    }
+
+    /// Replications per second given on the command line, if any.
+    pub fn send_rate(&self) -> Option<f64> {
+        match *self {
+            Cli::Server { send_rate, .. } => send_rate,
+            _ => None,
+        }
+    }
+
+    /// Seconds between autosaves and the number of autosaves kept, if autosaving.
+    pub fn autosave(&self) -> Option<(f32, usize)> {
+        match *self {
+            Cli::Server {
+                autosave: Some(seconds),
+                autosave_keep,
+                ..
+            } => Some((seconds, autosave_keep.max(1))),
+            _ => None,
+        }
+    }
+
+    /// File to record movement inputs to, if any.
+    pub fn record(&self) -> Option<&Path> {
+        match self {
+            Cli::Server { record, .. } => record.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Scripts to run on the server.
+    pub fn scripts(&self) -> &[PathBuf] {
+        match self {
+            Cli::Server { scripts, .. } => scripts,
+            _ => &[],
+        }
+    }
+
+    /// Name of the local player.
+    pub fn name(&self) -> &str {
+        match self {
+            Cli::Singleplayer { name } | Cli::Server { name, .. } | Cli::Client { name, .. } => {
+                name
+            }
+            Cli::Replay { .. } | Cli::ValidateAssets => DEFAULT_NAME,
+        }
     }
 }
 
 pub fn init(
     commands: &mut Commands,
     cli: &Cli,
+    config: &NetworkConfig,
     channels: &RepliconChannels,
 ) -> Result<NetworkMode> {
     match *cli {
-        Cli::Singleplayer {} => Ok(NetworkMode::Singleplayer),
-        Cli::Server { port } => {
-            init_server(commands, channels, port)?;
+        Cli::Singleplayer { .. } | Cli::ValidateAssets => Ok(NetworkMode::Singleplayer),
+        // Fake clients of a benchmark connect in memory, and replays have no clients at all.
+        Cli::Server { bench: Some(_), .. }
+        | Cli::Server { loopback: true, .. }
+        | Cli::Replay { .. } => {
+            commands.insert_resource(RenetServer::new(connection_config(channels)));
+            Ok(NetworkMode::Server)
+        }
+        // The server connects the client on its side, the client is ready right away.
+        Cli::Client { loopback: true, .. } => {
+            let mut client = RenetClient::new(connection_config(channels));
+            client.set_connected();
+            commands.insert_resource(client);
+            Ok(NetworkMode::Client)
+        }
+        Cli::Server { port, .. } => {
+            init_server(commands, channels, config, port)?;
             Ok(NetworkMode::Server)
         }
-        Cli::Client { ip, port } => {
-            init_client(commands, channels, ip, port)?;
+        Cli::Client {
+            ip, port, discover, ..
+        } => {
+            let server_addr = if discover {
+                discover_server()?
+            } else {
+                SocketAddr::new(ip, port)
+            };
+            init_client(commands, channels, config, server_addr)?;
             Ok(NetworkMode::Client)
         }
     }
 }
 
-fn init_server(commands: &mut Commands, channels: &RepliconChannels, port: u16) -> Result<()> {
+/// Ticks per second of the server, given with `--tick-rate` or in the configuration.
+///
+/// Physics and movement then run in [`FixedUpdate`] at this rate, which is also the one of
+/// the loop of headless servers. Deterministic physics replace it with their own rate.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TickRate(pub f64);
+
+/// Sets the tick rate of the server, before the physics and the character controllers are
+/// added, as it picks the schedule they run in. Clients follow the ticks of the server.
+pub(crate) fn init_tick_rate(app: &mut App) {
+    let world = app.world();
+    let cli = world.resource::<Cli>();
+    if matches!(cli, Cli::Client { .. }) {
+        return;
+    }
+    let config = world.resource::<ConfigFile>();
+    let Some(tick_rate) = cli.tick_rate().or(config.network.tick_rate) else {
+        return;
+    };
+    info!("Ticking {tick_rate} times per second");
+    app.insert_resource(TickRate(tick_rate))
+        .insert_resource(Time::<Fixed>::from_hz(tick_rate));
+}
+
+/// A rate in hertz, which has to be positive.
+fn parse_rate(value: &str) -> std::result::Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(rate) if rate.is_finite() && rate > 0.0 => Ok(rate),
+        Ok(_) => Err("must be positive".to_owned()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Paces the replication to the clients, when sent less often than every tick.
+#[derive(Resource)]
+pub(crate) struct SendRate(Timer);
+
+pub(crate) fn init_send_rate(mut commands: Commands, cli: Res<Cli>) {
+    if let Some(send_rate) = cli.send_rate() {
+        info!("Replicating to the clients {send_rate} times per second");
+        commands.insert_resource(SendRate(Timer::from_seconds(
+            (1.0 / send_rate) as f32,
+            TimerMode::Repeating,
+        )));
+    }
+}
+
+/// Advances the send rate by a tick, as [`Time`] is the fixed one in the tick schedule.
+pub(crate) fn tick_send_rate(mut send_rate: ResMut<SendRate>, time: Res<Time>) {
+    send_rate.0.tick(time.delta());
+}
+
+/// Whether to replicate this tick. Changes made in between, and the server messages
+/// buffered by replicon, are sent along the next time.
+pub(crate) fn replication_due(send_rate: Option<Res<SendRate>>) -> bool {
+    send_rate.is_none_or(|send_rate| send_rate.0.just_finished())
+}
+
+/// The password of `config` as sent by a client when connecting, padded with zeros.
+fn password_data(config: &NetworkConfig) -> Option<[u8; NETCODE_USER_DATA_BYTES]> {
+    let password = config.password.as_deref()?;
+    let mut data = [0; NETCODE_USER_DATA_BYTES];
+    let len = password.len().min(NETCODE_USER_DATA_BYTES);
+    data[..len].copy_from_slice(&password.as_bytes()[..len]);
+    Some(data)
+}
+
+/// Disconnects the clients which did not give the password of the server, if it has one.
+pub(crate) fn check_password(
+    mut disconnect_writer: MessageWriter<DisconnectRequest>,
+    config: Res<ConfigFile>,
+    transport: Option<Res<NetcodeServerTransport>>,
+    clients: Query<(Entity, &NetworkId), Added<ConnectedClient>>,
+) {
+    let (Some(password), Some(transport)) = (password_data(&config.network), transport) else {
+        return;
+    };
+    for (client, network_id) in &clients {
+        if transport.user_data(network_id.get()) != Some(password) {
+            warn!("Disconnecting client {client}, as it gave a wrong password");
+            disconnect_writer.write(DisconnectRequest { client });
+        }
+    }
+}
+
+/// Where a server listens for remote clients, such as the port it picked for `--port 0`.
+/// Servers whose clients connect in memory have none.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ServerInfo {
+    /// Address of the socket, on every interface.
+    pub addr: SocketAddr,
+}
+
+fn init_server(
+    commands: &mut Commands,
+    channels: &RepliconChannels,
+    config: &NetworkConfig,
+    port: u16,
+) -> Result<()> {
     let server = RenetServer::new(connection_config(channels));
 
     let current_time = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
     let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port))?;
+    let addr = socket.local_addr()?;
     let server_config = ServerConfig {
         current_time,
-        max_clients: 1,
+        max_clients: config.max_clients,
         protocol_id: PROTOCOL_ID,
         authentication: ServerAuthentication::Unsecure,
         public_addresses: Default::default(),
     };
     let transport = NetcodeServerTransport::new(server_config, socket)?;
+    info!("Listening on {addr}");
 
     commands.insert_resource(server);
     commands.insert_resource(transport);
-    commands.spawn(Text::new("Server"));
+    commands.insert_resource(ServerInfo { addr });
+    #[cfg(feature = "client-visuals")]
+    commands.spawn(Text(format!("Server: {addr}")));
 
     Ok(())
 }
 
+/// The address of the first server found on the local network.
+fn discover_server() -> Result<SocketAddr> {
+    info!("Looking for servers on the local network");
+    let servers = discovery::discover(DISCOVERY_TIMEOUT)?;
+    for server in &servers {
+        info!(
+            "Found the server of {} at {}, with {} players, version {}",
+            server.name, server.addr, server.players, server.version
+        );
+    }
+    match servers.first() {
+        Some(server) => Ok(server.addr),
+        None => Err("no servers found on the local network".into()),
+    }
+}
+
 fn init_client(
     commands: &mut Commands,
     channels: &RepliconChannels,
-    ip: IpAddr,
-    port: u16,
+    config: &NetworkConfig,
+    server_addr: SocketAddr,
 ) -> Result<()> {
-    info!("connecting to {ip}:{port}");
+    info!("connecting to {server_addr}");
 
     let client = RenetClient::new(connection_config(channels));
 
     let current_time = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
     let client_id = current_time.as_millis() as u64;
-    let server_addr = SocketAddr::new(ip, port);
     let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
     let addr = socket.local_addr()?;
+    debug!("Bound the client to {addr}");
     let authentication = ClientAuthentication::Unsecure {
         client_id,
         protocol_id: PROTOCOL_ID,
         server_addr,
-        user_data: None,
+        user_data: password_data(config),
     };
     let transport = NetcodeClientTransport::new(current_time, authentication, socket)?;
 
     commands.insert_resource(client);
     commands.insert_resource(transport);
+    #[cfg(feature = "client-visuals")]
     commands.spawn(Text(format!("Client: {addr}")));
 
     Ok(())
 }
 
-fn connection_config(channels: &RepliconChannels) -> ConnectionConfig {
+pub(crate) fn connection_config(channels: &RepliconChannels) -> ConnectionConfig {
     ConnectionConfig {
         server_channels_config: channels.server_configs(),
         client_channels_config: channels.client_configs(),
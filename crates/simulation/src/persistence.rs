@@ -0,0 +1,397 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, futures::check_ready},
+};
+use bevy_replicon::prelude::*;
+use merlo_model::{
+    CameraPath, CharacterModel, Doodad, Health, Lamp, Level, Pickup, PickupSpawner, PlatformPath,
+    Player, SpawnPoint, StaticGeometry, TimeOfDay, TriggerZone, Weather,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{ConfigFile, ConfigReloaded},
+    controller::has_server_authority,
+    network::{Cli, DEFAULT_AUTOSAVE_KEEP},
+};
+
+/// File where the world is saved when no other file is given.
+pub const SAVE_PATH: &str = "world.ron";
+
+pub struct PersistencePlugin;
+
+impl Plugin for PersistencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_client_message::<SaveWorld>(Channel::Ordered)
+            .add_client_message::<RestoreAutosave>(Channel::Ordered)
+            .add_systems(Startup, init_autosave)
+            .add_systems(
+                Update,
+                (
+                    save_requested,
+                    restore_requested,
+                    reload_autosave.run_if(on_message::<ConfigReloaded>),
+                    autosave_world.run_if(resource_exists::<Autosave>),
+                )
+                    .run_if(has_server_authority),
+            )
+            .add_systems(
+                Last,
+                save_on_shutdown.run_if(on_message::<AppExit>.and(has_server_authority)),
+            );
+    }
+}
+
+/// A [`Message`] written by the host to save the world, such as with the `/save` command.
+#[derive(Message, Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SaveWorld {
+    /// File to write, or the file of the world when `None`.
+    pub path: Option<PathBuf>,
+}
+
+/// A [`Message`] written by the host to replace the world with its latest autosave, such
+/// as with the `/restore` command.
+#[derive(Message, Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct RestoreAutosave;
+
+/// A replicated entity as written to disk, with the components worth restoring.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct SavedEntity {
+    pub transform: Option<Transform>,
+    pub character_model: Option<CharacterModel>,
+    pub player: Option<Player>,
+    pub health: Option<Health>,
+    pub doodad: Option<Doodad>,
+    pub lamp: Option<Lamp>,
+    pub spawn_point: Option<SpawnPoint>,
+    pub static_geometry: Option<StaticGeometry>,
+    pub platform_path: Option<PlatformPath>,
+    pub trigger_zone: Option<TriggerZone>,
+    pub pickup_spawner: Option<PickupSpawner>,
+    pub level: Option<Level>,
+    pub time_of_day: Option<TimeOfDay>,
+    pub weather: Option<Weather>,
+    pub camera_path: Option<CameraPath>,
+}
+
+/// Every replicated entity of the world which outlives the connections of clients.
+#[derive(Serialize, Deserialize, Default)]
+pub struct WorldSave {
+    pub entities: Vec<SavedEntity>,
+}
+
+impl WorldSave {
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(ron::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+
+    /// Spawns the saved entities as replicated entities.
+    pub fn spawn(self, commands: &mut Commands) {
+        let count = self.entities.len();
+        for saved in self.entities {
+            saved.spawn(commands);
+        }
+        info!("Restored {count} entities");
+    }
+}
+
+impl SavedEntity {
+    /// Takes the components worth restoring of `entity`.
+    pub fn of(entity: EntityRef) -> Self {
+        Self {
+            transform: entity.get().copied(),
+            character_model: entity.get().cloned(),
+            player: entity.get().cloned(),
+            health: entity.get().copied(),
+            doodad: entity.get().copied(),
+            lamp: entity.get().copied(),
+            spawn_point: entity.get().copied(),
+            static_geometry: entity.get().cloned(),
+            platform_path: entity.get().cloned(),
+            trigger_zone: entity.get().cloned(),
+            pickup_spawner: entity.get().copied(),
+            level: entity.get().cloned(),
+            time_of_day: entity.get().copied(),
+            weather: entity.get().copied(),
+            camera_path: entity.get().cloned(),
+        }
+    }
+
+    /// Spawns the saved entity as a replicated entity. The model of a character is
+    /// inserted before its player, which sets up the character on insertion.
+    pub fn spawn(self, commands: &mut Commands) -> Entity {
+        let mut entity = commands.spawn(Replicated);
+        if let Some(transform) = self.transform {
+            entity.insert(transform);
+        }
+        if let Some(model) = self.character_model {
+            entity.insert(model);
+        }
+        if let Some(health) = self.health {
+            entity.insert(health);
+        }
+        if let Some(player) = self.player {
+            entity.insert(player);
+        }
+        if let Some(doodad) = self.doodad {
+            entity.insert(doodad);
+        }
+        if let Some(lamp) = self.lamp {
+            entity.insert(lamp);
+        }
+        if let Some(spawn_point) = self.spawn_point {
+            entity.insert(spawn_point);
+        }
+        // The path goes first, so that the geometry is set up as a moving platform.
+        if let Some(path) = self.platform_path {
+            entity.insert(path);
+        }
+        if let Some(geometry) = self.static_geometry {
+            entity.insert(geometry);
+        }
+        if let Some(zone) = self.trigger_zone {
+            entity.insert(zone);
+        }
+        if let Some(spawner) = self.pickup_spawner {
+            entity.insert(spawner);
+        }
+        if let Some(level) = self.level {
+            entity.insert(level);
+        }
+        if let Some(time_of_day) = self.time_of_day {
+            entity.insert(time_of_day);
+        }
+        if let Some(weather) = self.weather {
+            entity.insert(weather);
+        }
+        if let Some(camera_path) = self.camera_path {
+            entity.insert(camera_path);
+        }
+        entity.id()
+    }
+}
+
+/// Replicated entities which outlive the connections of clients.
+pub(crate) type SavedFilter = (
+    With<Replicated>,
+    Or<(
+        With<Player>,
+        With<Doodad>,
+        With<Lamp>,
+        With<SpawnPoint>,
+        With<StaticGeometry>,
+        With<TriggerZone>,
+        With<PickupSpawner>,
+        With<Level>,
+        With<CameraPath>,
+    )>,
+);
+
+fn collect_world(entities: &Query<EntityRef, SavedFilter>) -> WorldSave {
+    let entities = entities.iter().map(SavedEntity::of).collect();
+    WorldSave { entities }
+}
+
+/// The file to save the world to by default: the one it was loaded from, if any.
+fn default_path(cli: &Cli) -> PathBuf {
+    cli.load()
+        .map_or_else(|| PathBuf::from(SAVE_PATH), Path::to_path_buf)
+}
+
+fn save_world(entities: &Query<EntityRef, SavedFilter>, path: &Path) {
+    let world = collect_world(entities);
+    match world.save(path) {
+        Ok(()) => info!(
+            "Saved {} entities to {}",
+            world.entities.len(),
+            path.display()
+        ),
+        Err(err) => error!("Cannot save the world to {}: {err}", path.display()),
+    }
+}
+
+/// Saves the world when asked by the host.
+fn save_requested(
+    mut save_reader: MessageReader<FromClient<SaveWorld>>,
+    cli: Res<Cli>,
+    entities: Query<EntityRef, SavedFilter>,
+) {
+    for event in save_reader.read() {
+        if event.client_id != ClientId::Server {
+            warn!("Ignoring save from non-host client {:?}", event.client_id);
+            continue;
+        }
+        let path = event
+            .message
+            .path
+            .clone()
+            .unwrap_or_else(|| default_path(&cli));
+        save_world(&entities, &path);
+    }
+}
+
+/// Saves the world of a server when it shuts down, unless it was only benchmarked.
+fn save_on_shutdown(cli: Res<Cli>, entities: Query<EntityRef, SavedFilter>) {
+    if matches!(*cli, Cli::Server { .. }) && cli.bench().is_none() {
+        save_world(&entities, &default_path(&cli));
+    }
+}
+
+/// The file of the autosave `index`, next to the file of the world, `0` being the latest.
+fn autosave_path(cli: &Cli, index: usize) -> PathBuf {
+    default_path(cli).with_extension(format!("autosave.{index}.ron"))
+}
+
+/// Saves the world every while, writing it to disk in the background.
+#[derive(Resource)]
+struct Autosave {
+    timer: Timer,
+    keep: usize,
+    task: Option<Task<Result<usize>>>,
+}
+
+/// Seconds between autosaves and the number of autosaves kept, given on the command line or
+/// else in the configuration of a dedicated server.
+fn autosave_settings(cli: &Cli, config: &ConfigFile) -> Option<(f32, usize)> {
+    cli.autosave().or_else(|| match cli {
+        Cli::Server { bench: None, .. } => {
+            Some((config.persistence.autosave?, DEFAULT_AUTOSAVE_KEEP))
+        }
+        _ => None,
+    })
+}
+
+fn init_autosave(mut commands: Commands, cli: Res<Cli>, config: Res<ConfigFile>) {
+    if let Some((seconds, keep)) = autosave_settings(&cli, &config) {
+        info!("Autosaving the world every {seconds} seconds, keeping {keep} saves");
+        commands.insert_resource(Autosave {
+            timer: Timer::from_seconds(seconds, TimerMode::Repeating),
+            keep,
+            task: None,
+        });
+    }
+}
+
+/// Applies the autosave interval of the reloaded configuration, starting to autosave if not
+/// yet.
+fn reload_autosave(
+    commands: Commands,
+    cli: Res<Cli>,
+    config: Res<ConfigFile>,
+    autosave: Option<ResMut<Autosave>>,
+) {
+    let Some(seconds) = config.persistence.autosave else {
+        return;
+    };
+    match autosave {
+        Some(mut autosave) => {
+            if autosave.timer.duration().as_secs_f32() != seconds {
+                info!("Autosaving the world every {seconds} seconds");
+                autosave
+                    .timer
+                    .set_duration(Duration::from_secs_f32(seconds));
+            }
+        }
+        None => init_autosave(commands, cli, config),
+    }
+}
+
+/// Shifts the autosaves by one, dropping the oldest, and writes `world` as the latest.
+fn write_autosave(world: &WorldSave, paths: &[PathBuf]) -> Result<()> {
+    for index in (1..paths.len()).rev() {
+        if paths[index - 1].exists() {
+            std::fs::rename(&paths[index - 1], &paths[index])?;
+        }
+    }
+    world.save(&paths[0])
+}
+
+/// Collects the world when the autosave is due, and hands it to a background task to write,
+/// unless the previous one is still writing.
+fn autosave_world(
+    mut autosave: ResMut<Autosave>,
+    time: Res<Time>,
+    cli: Res<Cli>,
+    entities: Query<EntityRef, SavedFilter>,
+) {
+    if let Some(task) = &mut autosave.task
+        && let Some(result) = check_ready(task)
+    {
+        autosave.task = None;
+        match result {
+            Ok(count) => info!(
+                "Autosaved {count} entities to {}",
+                autosave_path(&cli, 0).display()
+            ),
+            Err(err) => error!("Cannot autosave the world: {err}"),
+        }
+    }
+    if !autosave.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    if autosave.task.is_some() {
+        warn!("Skipping the autosave, as the previous one is still being written");
+        return;
+    }
+    let world = collect_world(&entities);
+    let paths: Vec<_> = (0..autosave.keep)
+        .map(|index| autosave_path(&cli, index))
+        .collect();
+    autosave.task = Some(IoTaskPool::get().spawn(async move {
+        write_autosave(&world, &paths)?;
+        Ok(world.entities.len())
+    }));
+}
+
+/// Replaces the saved entities of the world, and the pickups of their spawners, with the
+/// latest autosave when asked by the host.
+fn restore_requested(
+    mut commands: Commands,
+    mut restore_reader: MessageReader<FromClient<RestoreAutosave>>,
+    cli: Res<Cli>,
+    saved: Query<Entity, SavedFilter>,
+    pickups: Query<Entity, With<Pickup>>,
+) {
+    let mut requested = false;
+    for event in restore_reader.read() {
+        if event.client_id == ClientId::Server {
+            requested = true;
+        } else {
+            warn!(
+                "Ignoring restore from non-host client {:?}",
+                event.client_id
+            );
+        }
+    }
+    if !requested {
+        return;
+    }
+    let path = autosave_path(&cli, 0);
+    let world = match WorldSave::load(&path) {
+        Ok(world) => world,
+        Err(err) => {
+            error!("Cannot restore the autosave {}: {err}", path.display());
+            return;
+        }
+    };
+    for entity in saved.iter().chain(&pickups) {
+        commands.entity(entity).despawn();
+    }
+    world.spawn(&mut commands);
+}
@@ -0,0 +1,308 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{
+    ecs::{intern::Interned, schedule::ScheduleLabel},
+    prelude::*,
+};
+use bevy_rapier3d::prelude::*;
+use bevy_replicon::prelude::*;
+use clap::{Args, ValueEnum};
+use merlo_model::Gravity;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    controller::has_server_authority,
+    network::{Cli, TickRate},
+};
+
+/// Rate of the physics steps when stepping deterministically.
+pub const DETERMINISTIC_RATE: f64 = 60.0;
+/// Acceleration of gravity on Earth, in meters per second squared.
+const EARTH_GRAVITY: f32 = 9.81;
+/// Longest step by the frame time, as rapier does by default, so that physics slow down
+/// rather than tunnel when frames take long.
+const MAX_VARIABLE_DT: f32 = 1.0 / 60.0;
+/// Iterations of the solver of rapier by default, enough for most scenes.
+const DEFAULT_SOLVER_ITERATIONS: u32 = 4;
+
+/// How rapier advances the simulation at each step.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PhysicsTimestep {
+    /// By the frame time, once per frame, unless the server has a tick rate to step by.
+    #[default]
+    Variable,
+    /// By the fixed timestep of the server, as many times as it fits in the frame.
+    Fixed,
+    /// By the fixed timestep of the server once per frame, unless ahead of the real time.
+    Interpolated,
+}
+
+/// Options for the physics simulation.
+#[derive(Debug, Clone, Copy, Args, Resource, Serialize, Deserialize)]
+pub struct PhysicsArgs {
+    /// Downward acceleration of gravity in meters per second squared, such as 1.62 on the
+    /// Moon. Set by the server for everyone.
+    #[arg(
+        long,
+        global = true,
+        env = "MERLO_GRAVITY",
+        default_value_t = EARTH_GRAVITY,
+        allow_negative_numbers = true
+    )]
+    gravity: f32,
+
+    /// Step physics by a fixed timestep, so that replays, rollback, and repeated runs
+    /// produce the same results. Bit for bit across machines only when built with the
+    /// `deterministic` feature.
+    #[arg(long, global = true, env = "MERLO_DETERMINISTIC")]
+    deterministic: bool,
+
+    /// How physics advance at each step. Deterministic physics are always fixed.
+    #[arg(
+        long,
+        global = true,
+        env = "MERLO_TIMESTEP",
+        value_enum,
+        default_value_t
+    )]
+    #[serde(default)]
+    timestep: PhysicsTimestep,
+
+    /// Steps each physics step is split into, which keeps fast bodies from tunneling at a
+    /// higher cost.
+    #[arg(
+        long,
+        global = true,
+        env = "MERLO_SUBSTEPS",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    #[serde(default = "default_substeps")]
+    substeps: u32,
+
+    /// Iterations of the solver at each step. More stabilize stacks of bodies, fewer save
+    /// time on busy servers.
+    #[arg(
+        long,
+        global = true,
+        env = "MERLO_SOLVER_ITERATIONS",
+        default_value_t = DEFAULT_SOLVER_ITERATIONS,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    #[serde(default = "default_solver_iterations")]
+    solver_iterations: u32,
+}
+
+fn default_substeps() -> u32 {
+    1
+}
+
+fn default_solver_iterations() -> u32 {
+    DEFAULT_SOLVER_ITERATIONS
+}
+
+impl Default for PhysicsArgs {
+    fn default() -> Self {
+        Self {
+            gravity: EARTH_GRAVITY,
+            deterministic: false,
+            timestep: PhysicsTimestep::default(),
+            substeps: default_substeps(),
+            solver_iterations: default_solver_iterations(),
+        }
+    }
+}
+
+impl PhysicsArgs {
+    /// The same options, stepping physics by a fixed timestep.
+    pub fn deterministic(self) -> Self {
+        Self {
+            deterministic: true,
+            ..self
+        }
+    }
+
+    /// Whether physics are stepped by a fixed timestep.
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// The settings of rapier these options pick, stepping by a fixed timestep when
+    /// deterministic, or when `ticked` by the server unless asked otherwise.
+    pub fn settings(&self, ticked: bool) -> PhysicsSettings {
+        let timestep = match self.timestep {
+            _ if self.deterministic => PhysicsTimestep::Fixed,
+            PhysicsTimestep::Variable if ticked => PhysicsTimestep::Fixed,
+            timestep => timestep,
+        };
+        PhysicsSettings {
+            timestep,
+            substeps: self.substeps as usize,
+            solver_iterations: self.solver_iterations as usize,
+        }
+    }
+}
+
+/// Settings of rapier applied at startup, trading accuracy for time spent stepping.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhysicsSettings {
+    pub timestep: PhysicsTimestep,
+    /// Steps each physics step is split into.
+    pub substeps: usize,
+    /// Iterations of the solver at each step.
+    pub solver_iterations: usize,
+}
+
+impl PhysicsSettings {
+    /// Whether rapier steps in [`FixedUpdate`], and characters move along with it there,
+    /// rather than once per frame.
+    pub fn is_fixed(&self) -> bool {
+        self.timestep == PhysicsTimestep::Fixed
+    }
+
+    /// The schedule rapier steps in, [`FixedUpdate`] when fixed, otherwise [`PostUpdate`].
+    pub fn schedule(&self) -> Interned<dyn ScheduleLabel> {
+        if self.is_fixed() {
+            FixedUpdate.intern()
+        } else {
+            PostUpdate.intern()
+        }
+    }
+
+    /// The timestep mode of rapier, stepping by `dt` unless variable.
+    fn timestep_mode(&self, dt: f32) -> TimestepMode {
+        match self.timestep {
+            PhysicsTimestep::Variable => TimestepMode::Variable {
+                max_dt: MAX_VARIABLE_DT,
+                time_scale: 1.0,
+                substeps: self.substeps,
+            },
+            PhysicsTimestep::Fixed => TimestepMode::Fixed {
+                dt,
+                substeps: self.substeps,
+            },
+            PhysicsTimestep::Interpolated => TimestepMode::Interpolated {
+                dt,
+                time_scale: 1.0,
+                substeps: self.substeps,
+            },
+        }
+    }
+}
+
+/// Sets up rapier, stepping either once per frame, or by a fixed timestep in
+/// [`FixedUpdate`] when fixed, deterministic, or ticked by the server at its [`TickRate`],
+/// as given by its [`PhysicsSettings`].
+///
+/// Given the same inputs in the same order, the solver of rapier has no randomness of
+/// its own to seed. What breaks determinism is the variable frame time, and the SIMD and
+/// parallel code paths of rapier, which `enhanced-determinism` replaces with portable ones.
+pub struct PhysicsPlugin {
+    args: PhysicsArgs,
+}
+
+impl PhysicsPlugin {
+    pub fn new(args: PhysicsArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        if self.args.deterministic {
+            if !cfg!(feature = "deterministic") {
+                warn!(
+                    "Stepping physics with a fixed timestep, but without the `deterministic` feature results may differ across machines"
+                );
+            }
+            if let Some(mut tick_rate) = app.world_mut().get_resource_mut::<TickRate>() {
+                warn!("Ticking at {DETERMINISTIC_RATE} Hz instead, as physics are deterministic");
+                tick_rate.0 = DETERMINISTIC_RATE;
+            }
+            app.insert_resource(Time::<Fixed>::from_hz(DETERMINISTIC_RATE));
+        }
+        // Rapier and the character controllers both step in the schedule these pick.
+        let settings = self
+            .args
+            .settings(app.world().contains_resource::<TickRate>());
+        app.insert_resource(self.args)
+            .insert_resource(settings)
+            .add_client_message::<SetGravity>(Channel::Ordered)
+            .add_systems(Startup, spawn_gravity)
+            .add_systems(PostStartup, apply_settings)
+            .add_systems(Update, set_gravity_requested.run_if(has_server_authority))
+            .add_systems(PreUpdate, apply_gravity);
+        // Rapier reads the timestep mode when added, so it goes first.
+        let dt = app
+            .world()
+            .get_resource::<Time<Fixed>>()
+            .map_or_else(|| Time::<Fixed>::default().timestep(), Time::timestep);
+        app.insert_resource(settings.timestep_mode(dt.as_secs_f32()));
+        app.add_plugins(
+            RapierPhysicsPlugin::<NoUserData>::default().in_schedule(settings.schedule()),
+        );
+    }
+}
+
+/// A [`Message`] written by the host to change the gravity, such as with the `/gravity`
+/// command.
+#[derive(Message, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct SetGravity {
+    /// Downward acceleration in meters per second squared.
+    pub acceleration: f32,
+}
+
+/// Spawns the replicated gravity of the world, which clients receive from the server.
+fn spawn_gravity(mut commands: Commands, cli: Res<Cli>, args: Res<PhysicsArgs>) {
+    if !matches!(*cli, Cli::Client { .. }) {
+        commands.spawn((Replicated, Gravity(Vec3::NEG_Y * args.gravity)));
+    }
+}
+
+fn set_gravity_requested(
+    mut gravity_reader: MessageReader<FromClient<SetGravity>>,
+    mut gravity: Query<&mut Gravity>,
+) {
+    for event in gravity_reader.read() {
+        if event.client_id != ClientId::Server {
+            warn!(
+                "Ignoring gravity from non-host client {:?}",
+                event.client_id
+            );
+            continue;
+        }
+        for mut gravity in &mut gravity {
+            gravity.0 = Vec3::NEG_Y * event.message.acceleration;
+        }
+        info!("Gravity set to {} m/s²", event.message.acceleration);
+    }
+}
+
+/// Applies the settings to rapier, once its contexts are spawned.
+fn apply_settings(
+    settings: Res<PhysicsSettings>,
+    fixed: Res<Time<Fixed>>,
+    mut mode: ResMut<TimestepMode>,
+    mut contexts: Query<&mut RapierContextSimulation>,
+) {
+    *mode = settings.timestep_mode(fixed.timestep().as_secs_f32());
+    for mut context in &mut contexts {
+        context.integration_parameters.num_solver_iterations = settings.solver_iterations;
+    }
+    debug!("Physics settings: {:?}", *settings);
+}
+
+/// Keeps the gravity of rapier in sync with the replicated one.
+fn apply_gravity(gravity: Query<&Gravity>, mut configurations: Query<&mut RapierConfiguration>) {
+    let Ok(gravity) = gravity.single() else {
+        return;
+    };
+    for mut configuration in &mut configurations {
+        if configuration.gravity != gravity.0 {
+            configuration.gravity = gravity.0;
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::{Dead, Health, Pickup, PickupIncoming, PickupKind, PickupSpawner, Player};
+
+use crate::controller::has_server_authority;
+
+/// Keeps a pickup at every [`PickupSpawner`], which the server despawns when a character
+/// collects it and spawns again once the delay of the spawner is over.
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(init_pickup).add_systems(
+            Update,
+            (stock_new_spawners, collect_pickups, restock_spawners)
+                .chain()
+                .run_if(has_server_authority),
+        );
+    }
+}
+
+/// Height of a pickup above its spawner.
+const PICKUP_HEIGHT: f32 = 0.75;
+const PICKUP_RADIUS: f32 = 0.4;
+/// Health restored by a health pickup.
+const HEAL_AMOUNT: f32 = 25.0;
+/// Seconds before a pickup comes back during which its spawner is [`PickupIncoming`].
+const INCOMING_WARNING: f32 = 3.0;
+
+/// The spawner a pickup belongs to, only on the server.
+#[derive(Component)]
+struct FromSpawner(Entity);
+
+/// Counts down to the next pickup of an empty spawner, only on the server.
+#[derive(Component)]
+struct Restock(Timer);
+
+/// Backs pickups with a sensor, so that characters walk through them.
+fn init_pickup(add: On<Add, Pickup>, mut commands: Commands) {
+    commands
+        .entity(add.entity)
+        .insert((Collider::ball(PICKUP_RADIUS), Sensor));
+}
+
+fn spawn_pickup(commands: &mut Commands, spawner: Entity, kind: PickupKind, transform: &Transform) {
+    commands.spawn((
+        Replicated,
+        Transform::from_translation(transform.translation + Vec3::Y * PICKUP_HEIGHT),
+        Pickup { kind },
+        FromSpawner(spawner),
+    ));
+}
+
+/// Gives a pickup to spawners as soon as they are placed.
+fn stock_new_spawners(
+    mut commands: Commands,
+    spawners: Query<(Entity, &PickupSpawner, &Transform), Added<PickupSpawner>>,
+) {
+    for (entity, spawner, transform) in &spawners {
+        spawn_pickup(&mut commands, entity, spawner.kind, transform);
+    }
+}
+
+/// Applies a pickup to the character it touches, if it makes any difference to them.
+fn apply_pickup(kind: PickupKind, health: &mut Health) -> bool {
+    match kind {
+        PickupKind::Health => {
+            if !health.is_damaged() {
+                return false;
+            }
+            health.current = (health.current + HEAL_AMOUNT).min(health.max);
+            true
+        }
+    }
+}
+
+/// Hands pickups to the living characters overlapping them, emptying their spawners.
+fn collect_pickups(
+    mut commands: Commands,
+    rapier_context: ReadRapierContext,
+    pickups: Query<(Entity, &Pickup, &FromSpawner)>,
+    spawners: Query<&PickupSpawner>,
+    mut characters: Query<&mut Health, (With<Player>, Without<Dead>)>,
+) {
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    for (entity, pickup, from_spawner) in &pickups {
+        let collector = rapier_context
+            .intersection_pairs_with(entity)
+            .filter(|&(_, _, intersecting)| intersecting)
+            .map(|(a, b, _)| if a == entity { b } else { a })
+            .find(|&character| {
+                characters
+                    .get_mut(character)
+                    .is_ok_and(|mut health| apply_pickup(pickup.kind, &mut health))
+            });
+        let Some(character) = collector else {
+            continue;
+        };
+        debug!("{character} collected a {:?} pickup", pickup.kind);
+        commands.entity(entity).despawn();
+        if let Ok(spawner) = spawners.get(from_spawner.0) {
+            commands
+                .entity(from_spawner.0)
+                .insert(Restock(Timer::from_seconds(
+                    spawner.respawn_delay,
+                    TimerMode::Once,
+                )));
+        }
+    }
+}
+
+/// Announces pickups about to come back, and puts them back once their delay is over.
+fn restock_spawners(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut spawners: Query<(
+        Entity,
+        &PickupSpawner,
+        &Transform,
+        &mut Restock,
+        Has<PickupIncoming>,
+    )>,
+) {
+    for (entity, spawner, transform, mut restock, incoming) in &mut spawners {
+        restock.0.tick(time.delta());
+        if restock.0.is_finished() {
+            commands
+                .entity(entity)
+                .remove::<(Restock, PickupIncoming)>();
+            spawn_pickup(&mut commands, entity, spawner.kind, transform);
+        } else if !incoming && restock.0.remaining_secs() <= INCOMING_WARNING {
+            commands.entity(entity).insert(PickupIncoming);
+        }
+    }
+}
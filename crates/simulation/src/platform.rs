@@ -0,0 +1,160 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use merlo_model::{LoopMode, PlatformPath};
+
+use crate::{
+    controller::{
+        CharacterControllerSet, CharacterDimensions, CharacterMovementState, has_server_authority,
+        movement_schedule,
+    },
+    replication::ReplicateVelocity,
+};
+
+pub struct PlatformPlugin;
+
+impl Plugin for PlatformPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(init_platform);
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Platforms carry characters, so they move along with them, once the physics picked
+        // where characters move.
+        let schedule = movement_schedule(app.world());
+        app.add_systems(
+            schedule,
+            (
+                move_platforms.before(CharacterControllerSet::Movement),
+                carry_riders.after(CharacterControllerSet::Movement),
+            )
+                .run_if(has_server_authority),
+        );
+    }
+}
+
+/// How far below the soles of a character a platform still carries it.
+const RIDE_DISTANCE: f32 = 0.5;
+
+/// Waypoint a platform is moving to on the server, and the direction it goes along its path.
+#[derive(Component, Debug, Default)]
+struct PlatformProgress {
+    target: usize,
+    backwards: bool,
+}
+
+impl PlatformProgress {
+    /// Moves on to the waypoint after the target, or returns `false` at the end of a path
+    /// followed once.
+    fn advance(&mut self, path: &PlatformPath) -> bool {
+        let last = path.waypoints.len().saturating_sub(1);
+        match path.loop_mode {
+            LoopMode::Once if self.target >= last => return false,
+            LoopMode::Once => self.target += 1,
+            LoopMode::Loop => self.target = (self.target + 1) % (last + 1),
+            LoopMode::PingPong => {
+                if last == 0 {
+                    return false;
+                }
+                if self.target == last {
+                    self.backwards = true;
+                } else if self.target == 0 {
+                    self.backwards = false;
+                }
+                if self.backwards {
+                    self.target -= 1;
+                } else {
+                    self.target += 1;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Platforms move by their velocity, which is replicated, so that clients keep moving them
+/// smoothly between updates of their transform.
+fn init_platform(add: On<Add, PlatformPath>, mut commands: Commands) {
+    commands.entity(add.entity).insert((
+        Velocity::zero(),
+        ReplicateVelocity,
+        PlatformProgress::default(),
+    ));
+}
+
+/// Sets the velocity of platforms toward their next waypoint, without overshooting it.
+fn move_platforms(
+    time: Res<Time>,
+    mut platforms: Query<(
+        &Transform,
+        &PlatformPath,
+        &mut PlatformProgress,
+        &mut Velocity,
+    )>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+    for (transform, path, mut progress, mut velocity) in &mut platforms {
+        let step = path.speed * dt;
+        let mut to_target = None;
+        // Skips the waypoints reached within this step, at most once around the path.
+        for _ in 0..path.waypoints.len() {
+            let Some(&target) = path.waypoints.get(progress.target) else {
+                break;
+            };
+            let offset = target - transform.translation;
+            if offset.length() > step {
+                to_target = Some(offset.normalize() * path.speed);
+                break;
+            }
+            if !progress.advance(path) {
+                to_target = Some(offset / dt);
+                break;
+            }
+        }
+        *velocity = Velocity::linear(to_target.unwrap_or_default());
+    }
+}
+
+/// Adds the horizontal velocity of the platform under grounded characters to theirs, since
+/// their movement replaces it every frame. Platforms lift characters by contact instead.
+fn carry_riders(
+    rapier_context: ReadRapierContext,
+    platforms: Query<&Velocity, With<PlatformPath>>,
+    mut characters: Query<
+        (
+            Entity,
+            &Transform,
+            &CharacterDimensions,
+            &CharacterMovementState,
+            &mut Velocity,
+        ),
+        Without<PlatformPath>,
+    >,
+) {
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    for (character, transform, dimensions, movement_state, mut velocity) in &mut characters {
+        if !movement_state.grounded {
+            continue;
+        }
+        let origin = dimensions.probe_origin(transform.translation);
+        let filter = QueryFilter::default()
+            .exclude_collider(character)
+            .exclude_sensors();
+        let Some(platform) = rapier_context
+            .cast_ray(origin, Vec3::NEG_Y, RIDE_DISTANCE, true, filter)
+            .and_then(|(ground, _)| platforms.get(ground).ok())
+        else {
+            continue;
+        };
+        velocity.linvel.x += platform.linvel.x;
+        velocity.linvel.z += platform.linvel.z;
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::EntityHashMap, prelude::*};
+use bevy_replicon::prelude::*;
+use merlo_model::{Player, PlayerId};
+
+use crate::{admin::ControlledCharacter, controller::has_server_authority};
+
+/// Tells the logic of a game, on the server, when clients take and give up the character of
+/// a player, so that it can keep scores, greet them, or set up their character.
+pub struct PlayersPlugin;
+
+impl Plugin for PlayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<PlayerJoined>()
+            .add_message::<PlayerLeft>()
+            .add_systems(Update, announce_players.run_if(has_server_authority));
+    }
+}
+
+/// A [`Message`] written on the server when a client starts controlling the character of
+/// a player.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerJoined {
+    pub player: PlayerId,
+    pub client_id: ClientId,
+    pub character: Entity,
+}
+
+/// A [`Message`] written on the server when a client stops controlling the character of
+/// a player, as it disconnected, switched to another character, or the character is gone.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerLeft {
+    pub player: PlayerId,
+    pub client_id: ClientId,
+    pub character: Entity,
+}
+
+/// Compares the characters controlled by clients with the ones of the last frame.
+/// Their players are remembered, since characters may be gone by the time they are left.
+fn announce_players(
+    mut joined_writer: MessageWriter<PlayerJoined>,
+    mut left_writer: MessageWriter<PlayerLeft>,
+    mut controlled: Local<EntityHashMap<(Entity, PlayerId)>>,
+    clients: Query<(Entity, &ControlledCharacter), With<ConnectedClient>>,
+    players: Query<&Player>,
+) {
+    controlled.retain(|&client, &mut (character, player)| {
+        let kept = clients
+            .get(client)
+            .is_ok_and(|(_, controlled)| controlled.0 == character)
+            && players.contains(character);
+        if !kept {
+            left_writer.write(PlayerLeft {
+                player,
+                client_id: ClientId::Client(client),
+                character,
+            });
+        }
+        kept
+    });
+    for (client, &ControlledCharacter(character)) in &clients {
+        if controlled.contains_key(&client) {
+            continue;
+        }
+        let Ok(player) = players.get(character) else {
+            continue;
+        };
+        controlled.insert(client, (character, player.id()));
+        joined_writer.write(PlayerJoined {
+            player: player.id(),
+            client_id: ClientId::Client(client),
+            character,
+        });
+    }
+}
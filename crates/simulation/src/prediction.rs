@@ -0,0 +1,189 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::component::Mutable, prelude::*};
+use bevy_rapier3d::prelude::*;
+use bevy_replicon::{
+    bytes::Bytes,
+    prelude::*,
+    shared::replication::{
+        deferred_entity::DeferredEntity,
+        registry::{
+            ctx::{RemoveCtx, WriteCtx},
+            rule_fns::RuleFns,
+        },
+    },
+};
+use merlo_model::{Carried, Doodad};
+
+use crate::{
+    controller::{CharacterController, has_server_authority},
+    replication::NetworkTransform,
+};
+
+/// Lets clients simulate the doodads their character pushes, instead of waiting for the
+/// server to move them, and eases them back to where the server says they are.
+pub struct PredictionPlugin;
+
+impl Plugin for PredictionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_marker::<Predicted>()
+            .set_marker_fns::<Predicted, NetworkTransform>(
+                write_authoritative::<NetworkTransform, Transform>,
+                remove_authoritative::<Transform>,
+            )
+            .set_marker_fns::<Predicted, Velocity>(
+                write_authoritative::<Velocity, Velocity>,
+                remove_authoritative::<Velocity>,
+            )
+            .add_systems(
+                Update,
+                (predict_touched_doodads, correct_predictions)
+                    .chain()
+                    .run_if(not(has_server_authority)),
+            );
+    }
+}
+
+/// Seconds a doodad keeps being predicted after the local character stops touching it, so
+/// that it settles before the server takes over again.
+const RELEASE_DELAY: f32 = 0.5;
+/// How fast predictions are pulled toward the server state, per second.
+const CORRECTION_RATE: f32 = 4.0;
+/// Distance beyond which a prediction is too wrong to ease, and snaps to the server state.
+const SNAP_DISTANCE: f32 = 1.0;
+
+/// Marks a doodad the client simulates on its own, only on clients.
+///
+/// Replicated transforms and velocities of predicted doodads end up in [`Authoritative`]
+/// instead of overwriting the prediction.
+#[derive(Component, Debug, Default)]
+pub struct Predicted {
+    /// Seconds since the local character last touched the doodad.
+    since_contact: f32,
+}
+
+/// The last value of `C` received from the server for a [`Predicted`] entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Authoritative<C>(pub C);
+
+/// Stores a received `C` as the [`Authoritative`] value of `T` it is replicated for.
+fn write_authoritative<C, T>(
+    ctx: &mut WriteCtx,
+    rule_fns: &RuleFns<C>,
+    entity: &mut DeferredEntity,
+    message: &mut Bytes,
+) -> Result<()>
+where
+    C: Component<Mutability = Mutable> + Into<T>,
+    T: Send + Sync + 'static,
+{
+    let component: C = rule_fns.deserialize(ctx, message)?;
+    entity.insert(Authoritative::<T>(component.into()));
+    Ok(())
+}
+
+fn remove_authoritative<C: Component>(_ctx: &mut RemoveCtx, entity: &mut DeferredEntity) {
+    entity.remove::<Authoritative<C>>().remove::<C>();
+}
+
+/// Predicts the doodads the local character touches, and hands them back to the server
+/// a while after it stops touching them.
+#[allow(clippy::type_complexity)]
+fn predict_touched_doodads(
+    mut commands: Commands,
+    time: Res<Time>,
+    rapier_context: ReadRapierContext,
+    controller: Query<&ChildOf, With<CharacterController>>,
+    doodads: Query<(), (With<Doodad>, Without<Carried>)>,
+    mut predicted: Query<(
+        Entity,
+        &mut Predicted,
+        &mut Transform,
+        Option<&mut Velocity>,
+        Option<&Authoritative<Transform>>,
+        Option<&Authoritative<Velocity>>,
+    )>,
+) {
+    let (Ok(rapier_context), Ok(character)) = (
+        rapier_context.single(),
+        controller.single().map(ChildOf::parent),
+    ) else {
+        return;
+    };
+
+    let mut touched = Vec::new();
+    for pair in rapier_context.contact_pairs_with(character) {
+        if !pair.has_any_active_contact() {
+            continue;
+        }
+        let other = match (pair.collider1(), pair.collider2()) {
+            (Some(collider), Some(other)) if collider == character => other,
+            (Some(other), _) => other,
+            _ => continue,
+        };
+        if doodads.contains(other) {
+            touched.push(other);
+        }
+    }
+
+    for (doodad, mut prediction, mut transform, velocity, server_transform, server_velocity) in
+        &mut predicted
+    {
+        if touched.contains(&doodad) {
+            prediction.since_contact = 0.0;
+            continue;
+        }
+        prediction.since_contact += time.delta_secs();
+        if prediction.since_contact < RELEASE_DELAY && doodads.contains(doodad) {
+            continue;
+        }
+        // Replication writes to the doodad directly again, starting from the server state.
+        if let Some(server_transform) = server_transform {
+            *transform = server_transform.0;
+        }
+        if let (Some(mut velocity), Some(server_velocity)) = (velocity, server_velocity) {
+            *velocity = server_velocity.0;
+        }
+        commands
+            .entity(doodad)
+            .remove::<(Predicted, Authoritative<Transform>, Authoritative<Velocity>)>();
+    }
+
+    for doodad in touched {
+        if !predicted.contains(doodad) {
+            commands.entity(doodad).insert(Predicted::default());
+        }
+    }
+}
+
+/// Eases predicted doodads toward the server state, snapping them when too far off.
+#[allow(clippy::type_complexity)]
+fn correct_predictions(
+    time: Res<Time>,
+    mut predicted: Query<
+        (
+            &mut Transform,
+            Option<&mut Velocity>,
+            &Authoritative<Transform>,
+            Option<&Authoritative<Velocity>>,
+        ),
+        With<Predicted>,
+    >,
+) {
+    let blend = 1.0 - (-CORRECTION_RATE * time.delta_secs()).exp();
+    for (mut transform, velocity, server_transform, server_velocity) in &mut predicted {
+        let target = server_transform.0;
+        if transform.translation.distance(target.translation) > SNAP_DISTANCE {
+            *transform = target;
+        } else {
+            transform.translation = transform.translation.lerp(target.translation, blend);
+            transform.rotation = transform.rotation.slerp(target.rotation, blend);
+        }
+        if let (Some(mut velocity), Some(server_velocity)) = (velocity, server_velocity) {
+            velocity.linvel = velocity.linvel.lerp(server_velocity.0.linvel, blend);
+            velocity.angvel = velocity.angvel.lerp(server_velocity.0.angvel, blend);
+        }
+    }
+}
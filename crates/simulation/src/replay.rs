@@ -0,0 +1,265 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::path::{Path, PathBuf};
+
+use bevy::{
+    diagnostic::FrameCount,
+    ecs::entity::{EntityHashMap, MapEntities},
+    prelude::*,
+};
+use bevy_replicon::prelude::*;
+use merlo_model::Player;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    SimulationPlugin,
+    controller::{CharacterControllerSet, MovementAction},
+    network::Cli,
+    physics::PhysicsArgs,
+};
+
+/// Records the movement inputs of a server with `--record`, and feeds recorded inputs back
+/// for [`run`].
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, start_recording)
+            .add_systems(
+                Update,
+                (
+                    record_inputs
+                        .after(CharacterControllerSet::Input)
+                        .before(CharacterControllerSet::Movement)
+                        .run_if(resource_exists::<Recorder>),
+                    replay_inputs
+                        .in_set(CharacterControllerSet::Input)
+                        .run_if(resource_exists::<Replayer>),
+                ),
+            )
+            .add_systems(
+                Last,
+                save_recording.run_if(on_message::<AppExit>.and(resource_exists::<Recorder>)),
+            );
+    }
+}
+
+/// Largest difference between a replayed and an expected transform still matching, in
+/// meters and radians.
+const TOLERANCE: f32 = 1e-4;
+
+/// A movement input as received by the server during the frame `tick`.
+///
+/// Its character is stored as a placeholder entity whose index is the one of the character
+/// among the players in spawn order, which is the same on every run, unlike its entity.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct RecordedInput {
+    pub tick: u32,
+    pub action: MovementAction,
+}
+
+/// The inputs of every player of a session, and where the characters ended up when last
+/// replayed with `--bless`.
+#[derive(Serialize, Deserialize)]
+pub struct Recording {
+    pub level: Option<PathBuf>,
+    pub physics: PhysicsArgs,
+    /// Frames the session lasted.
+    pub ticks: u32,
+    pub inputs: Vec<RecordedInput>,
+    pub snapshot: Option<Vec<Transform>>,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(ron::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct Recorder {
+    path: PathBuf,
+    recording: Recording,
+}
+
+#[derive(Resource)]
+struct Replayer {
+    inputs: Vec<RecordedInput>,
+    /// Index of the next input to feed.
+    next: usize,
+}
+
+/// Player characters in spawn order, as entities and as their recorded placeholders.
+fn player_indices(players: &Query<Entity, With<Player>>) -> Vec<(Entity, Entity)> {
+    let mut players: Vec<_> = players.iter().collect();
+    players.sort();
+    players
+        .into_iter()
+        .enumerate()
+        .map(|(index, player)| {
+            let placeholder = Entity::from_raw_u32(index as u32).unwrap_or(Entity::PLACEHOLDER);
+            (player, placeholder)
+        })
+        .collect()
+}
+
+fn start_recording(mut commands: Commands, cli: Res<Cli>, physics: Res<PhysicsArgs>) {
+    let Some(path) = cli.record() else {
+        return;
+    };
+    info!("Recording inputs to {}", path.display());
+    commands.insert_resource(Recorder {
+        path: path.to_path_buf(),
+        recording: Recording {
+            level: cli.level().map(Path::to_path_buf),
+            physics: *physics,
+            ticks: 0,
+            inputs: Vec::new(),
+            snapshot: None,
+        },
+    });
+}
+
+/// Records the inputs of the frame, from the host and from every client alike.
+fn record_inputs(
+    frame: Res<FrameCount>,
+    mut recorder: ResMut<Recorder>,
+    mut movement_reader: MessageReader<FromClient<MovementAction>>,
+    players: Query<Entity, With<Player>>,
+) {
+    let mut placeholders: EntityHashMap<Entity> = player_indices(&players).into_iter().collect();
+    for event in movement_reader.read() {
+        if !placeholders.contains_key(&event.message.entity()) {
+            continue;
+        }
+        let mut action = event.message;
+        action.map_entities(&mut placeholders);
+        recorder.recording.inputs.push(RecordedInput {
+            tick: frame.0,
+            action,
+        });
+    }
+}
+
+fn save_recording(frame: Res<FrameCount>, mut recorder: ResMut<Recorder>) {
+    recorder.recording.ticks = frame.0 + 1;
+    match recorder.recording.save(&recorder.path) {
+        Ok(()) => info!(
+            "Recorded {} inputs over {} frames to {}",
+            recorder.recording.inputs.len(),
+            recorder.recording.ticks,
+            recorder.path.display()
+        ),
+        Err(err) => error!(
+            "Cannot save the recording to {}: {err}",
+            recorder.path.display()
+        ),
+    }
+}
+
+/// Feeds the recorded inputs of the frame, as if the host sent them.
+fn replay_inputs(
+    frame: Res<FrameCount>,
+    mut replayer: ResMut<Replayer>,
+    mut movement_writer: MessageWriter<FromClient<MovementAction>>,
+    players: Query<Entity, With<Player>>,
+) {
+    let mut characters: EntityHashMap<Entity> = player_indices(&players)
+        .into_iter()
+        .map(|(player, placeholder)| (placeholder, player))
+        .collect();
+    while let Some(input) = replayer.inputs.get(replayer.next)
+        && input.tick <= frame.0
+    {
+        let mut action = input.action;
+        replayer.next += 1;
+        action.map_entities(&mut characters);
+        movement_writer.write(FromClient {
+            client_id: ClientId::Server,
+            message: action,
+        });
+    }
+}
+
+/// Transforms of the player characters, in spawn order.
+fn snapshot(world: &mut World) -> Vec<Transform> {
+    let mut players: Vec<_> = world
+        .query_filtered::<(Entity, &Transform), With<Player>>()
+        .iter(world)
+        .map(|(player, transform)| (player, *transform))
+        .collect();
+    players.sort_by_key(|(player, _)| *player);
+    players
+        .into_iter()
+        .map(|(_, transform)| transform)
+        .collect()
+}
+
+/// Replays the recording at `path` in a headless world, stepping physics by a fixed
+/// timestep once per frame, and checks that the characters end up where its snapshot says.
+///
+/// With `bless`, or when the recording has no snapshot yet, the snapshot is written instead.
+pub fn run(path: &Path, bless: bool) -> Result<()> {
+    let mut recording = Recording::load(path)?;
+    let cli = Cli::Replay {
+        recording: path.to_path_buf(),
+        bless,
+        level: recording.level.clone(),
+    };
+    let mut app = crate::stepped_app(cli, recording.physics, SimulationPlugin::default());
+    app.insert_resource(Replayer {
+        inputs: recording.inputs.clone(),
+        next: 0,
+    });
+    while app.world().resource::<FrameCount>().0 < recording.ticks {
+        app.update();
+    }
+    let transforms = snapshot(app.world_mut());
+
+    let Some(expected) = recording.snapshot.as_ref().filter(|_| !bless) else {
+        info!(
+            "Blessed the snapshot of {} characters after {} frames",
+            transforms.len(),
+            recording.ticks
+        );
+        recording.snapshot = Some(transforms);
+        return recording.save(path);
+    };
+    if expected.len() != transforms.len() {
+        return Err(format!(
+            "expected {} characters, replayed {}",
+            expected.len(),
+            transforms.len()
+        )
+        .into());
+    }
+    let mut mismatches = 0;
+    for (index, (expected, replayed)) in expected.iter().zip(&transforms).enumerate() {
+        let distance = expected.translation.distance(replayed.translation);
+        let angle = expected.rotation.angle_between(replayed.rotation);
+        if distance > TOLERANCE || angle > TOLERANCE {
+            error!(
+                "Character {index} ended at {} instead of {}, off by {distance} m and {angle} rad",
+                replayed.translation, expected.translation
+            );
+            mismatches += 1;
+        }
+    }
+    if mismatches > 0 {
+        return Err(format!("{mismatches} characters diverged from the snapshot").into());
+    }
+    info!(
+        "Replayed {} inputs over {} frames matching the snapshot",
+        recording.inputs.len(),
+        recording.ticks
+    );
+    Ok(())
+}
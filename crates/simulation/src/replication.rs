@@ -0,0 +1,173 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{prelude::*, transform::TransformSystems};
+use bevy_rapier3d::prelude::Velocity;
+use bevy_replicon::{
+    bytes::Bytes,
+    prelude::*,
+    shared::replication::{
+        deferred_entity::DeferredEntity,
+        registry::{
+            ctx::{RemoveCtx, WriteCtx},
+            rule_fns::RuleFns,
+        },
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{controller::has_server_authority, traffic::TrafficAppExt};
+
+/// Fastest linear speed replicated along an axis, in meters per second. Faster speeds are
+/// clamped, which only happens to things flying off the world.
+const MAX_LINEAR_SPEED: f32 = 64.0;
+/// Fastest angular speed replicated around an axis, in radians per second.
+const MAX_ANGULAR_SPEED: f32 = 32.0;
+
+pub struct ReplicationPlugin {
+    pub transform_thresholds: TransformThresholds,
+}
+
+impl Plugin for ReplicationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.transform_thresholds)
+            .replicate_tracked::<NetworkTransform>()
+            .set_command_fns(write_transform, remove_transform)
+            .add_systems(
+                PostUpdate,
+                update_network_transforms
+                    .run_if(has_server_authority)
+                    .after(TransformSystems::Propagate)
+                    .before(ServerSystems::Send),
+            );
+    }
+}
+
+/// How far a replicated [`Transform`] moves or turns before the server sends it again, so
+/// that idle characters and settled doodads do not replicate the jitter of the physics.
+/// Thresholds of zero replicate every change.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TransformThresholds {
+    /// Distance in meters from the last replicated translation.
+    pub translation: f32,
+    /// Angle in radians from the last replicated rotation.
+    pub rotation: f32,
+}
+
+impl Default for TransformThresholds {
+    fn default() -> Self {
+        Self {
+            translation: 0.001,
+            rotation: 0.002,
+        }
+    }
+}
+
+impl TransformThresholds {
+    /// Whether `transform` is far enough from `last` to be replicated.
+    fn exceeded(&self, last: &Transform, transform: &Transform) -> bool {
+        last.translation.distance(transform.translation) > self.translation
+            || last.rotation.angle_between(transform.rotation) > self.rotation
+            || last.scale != transform.scale
+    }
+}
+
+/// The [`Transform`] of an entity as replicated in its place, which the server only updates
+/// when it moved or turned past the [`TransformThresholds`], and clients write as the
+/// [`Transform`] itself.
+///
+/// Replicon sends every change of a replicated component, so the thresholds hold back the
+/// changes of this copy rather than those of the [`Transform`], which other systems see.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct NetworkTransform(pub Transform);
+
+impl From<NetworkTransform> for Transform {
+    fn from(transform: NetworkTransform) -> Self {
+        transform.0
+    }
+}
+
+type ChangedTransform = (
+    Entity,
+    &'static Transform,
+    Option<&'static mut NetworkTransform>,
+);
+
+/// Copies the transforms which moved far enough since they were last replicated.
+fn update_network_transforms(
+    mut commands: Commands,
+    thresholds: Res<TransformThresholds>,
+    mut transforms: Query<ChangedTransform, (With<Replicated>, Changed<Transform>)>,
+) {
+    let _span = info_span!("replication_filter").entered();
+    for (entity, transform, replicated) in &mut transforms {
+        match replicated {
+            Some(mut replicated) if thresholds.exceeded(&replicated.0, transform) => {
+                replicated.0 = *transform;
+            }
+            Some(_) => {}
+            None => {
+                commands.entity(entity).insert(NetworkTransform(*transform));
+            }
+        }
+    }
+}
+
+/// Writes a received [`NetworkTransform`] as the [`Transform`] of the entity.
+fn write_transform(
+    ctx: &mut WriteCtx,
+    rule_fns: &RuleFns<NetworkTransform>,
+    entity: &mut DeferredEntity,
+    message: &mut Bytes,
+) -> Result<()> {
+    let NetworkTransform(transform) = rule_fns.deserialize(ctx, message)?;
+    entity.insert(transform);
+    Ok(())
+}
+
+fn remove_transform(_ctx: &mut RemoveCtx, entity: &mut DeferredEntity) {
+    entity.remove::<Transform>();
+}
+
+/// Marks an entity whose [`Velocity`] is replicated to clients, such as characters for
+/// their animations, and doodads and platforms for their prediction. Removing it stops
+/// replicating the velocity of the entity, leaving clients with the last one received.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ReplicateVelocity;
+
+/// A [`Velocity`] as replicated, with 16 bits per axis instead of 32, which is plenty for
+/// animations and predictions.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantizedVelocity {
+    linvel: [i16; 3],
+    angvel: [i16; 3],
+}
+
+fn quantize(value: Vec3, max: f32) -> [i16; 3] {
+    value
+        .to_array()
+        .map(|axis| ((axis / max).clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16)
+}
+
+fn dequantize(value: [i16; 3], max: f32) -> Vec3 {
+    Vec3::from_array(value.map(|axis| axis as f32 / i16::MAX as f32 * max))
+}
+
+impl From<Velocity> for QuantizedVelocity {
+    fn from(velocity: Velocity) -> Self {
+        Self {
+            linvel: quantize(velocity.linvel, MAX_LINEAR_SPEED),
+            angvel: quantize(velocity.angvel, MAX_ANGULAR_SPEED),
+        }
+    }
+}
+
+impl From<QuantizedVelocity> for Velocity {
+    fn from(velocity: QuantizedVelocity) -> Self {
+        Self {
+            linvel: dequantize(velocity.linvel, MAX_LINEAR_SPEED),
+            angvel: dequantize(velocity.angvel, MAX_ANGULAR_SPEED),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use merlo_model::Doodad;
+
+use crate::{
+    controller::has_server_authority, navigation::NavMesh, network::Cli, spawn::Archetype,
+};
+
+pub struct ScatterPlugin;
+
+impl Plugin for ScatterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, scatter_doodads.run_if(has_server_authority));
+    }
+}
+
+/// Doodads are scattered within this distance from the origin of the level.
+const SCATTER_RADIUS: f32 = 20.0;
+/// Height from where the ground is probed.
+const PROBE_HEIGHT: f32 = 100.0;
+/// Steepest ground a doodad is placed on, as the minimum vertical component of its normal.
+const MIN_GROUND_NORMAL_Y: f32 = 0.7;
+/// Radius of the space a doodad needs free around it.
+const DOODAD_RADIUS: f32 = 0.5;
+/// Attempts at placing each doodad before giving up on it.
+const ATTEMPTS: usize = 10;
+const VARIANTS: [Doodad; 2] = [Doodad::Cube, Doodad::Sphere];
+
+/// SplitMix64, a small generator giving the same numbers for a seed on every platform.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `[0, 1)`.
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+/// Places the doodads requested on the command line on free and walkable ground, once the
+/// navigation grid tells that the ground of the level is in the physics world.
+fn scatter_doodads(
+    mut commands: Commands,
+    cli: Res<Cli>,
+    nav_mesh: Res<NavMesh>,
+    rapier_context: ReadRapierContext,
+    mut done: Local<bool>,
+) {
+    if *done {
+        return;
+    }
+    let Some((count, seed)) = cli.scatter() else {
+        *done = true;
+        return;
+    };
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    if !nav_mesh.is_baked() {
+        return;
+    }
+    *done = true;
+
+    let mut rng = Rng(seed);
+    let filter = QueryFilter::default().exclude_sensors();
+    let ball = Collider::ball(DOODAD_RADIUS);
+    // Doodads placed now are not in the physics world yet, so they are checked apart.
+    let mut placed: Vec<Vec3> = Vec::with_capacity(count);
+    for _ in 0..count {
+        for _ in 0..ATTEMPTS {
+            // Uniform over the disc.
+            let distance = SCATTER_RADIUS * rng.next_f32().sqrt();
+            let angle = TAU * rng.next_f32();
+            let variant = VARIANTS[rng.next_u64() as usize % VARIANTS.len()];
+            let yaw = TAU * rng.next_f32();
+
+            let origin =
+                Vec3::new(angle.cos(), 0.0, angle.sin()) * distance + Vec3::Y * PROBE_HEIGHT;
+            let Some((_, hit)) = rapier_context.cast_ray_and_get_normal(
+                origin,
+                Vec3::NEG_Y,
+                2.0 * PROBE_HEIGHT,
+                true,
+                filter,
+            ) else {
+                continue;
+            };
+            if hit.normal.y < MIN_GROUND_NORMAL_Y {
+                continue;
+            }
+            let center = hit.point + Vec3::Y * (DOODAD_RADIUS + 0.01);
+            let mut blocked = placed
+                .iter()
+                .any(|other| other.distance(center) < 2.0 * DOODAD_RADIUS);
+            if !blocked {
+                rapier_context.intersect_shape(center, Quat::IDENTITY, &*ball.raw, filter, |_| {
+                    blocked = true;
+                    false
+                });
+            }
+            if blocked {
+                continue;
+            }
+
+            placed.push(center);
+            let transform =
+                Transform::from_translation(center).with_rotation(Quat::from_rotation_y(yaw));
+            Archetype::Doodad(variant).spawn(&mut commands, transform);
+            break;
+        }
+    }
+    info!(
+        "Scattered {} of {count} doodads with seed {seed}",
+        placed.len()
+    );
+}
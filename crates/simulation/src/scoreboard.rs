@@ -0,0 +1,116 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::{ScoreEntry, Team};
+use serde::{Deserialize, Serialize};
+
+use crate::{controller::has_server_authority, network::Cli, traffic::TrafficAppExt};
+
+pub struct ScoreboardPlugin;
+
+impl Plugin for ScoreboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_client_message::<PlayerJoin>(Channel::Ordered)
+            .replicate_tracked::<ScoreEntry>()
+            .add_systems(OnEnter(ClientState::Connected), send_join)
+            .add_systems(
+                Update,
+                (join_players, update_pings).run_if(has_server_authority),
+            )
+            .add_observer(remove_disconnected);
+    }
+}
+
+/// A [`Message`] sent by a client once connected, to be listed on the scoreboard.
+#[derive(Message, Serialize, Deserialize, Debug, Clone)]
+pub struct PlayerJoin {
+    pub name: String,
+}
+
+impl PlayerJoin {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+/// The client owning a [`ScoreEntry`], only known by the server.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreOwner(pub ClientId);
+
+fn send_join(cli: Res<Cli>, mut join_writer: MessageWriter<PlayerJoin>) {
+    join_writer.write(PlayerJoin::new(cli.name()));
+}
+
+/// Adds a scoreboard entry for every joining client, balancing teams.
+fn join_players(
+    mut commands: Commands,
+    mut join_reader: MessageReader<FromClient<PlayerJoin>>,
+    mut entries: Query<(&ScoreOwner, &mut ScoreEntry)>,
+) {
+    for event in join_reader.read() {
+        let name = event.message.name.clone();
+        if let Some((_, mut entry)) = entries
+            .iter_mut()
+            .find(|(owner, _)| owner.0 == event.client_id)
+        {
+            entry.name = name;
+            continue;
+        }
+
+        let red = entries
+            .iter()
+            .filter(|(_, entry)| entry.team == Team::Red)
+            .count();
+        let blue = entries
+            .iter()
+            .filter(|(_, entry)| entry.team == Team::Blue)
+            .count();
+        let team = if red <= blue { Team::Red } else { Team::Blue };
+
+        info!("{name} joined team {team:?}");
+        commands.spawn((
+            Replicated,
+            ScoreOwner(event.client_id),
+            ScoreEntry {
+                name,
+                ping_ms: 0,
+                score: 0,
+                team,
+            },
+        ));
+    }
+}
+
+fn update_pings(
+    clients: Query<&ClientStats, With<ConnectedClient>>,
+    mut entries: Query<(&ScoreOwner, &mut ScoreEntry)>,
+) {
+    for (owner, mut entry) in &mut entries {
+        let ClientId::Client(client) = owner.0 else {
+            continue;
+        };
+        let Ok(stats) = clients.get(client) else {
+            continue;
+        };
+        let ping_ms = (stats.rtt * 1000.0) as u32;
+        // Avoid replicating the entry when the ping did not change.
+        if entry.ping_ms != ping_ms {
+            entry.ping_ms = ping_ms;
+        }
+    }
+}
+
+fn remove_disconnected(
+    remove: On<Remove, ConnectedClient>,
+    mut commands: Commands,
+    entries: Query<(Entity, &ScoreOwner)>,
+) {
+    for (entity, owner) in &entries {
+        if owner.0 == ClientId::Client(remove.entity) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use bevy_replicon::prelude::*;
+use merlo_model::{Doodad, TriggerZone};
+use rhai::{AST, CallFnOptions, Dynamic, Engine, FuncArgs, Scope};
+
+use crate::{
+    admin::ControlledCharacter,
+    carry::InteractInput,
+    chat::ChatMessage,
+    controller::has_server_authority,
+    network::Cli,
+    scoreboard::PlayerJoin,
+    spawn::Archetype,
+    trigger_zone::{ZoneEntered, ZoneExited},
+};
+
+/// Runs Rhai scripts given with `--script` on the server, letting its owner customize the game.
+///
+/// A script may define any of these functions, called when the matching event happens:
+///
+/// - `on_player_joined(name)`
+/// - `on_zone_entered(zone, character)` and `on_zone_exited(zone, character)`
+/// - `on_interact(character)`
+///
+/// Zones are given by name and characters by id. In return, scripts may call:
+///
+/// - `spawn_doodad(kind, x, y, z)` with kind `"cube"` or `"sphere"`
+/// - `teleport(character, x, y, z)`
+/// - `send_chat(text)`
+///
+/// Variables defined at the top level of a script keep their value between calls.
+pub struct ScriptingPlugin;
+
+impl Plugin for ScriptingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_scripts).add_systems(
+            Update,
+            (call_hooks, apply_script_commands)
+                .chain()
+                .run_if(has_server_authority.and(resource_exists::<Scripts>)),
+        );
+    }
+}
+
+/// Sender of the chat lines of scripts.
+const SCRIPT_SENDER: &str = "Server";
+
+/// What a script asked the server to do, applied once the hooks of the frame returned.
+enum ScriptCommand {
+    SpawnDoodad(Doodad, Vec3),
+    Teleport(Entity, Vec3),
+    SendChat(String),
+}
+
+type CommandQueue = Arc<Mutex<Vec<ScriptCommand>>>;
+
+struct Script {
+    path: PathBuf,
+    ast: AST,
+    /// Top level variables of the script.
+    scope: Scope<'static>,
+}
+
+impl Script {
+    /// Calls the function `name` of the script, if it defines one.
+    fn call(&mut self, engine: &Engine, name: &str, args: impl FuncArgs) {
+        if !self
+            .ast
+            .iter_functions()
+            .any(|function| function.name == name)
+        {
+            return;
+        }
+        // The top level already ran once when loading.
+        let options = CallFnOptions::new().eval_ast(false);
+        if let Err(err) =
+            engine.call_fn_with_options::<Dynamic>(options, &mut self.scope, &self.ast, name, args)
+        {
+            error!("Script {} failed in `{name}`: {err}", self.path.display());
+        }
+    }
+}
+
+#[derive(Resource)]
+struct Scripts {
+    engine: Engine,
+    scripts: Vec<Script>,
+    commands: CommandQueue,
+}
+
+impl Scripts {
+    fn call(&mut self, name: &str, args: impl FuncArgs + Clone) {
+        for script in &mut self.scripts {
+            script.call(&self.engine, name, args.clone());
+        }
+    }
+}
+
+/// Accepts both integers and floats where a script passes a coordinate.
+fn coordinate(value: Dynamic) -> f32 {
+    value
+        .as_float()
+        .or_else(|_| value.as_int().map(|int| int as f64))
+        .unwrap_or_default() as f32
+}
+
+fn vector(x: Dynamic, y: Dynamic, z: Dynamic) -> Vec3 {
+    Vec3::new(coordinate(x), coordinate(y), coordinate(z))
+}
+
+/// Ids of characters as seen by scripts.
+fn character_id(character: Entity) -> i64 {
+    character.to_bits() as i64
+}
+
+/// An engine whose commands end up in `queue`.
+fn engine(queue: &CommandQueue) -> Engine {
+    let mut engine = Engine::new();
+    engine.on_print(|text| info!("{text}"));
+    engine.on_debug(|text, _, _| debug!("{text}"));
+
+    let commands = queue.clone();
+    engine.register_fn(
+        "spawn_doodad",
+        move |kind: &str, x: Dynamic, y: Dynamic, z: Dynamic| {
+            let doodad = match kind {
+                "cube" => Doodad::Cube,
+                "sphere" => Doodad::Sphere,
+                _ => {
+                    warn!("Scripts cannot spawn a doodad of kind `{kind}`");
+                    return;
+                }
+            };
+            let translation = vector(x, y, z);
+            if let Ok(mut commands) = commands.lock() {
+                commands.push(ScriptCommand::SpawnDoodad(doodad, translation));
+            }
+        },
+    );
+    let commands = queue.clone();
+    engine.register_fn(
+        "teleport",
+        move |character: i64, x: Dynamic, y: Dynamic, z: Dynamic| {
+            let Some(character) = Entity::try_from_bits(character as u64) else {
+                warn!("Scripts cannot teleport the invalid character {character}");
+                return;
+            };
+            let translation = vector(x, y, z);
+            if let Ok(mut commands) = commands.lock() {
+                commands.push(ScriptCommand::Teleport(character, translation));
+            }
+        },
+    );
+    let commands = queue.clone();
+    engine.register_fn("send_chat", move |text: &str| {
+        if let Ok(mut commands) = commands.lock() {
+            commands.push(ScriptCommand::SendChat(text.to_string()));
+        }
+    });
+    engine
+}
+
+/// Compiles the scripts and runs their top level, skipping the ones failing to.
+fn load_scripts(mut commands: Commands, cli: Res<Cli>) {
+    if cli.scripts().is_empty() {
+        return;
+    }
+    let queue = CommandQueue::default();
+    let engine = engine(&queue);
+    let mut scripts = Vec::new();
+    for path in cli.scripts() {
+        let ast = match engine.compile_file(path.clone()) {
+            Ok(ast) => ast,
+            Err(err) => {
+                error!("Cannot compile script {}: {err}", path.display());
+                continue;
+            }
+        };
+        let mut scope = Scope::new();
+        if let Err(err) = engine.run_ast_with_scope(&mut scope, &ast) {
+            error!("Cannot run script {}: {err}", path.display());
+            continue;
+        }
+        info!("Loaded script {}", path.display());
+        scripts.push(Script {
+            path: path.clone(),
+            ast,
+            scope,
+        });
+    }
+    commands.insert_resource(Scripts {
+        engine,
+        scripts,
+        commands: queue,
+    });
+}
+
+/// Calls the functions of the scripts for the events of the frame.
+fn call_hooks(
+    mut scripts: ResMut<Scripts>,
+    mut join_reader: MessageReader<FromClient<PlayerJoin>>,
+    mut entered_reader: MessageReader<ZoneEntered>,
+    mut exited_reader: MessageReader<ZoneExited>,
+    mut interact_reader: MessageReader<FromClient<InteractInput>>,
+    clients: Query<&ControlledCharacter>,
+    zones: Query<&TriggerZone>,
+) {
+    for event in join_reader.read() {
+        scripts.call("on_player_joined", (event.message.name.clone(),));
+    }
+    for event in entered_reader.read() {
+        if let Ok(zone) = zones.get(event.zone) {
+            let args = (zone.name.clone(), character_id(event.character));
+            scripts.call("on_zone_entered", args);
+        }
+    }
+    for event in exited_reader.read() {
+        // Nameless when the exit comes from despawning the zone.
+        let zone = zones
+            .get(event.zone)
+            .map_or_else(|_| String::new(), |zone| zone.name.clone());
+        scripts.call("on_zone_exited", (zone, character_id(event.character)));
+    }
+    for event in interact_reader.read() {
+        let character = event.message.character;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(character))
+        {
+            continue;
+        }
+        scripts.call("on_interact", (character_id(character),));
+    }
+}
+
+fn apply_script_commands(
+    mut commands: Commands,
+    scripts: Res<Scripts>,
+    mut transforms: Query<(&mut Transform, Option<&mut Velocity>)>,
+    mut chat_writer: MessageWriter<ToClients<ChatMessage>>,
+) {
+    let Ok(mut queue) = scripts.commands.lock() else {
+        return;
+    };
+    for command in queue.drain(..) {
+        match command {
+            ScriptCommand::SpawnDoodad(doodad, translation) => {
+                Archetype::Doodad(doodad)
+                    .spawn(&mut commands, Transform::from_translation(translation));
+            }
+            ScriptCommand::Teleport(character, translation) => {
+                let Ok((mut transform, velocity)) = transforms.get_mut(character) else {
+                    warn!("Scripts cannot teleport the missing character {character}");
+                    continue;
+                };
+                transform.translation = translation;
+                if let Some(mut velocity) = velocity {
+                    *velocity = Velocity::zero();
+                }
+            }
+            ScriptCommand::SendChat(text) => {
+                chat_writer.write(ToClients {
+                    mode: SendMode::Broadcast,
+                    message: ChatMessage::now(SCRIPT_SENDER, &text),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_rapier3d::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::{DamageKind, Dead, Health};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin::ControlledCharacter,
+    controller::{CharacterController, has_server_authority},
+    damage::DamageDealt,
+    game_mode,
+};
+
+pub struct ShootingPlugin;
+
+impl Plugin for ShootingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_client_message::<FireInput>(Channel::Ordered)
+            .add_mapped_server_message::<ShotFired>(Channel::Unordered)
+            .add_systems(Update, keyboard_input)
+            .add_systems(
+                Update,
+                (
+                    cool_down_weapons,
+                    fire_shots.run_if(not(game_mode::in_warmup)),
+                )
+                    .chain()
+                    .run_if(has_server_authority),
+            );
+    }
+}
+
+/// Hitscan weapon carried by every character.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeaponDefinition {
+    /// Seconds between two shots.
+    pub cooldown: f32,
+    pub range: f32,
+    pub damage: f32,
+    /// Offset of the muzzle from the origin of the character, in its local space.
+    pub muzzle: Vec3,
+}
+
+pub const RIFLE: WeaponDefinition = WeaponDefinition {
+    cooldown: 0.25,
+    range: 50.0,
+    damage: 10.0,
+    muzzle: Vec3::new(-0.3, 0.5, 0.5),
+};
+
+/// A [`Message`] written by a client to shoot with its character, forward.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct FireInput {
+    #[entities]
+    pub character: Entity,
+}
+
+/// A [`Message`] broadcast by the server for every shot, so that clients draw its tracer
+/// and its impact.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ShotFired {
+    #[entities]
+    pub shooter: Entity,
+    pub origin: Vec3,
+    /// Where the shot hit, or where it ended at the range of the weapon.
+    pub end: Vec3,
+    /// Normal of the surface hit, if any.
+    pub impact_normal: Option<Vec3>,
+}
+
+impl ShotFired {
+    /// Wraps the shot in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// Seconds left before a character can shoot again, on the server.
+#[derive(Component)]
+struct WeaponCooldown(f32);
+
+/// Sends [`FireInput`] for the controlled character.
+fn keyboard_input(
+    mut fire_writer: MessageWriter<FireInput>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controller: Query<&ChildOf, With<CharacterController>>,
+) {
+    let Ok(child) = controller.single() else {
+        return;
+    };
+    if keyboard_input.just_pressed(KeyCode::KeyG) {
+        fire_writer.write(FireInput {
+            character: child.parent(),
+        });
+    }
+}
+
+fn cool_down_weapons(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut weapons: Query<(Entity, &mut WeaponCooldown)>,
+) {
+    for (entity, mut cooldown) in &mut weapons {
+        cooldown.0 -= time.delta_secs();
+        if cooldown.0 <= 0.0 {
+            commands.entity(entity).remove::<WeaponCooldown>();
+        }
+    }
+}
+
+/// Casts a ray forward from the muzzle of characters shooting, ignoring the shooter, and
+/// damages what it hits first.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn fire_shots(
+    mut commands: Commands,
+    mut fire_reader: MessageReader<FromClient<FireInput>>,
+    mut shot_writer: MessageWriter<ToClients<ShotFired>>,
+    mut damage_writer: MessageWriter<ToClients<DamageDealt>>,
+    rapier_context: ReadRapierContext,
+    clients: Query<&ControlledCharacter>,
+    shooters: Query<&Transform, (With<Health>, Without<Dead>, Without<WeaponCooldown>)>,
+    mut targets: Query<&mut Health, Without<Dead>>,
+) {
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    let weapon = RIFLE;
+    for event in fire_reader.read() {
+        let shooter = event.message.character;
+        if let ClientId::Client(client) = event.client_id
+            && clients.get(client).ok() != Some(&ControlledCharacter(shooter))
+        {
+            warn!(
+                "Ignoring shot of {:?} for character {shooter} it does not control",
+                event.client_id
+            );
+            continue;
+        }
+        let Ok(transform) = shooters.get(shooter) else {
+            continue;
+        };
+        commands
+            .entity(shooter)
+            .insert(WeaponCooldown(weapon.cooldown));
+
+        let origin = transform.transform_point(weapon.muzzle);
+        // Characters face +Z.
+        let direction = transform.rotation * Vec3::Z;
+        let filter = QueryFilter::default()
+            .exclude_collider(shooter)
+            .exclude_sensors();
+        let hit =
+            rapier_context.cast_ray_and_get_normal(origin, direction, weapon.range, true, filter);
+        let (end, impact_normal) = match hit {
+            Some((_, intersection)) => (intersection.point, Some(intersection.normal)),
+            None => (origin + direction * weapon.range, None),
+        };
+
+        if let Some((target, _)) = hit
+            && let Ok(mut health) = targets.get_mut(target)
+        {
+            health.current = (health.current - weapon.damage).max(0.0);
+            damage_writer.write(
+                DamageDealt {
+                    target,
+                    source: Some(shooter),
+                    amount: weapon.damage,
+                    kind: DamageKind::Physical,
+                }
+                .broadcast(),
+            );
+        }
+        shot_writer.write(
+            ShotFired {
+                shooter,
+                origin,
+                end,
+                impact_normal,
+            }
+            .broadcast(),
+        );
+    }
+}
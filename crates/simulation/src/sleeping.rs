@@ -0,0 +1,73 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use merlo_model::{Carried, Doodad};
+
+use crate::controller::has_server_authority;
+
+/// Puts doodads at rest to sleep on the server, so that idle ones cost neither physics
+/// steps nor replication updates, as sleeping bodies keep their transform untouched.
+/// Rapier wakes them up again when something hits them.
+pub struct SleepingPlugin;
+
+impl Plugin for SleepingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(init_doodad_sleeping).add_systems(
+            Update,
+            put_idle_doodads_to_sleep.run_if(has_server_authority),
+        );
+    }
+}
+
+/// Meters per second below which a doodad counts as idle.
+const IDLE_SPEED: f32 = 0.05;
+/// Radians per second below which a doodad counts as idle.
+const IDLE_ANGULAR_SPEED: f32 = 0.05;
+/// Seconds a doodad stays idle before it is put to sleep.
+const IDLE_DELAY: f32 = 0.5;
+
+/// How long a doodad has been barely moving, and where it was last frame.
+#[derive(Component, Default)]
+struct Idle {
+    seconds: f32,
+    last: Transform,
+}
+
+fn init_doodad_sleeping(add: On<Add, Doodad>, mut commands: Commands) {
+    commands
+        .entity(add.entity)
+        .insert((Sleeping::default(), Idle::default()));
+}
+
+/// Puts doodads to sleep once they have been nearly still for a while, measuring how much
+/// they moved since the last frame, as doodads do not have a velocity of their own.
+#[allow(clippy::type_complexity)]
+fn put_idle_doodads_to_sleep(
+    time: Res<Time>,
+    mut doodads: Query<(&Transform, &mut Sleeping, &mut Idle), (With<Doodad>, Without<Carried>)>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+    for (transform, mut sleeping, mut idle) in &mut doodads {
+        let speed = transform.translation.distance(idle.last.translation) / delta;
+        let angular_speed = transform.rotation.angle_between(idle.last.rotation) / delta;
+        idle.last = *transform;
+        if sleeping.sleeping {
+            continue;
+        }
+        if speed > IDLE_SPEED || angular_speed > IDLE_ANGULAR_SPEED {
+            idle.seconds = 0.0;
+            continue;
+        }
+        idle.seconds += delta;
+        if idle.seconds >= IDLE_DELAY {
+            sleeping.sleeping = true;
+            idle.seconds = 0.0;
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::{Doodad, Health, Lamp, SpawnPoint};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DOODAD_HEALTH,
+    controller::has_server_authority,
+    edit::{Edit, EditHistories},
+};
+
+pub struct SpawnPlugin;
+
+impl Plugin for SpawnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_client_message::<SpawnRequest>(Channel::Ordered)
+            .add_systems(Update, spawn_requested.run_if(has_server_authority));
+    }
+}
+
+/// Entity archetypes that can be spawned from the editor palette.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Archetype {
+    Doodad(Doodad),
+    Lamp,
+    SpawnPoint,
+}
+
+impl Archetype {
+    pub const ALL: [Archetype; 4] = [
+        Archetype::Doodad(Doodad::Cube),
+        Archetype::Doodad(Doodad::Sphere),
+        Archetype::Lamp,
+        Archetype::SpawnPoint,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Archetype::Doodad(Doodad::Cube) => "Cube",
+            Archetype::Doodad(Doodad::Sphere) => "Sphere",
+            Archetype::Lamp => "Lamp",
+            Archetype::SpawnPoint => "Spawn Point",
+        }
+    }
+
+    /// Spawns a replicated entity of this archetype.
+    pub(crate) fn spawn(self, commands: &mut Commands, transform: Transform) -> Entity {
+        let mut entity = commands.spawn((Replicated, transform));
+        match self {
+            Archetype::Doodad(doodad) => entity.insert((doodad, Health::new(DOODAD_HEALTH))),
+            Archetype::Lamp => entity.insert(Lamp),
+            Archetype::SpawnPoint => entity.insert(SpawnPoint),
+        };
+        entity.id()
+    }
+
+    /// Height above the picked ground position where the entity is placed,
+    /// so that it does not spawn intersecting the ground.
+    fn ground_offset(self) -> f32 {
+        match self {
+            Archetype::Doodad(_) => 0.5,
+            Archetype::Lamp => 4.0,
+            Archetype::SpawnPoint => 0.0,
+        }
+    }
+}
+
+/// A [`Message`] asking the server to spawn a replicated entity.
+#[derive(Message, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct SpawnRequest {
+    pub archetype: Archetype,
+    pub ground: Vec3,
+}
+
+impl SpawnRequest {
+    pub fn new(archetype: Archetype, ground: Vec3) -> Self {
+        Self { archetype, ground }
+    }
+}
+
+/// Spawns replicated entities requested by the host, which can undo them as edits, like
+/// the other edits only the host may make.
+fn spawn_requested(
+    mut commands: Commands,
+    mut spawn_reader: MessageReader<FromClient<SpawnRequest>>,
+    mut histories: ResMut<EditHistories>,
+) {
+    for event in spawn_reader.read() {
+        if event.client_id != ClientId::Server {
+            warn!(
+                "Ignoring spawn request from non-host client {:?}",
+                event.client_id
+            );
+            continue;
+        }
+        let SpawnRequest { archetype, ground } = event.message;
+        let transform = Transform::from_translation(ground + Vec3::Y * archetype.ground_offset());
+        let entity = archetype.spawn(&mut commands, transform);
+        histories.record(event.client_id, Edit::Despawn(entity));
+        debug!("spawned {} at {ground}", archetype.name());
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "client-visuals")]
+use bevy::{
+    asset::RenderAssetUsages,
+    mesh::{Indices, PrimitiveTopology},
+};
+use bevy::{image::ImageLoaderSettings, prelude::*};
+use bevy_rapier3d::prelude::*;
+use merlo_model::StaticGeometry;
+
+pub struct TerrainPlugin;
+
+impl Plugin for TerrainPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, build_terrains);
+    }
+}
+
+/// Heightmap of a terrain still loading, replaced by its collider and mesh once loaded.
+#[derive(Component)]
+pub(crate) struct Heightmap(Handle<Image>);
+
+/// Loads a heightmap as linear values, so that gray levels map to heights evenly.
+pub(crate) fn load_heightmap(asset_server: &AssetServer, path: &str) -> Heightmap {
+    Heightmap(
+        asset_server.load_with_settings(path.to_owned(), |settings: &mut ImageLoaderSettings| {
+            settings.is_srgb = false
+        }),
+    )
+}
+
+/// Samples of a heightmap, on a grid of `columns` along X and `rows` along Z.
+struct Heights {
+    columns: usize,
+    rows: usize,
+    /// Heights from 0 to 1, row by row.
+    values: Vec<f32>,
+}
+
+impl Heights {
+    fn from_image(image: &Image) -> Option<Self> {
+        let (columns, rows) = (image.width() as usize, image.height() as usize);
+        // Heightfields need at least one cell.
+        if columns < 2 || rows < 2 {
+            return None;
+        }
+        let values = (0..rows)
+            .flat_map(|z| (0..columns).map(move |x| (x, z)))
+            .map(|(x, z)| {
+                let color = image.get_color_at(x as u32, z as u32).ok()?;
+                Some(color.to_linear().red)
+            })
+            .collect::<Option<_>>()?;
+        Some(Self {
+            columns,
+            rows,
+            values,
+        })
+    }
+
+    fn get(&self, column: usize, row: usize) -> f32 {
+        self.values[row * self.columns + column]
+    }
+
+    /// A heightfield spanning `size`, whose rows are along Z and columns along X like the
+    /// image, with the heights in column-major order.
+    fn collider(&self, size: Vec3) -> Collider {
+        let heights = (0..self.columns)
+            .flat_map(|column| (0..self.rows).map(move |row| self.get(column, row)))
+            .collect();
+        Collider::heightfield(heights, self.rows, self.columns, size)
+    }
+
+    /// The surface of the heightfield, with one vertex per sample.
+    #[cfg(feature = "client-visuals")]
+    fn mesh(&self, size: Vec3) -> Mesh {
+        let last = Vec2::new((self.columns - 1) as f32, (self.rows - 1) as f32);
+        let mut positions = Vec::with_capacity(self.values.len());
+        let mut uvs = Vec::with_capacity(self.values.len());
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let uv = Vec2::new(column as f32, row as f32) / last;
+                let height = self.get(column, row);
+                positions.push([
+                    (uv.x - 0.5) * size.x,
+                    height * size.y,
+                    (uv.y - 0.5) * size.z,
+                ]);
+                uvs.push(uv.to_array());
+            }
+        }
+
+        let index = |column: usize, row: usize| (row * self.columns + column) as u32;
+        let mut indices = Vec::with_capacity((self.columns - 1) * (self.rows - 1) * 6);
+        for row in 0..self.rows - 1 {
+            for column in 0..self.columns - 1 {
+                let (a, b) = (index(column, row), index(column + 1, row));
+                let (c, d) = (index(column, row + 1), index(column + 1, row + 1));
+                // Counterclockwise seen from above, split along the same diagonal as the
+                // cells of the heightfield.
+                indices.extend([a, c, b, b, c, d]);
+            }
+        }
+
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(indices))
+        .with_computed_smooth_normals()
+    }
+}
+
+/// Builds the collider and, with the `client-visuals` feature, the mesh of terrains whose
+/// heightmap finished loading.
+fn build_terrains(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    images: Res<Assets<Image>>,
+    #[cfg(feature = "client-visuals")] mut meshes: ResMut<Assets<Mesh>>,
+    #[cfg(feature = "client-visuals")] mut materials: ResMut<Assets<StandardMaterial>>,
+    terrains: Query<(Entity, &Heightmap, &StaticGeometry)>,
+) {
+    for (entity, heightmap, geometry) in &terrains {
+        let StaticGeometry::Heightfield {
+            heightmap: path,
+            size,
+        } = geometry
+        else {
+            continue;
+        };
+        if asset_server.load_state(&heightmap.0).is_failed() {
+            error!("Cannot load the heightmap {path}");
+            commands.entity(entity).remove::<Heightmap>();
+            continue;
+        }
+        let Some(image) = images.get(&heightmap.0) else {
+            continue;
+        };
+        commands.entity(entity).remove::<Heightmap>();
+        let Some(heights) = Heights::from_image(image) else {
+            error!(
+                "Cannot read the heightmap {path} of format {:?}",
+                image.texture_descriptor.format
+            );
+            continue;
+        };
+        commands.entity(entity).insert(heights.collider(*size));
+        #[cfg(feature = "client-visuals")]
+        commands.entity(entity).insert((
+            Mesh3d(meshes.add(heights.mesh(*size))),
+            MeshMaterial3d(materials.add(Color::WHITE)),
+        ));
+        debug!(
+            "Built terrain {path} of {}x{} samples",
+            heights.columns, heights.rows
+        );
+    }
+}
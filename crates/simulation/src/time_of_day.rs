@@ -0,0 +1,22 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use merlo_model::TimeOfDay;
+
+use crate::controller::has_server_authority;
+
+pub struct TimeOfDayPlugin;
+
+impl Plugin for TimeOfDayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, advance_time_of_day.run_if(has_server_authority));
+    }
+}
+
+fn advance_time_of_day(time: Res<Time>, mut times_of_day: Query<&mut TimeOfDay>) {
+    for mut time_of_day in &mut times_of_day {
+        time_of_day.advance(time.delta_secs());
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    any::TypeId,
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::{prelude::*, utils::prelude::ShortName};
+use bevy_replicon::{
+    bytes::Bytes,
+    prelude::*,
+    shared::replication::{
+        registry::{
+            command_fns::MutWrite,
+            ctx::{SerializeCtx, WriteCtx},
+            rule_fns::{self, RuleFns},
+        },
+        rules::filter::FilterRules,
+    },
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+pub struct TrafficPlugin;
+
+impl Plugin for TrafficPlugin {
+    fn build(&self, app: &mut App) {
+        let traffic = ReplicationTraffic::default();
+        // Rule functions are plain functions, so they reach the counts of this app through
+        // its type registry.
+        {
+            let mut registry = app.world().resource::<AppTypeRegistry>().write();
+            registry.register::<TrafficCounts>();
+            if let Some(registration) = registry.get_mut(TypeId::of::<TrafficCounts>()) {
+                registration.insert(traffic.counts.clone());
+            }
+        }
+        app.insert_resource(traffic)
+            .add_systems(Last, roll_traffic_window);
+    }
+}
+
+/// Replication updates of a component type, where bytes are the size of its serialized value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentTraffic {
+    pub sent_updates: u32,
+    pub sent_bytes: usize,
+    pub received_updates: u32,
+    pub received_bytes: usize,
+}
+
+/// Replication traffic per component type, measured over the last second.
+///
+/// The server counts the components replicon serializes for the ticks it sends, once for all
+/// the clients they go to, and a client counts the ones it deserializes from the server.
+/// Neither includes the entities, ticks, and sizes replicon sends along, which are part of
+/// the bytes per second of the connections instead.
+#[derive(Resource)]
+pub struct ReplicationTraffic {
+    counts: TrafficCounts,
+    last_second: BTreeMap<String, ComponentTraffic>,
+    /// Bytes sent to all clients, and received from the server, over the last second.
+    connection_bytes: (f64, f64),
+    timer: Timer,
+}
+
+impl Default for ReplicationTraffic {
+    fn default() -> Self {
+        Self {
+            counts: default(),
+            last_second: default(),
+            connection_bytes: default(),
+            timer: Timer::new(Duration::from_secs(1), TimerMode::Repeating),
+        }
+    }
+}
+
+impl ReplicationTraffic {
+    /// Traffic of the last full second, sorted by component name.
+    pub fn last_second(&self) -> impl Iterator<Item = (&str, &ComponentTraffic)> {
+        self.last_second
+            .iter()
+            .map(|(name, traffic)| (name.as_str(), traffic))
+    }
+
+    /// Bytes sent to all the clients, and received from the server, over the last second by
+    /// the connections, with everything replicon and the transport send along.
+    pub fn connection_bytes(&self) -> (f64, f64) {
+        self.connection_bytes
+    }
+}
+
+/// Traffic counted by the rule functions since the last second rolled over.
+#[derive(Reflect, Clone, Default)]
+#[reflect(opaque)]
+struct TrafficCounts(Arc<Mutex<BTreeMap<String, ComponentTraffic>>>);
+
+impl TrafficCounts {
+    /// Adds to the traffic of `C` in the app of `registry`, if it measures traffic.
+    fn count<C>(registry: &AppTypeRegistry, add: impl FnOnce(&mut ComponentTraffic)) {
+        let registry = registry.read();
+        let Some(counts) = registry.get_type_data::<TrafficCounts>(TypeId::of::<TrafficCounts>())
+        else {
+            return;
+        };
+        let Ok(mut counts) = counts.0.lock() else {
+            return;
+        };
+        add(counts.entry(ShortName::of::<C>().to_string()).or_default());
+    }
+}
+
+pub trait TrafficAppExt {
+    /// Replicates `C` like [`AppRuleExt::replicate`], measuring its replication traffic.
+    fn replicate_tracked<C>(&mut self) -> &mut Self
+    where
+        C: Component<Mutability: MutWrite<C>> + Serialize + DeserializeOwned;
+
+    /// Replicates `C` as `T` like [`AppRuleExt::replicate_filtered_as`], measuring its
+    /// replication traffic.
+    fn replicate_tracked_filtered_as<C, T, F: FilterRules>(&mut self) -> &mut Self
+    where
+        C: Component<Mutability: MutWrite<C>> + Clone + Into<T> + From<T>,
+        T: Serialize + DeserializeOwned;
+}
+
+impl TrafficAppExt for App {
+    fn replicate_tracked<C>(&mut self) -> &mut Self
+    where
+        C: Component<Mutability: MutWrite<C>> + Serialize + DeserializeOwned,
+    {
+        self.replicate_with(RuleFns::new(serialize::<C>, deserialize::<C>))
+    }
+
+    fn replicate_tracked_filtered_as<C, T, F: FilterRules>(&mut self) -> &mut Self
+    where
+        C: Component<Mutability: MutWrite<C>> + Clone + Into<T> + From<T>,
+        T: Serialize + DeserializeOwned,
+    {
+        self.replicate_with_filtered::<_, F>(RuleFns::new(
+            serialize_as::<C, T>,
+            deserialize_as::<C, T>,
+        ))
+    }
+}
+
+fn count_sent<C>(ctx: &SerializeCtx, bytes: usize) {
+    TrafficCounts::count::<C>(ctx.type_registry, |traffic| {
+        traffic.sent_updates += 1;
+        traffic.sent_bytes += bytes;
+    });
+}
+
+fn count_received<C>(ctx: &WriteCtx, bytes: usize) {
+    TrafficCounts::count::<C>(ctx.type_registry, |traffic| {
+        traffic.received_updates += 1;
+        traffic.received_bytes += bytes;
+    });
+}
+
+fn serialize<C: Component + Serialize>(
+    ctx: &SerializeCtx,
+    component: &C,
+    message: &mut Vec<u8>,
+) -> Result<()> {
+    let start = message.len();
+    rule_fns::default_serialize(ctx, component, message)?;
+    count_sent::<C>(ctx, message.len() - start);
+    Ok(())
+}
+
+fn deserialize<C: Component + DeserializeOwned>(
+    ctx: &mut WriteCtx,
+    message: &mut Bytes,
+) -> Result<C> {
+    let len = message.len();
+    let component = rule_fns::default_deserialize(ctx, message)?;
+    count_received::<C>(ctx, len - message.len());
+    Ok(component)
+}
+
+fn serialize_as<C: Component + Clone + Into<T>, T: Serialize>(
+    ctx: &SerializeCtx,
+    component: &C,
+    message: &mut Vec<u8>,
+) -> Result<()> {
+    let start = message.len();
+    rule_fns::serialize_as::<C, T>(ctx, component, message)?;
+    count_sent::<C>(ctx, message.len() - start);
+    Ok(())
+}
+
+fn deserialize_as<C: Component + From<T>, T: DeserializeOwned>(
+    ctx: &mut WriteCtx,
+    message: &mut Bytes,
+) -> Result<C> {
+    let len = message.len();
+    let component = rule_fns::deserialize_as::<C, T>(ctx, message)?;
+    count_received::<C>(ctx, len - message.len());
+    Ok(component)
+}
+
+fn roll_traffic_window(
+    time: Res<Time>,
+    mut traffic: ResMut<ReplicationTraffic>,
+    clients: Query<&ClientStats>,
+    connection: Option<Res<ClientStats>>,
+    client_state: Res<State<ClientState>>,
+) {
+    if !traffic.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let counts = traffic
+        .counts
+        .0
+        .lock()
+        .map(|mut counts| std::mem::take(&mut *counts))
+        .unwrap_or_default();
+    traffic.last_second = counts;
+    let sent = clients
+        .iter()
+        .fold(0.0, |sent, stats| sent + stats.sent_bps);
+    let received = connection
+        .filter(|_| *client_state == ClientState::Connected)
+        .map_or(0.0, |stats| stats.received_bps);
+    traffic.connection_bytes = (sent, received);
+}
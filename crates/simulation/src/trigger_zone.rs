@@ -0,0 +1,117 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{ecs::entity::MapEntities, prelude::*};
+use bevy_rapier3d::prelude::*;
+use bevy_replicon::prelude::*;
+use merlo_model::{Player, TriggerZone};
+use serde::{Deserialize, Serialize};
+
+use crate::controller::has_server_authority;
+
+pub struct TriggerZonePlugin;
+
+impl Plugin for TriggerZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_mapped_server_message::<ZoneEntered>(Channel::Ordered)
+            .add_mapped_server_message::<ZoneExited>(Channel::Ordered)
+            .add_observer(init_trigger_zone)
+            .add_systems(Update, detect_zone_events.run_if(has_server_authority));
+    }
+}
+
+/// A [`Message`] broadcast by the server when the character of a player enters a zone.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ZoneEntered {
+    #[entities]
+    pub zone: Entity,
+    #[entities]
+    pub character: Entity,
+}
+
+impl ZoneEntered {
+    /// Wraps the entrance in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// A [`Message`] broadcast by the server when the character of a player leaves a zone,
+/// including when either of them is despawned.
+#[derive(Message, MapEntities, Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ZoneExited {
+    #[entities]
+    pub zone: Entity,
+    #[entities]
+    pub character: Entity,
+}
+
+impl ZoneExited {
+    /// Wraps the exit in a message to be sent to every client.
+    pub fn broadcast(self) -> ToClients<Self> {
+        ToClients {
+            mode: SendMode::Broadcast,
+            message: self,
+        }
+    }
+}
+
+/// Backs zones with a sensor, which reports what overlaps it without blocking anything.
+fn init_trigger_zone(
+    add: On<Add, TriggerZone>,
+    mut commands: Commands,
+    zones: Query<&TriggerZone>,
+) {
+    let Ok(zone) = zones.get(add.entity) else {
+        return;
+    };
+    let half_size = zone.size / 2.0;
+    commands.entity(add.entity).insert((
+        Collider::cuboid(half_size.x, half_size.y, half_size.z),
+        Sensor,
+        ActiveEvents::COLLISION_EVENTS,
+    ));
+}
+
+/// Turns the collisions of zones with the characters of players into zone messages.
+/// Occupied zones are remembered, since either entity may be gone when they separate.
+fn detect_zone_events(
+    mut collision_reader: MessageReader<CollisionEvent>,
+    mut entered_writer: MessageWriter<ToClients<ZoneEntered>>,
+    mut exited_writer: MessageWriter<ToClients<ZoneExited>>,
+    mut occupied: Local<Vec<(Entity, Entity)>>,
+    zones: Query<&TriggerZone>,
+    players: Query<(), With<Player>>,
+) {
+    for event in collision_reader.read() {
+        match *event {
+            CollisionEvent::Started(a, b, _) => {
+                let Some((zone, character)) = [(a, b), (b, a)]
+                    .into_iter()
+                    .find(|&(zone, character)| zones.contains(zone) && players.contains(character))
+                else {
+                    continue;
+                };
+                if let Ok(trigger_zone) = zones.get(zone) {
+                    debug!("{character} entered zone {}", trigger_zone.name);
+                }
+                occupied.push((zone, character));
+                entered_writer.write(ZoneEntered { zone, character }.broadcast());
+            }
+            CollisionEvent::Stopped(a, b, _) => {
+                let Some(index) = occupied
+                    .iter()
+                    .position(|&pair| pair == (a, b) || pair == (b, a))
+                else {
+                    continue;
+                };
+                let (zone, character) = occupied.swap_remove(index);
+                exited_writer.write(ZoneExited { zone, character }.broadcast());
+            }
+        }
+    }
+}
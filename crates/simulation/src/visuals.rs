@@ -0,0 +1,131 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use merlo_model::{CharacterModel, Doodad, Lamp, Player, SpawnPoint, StaticGeometry};
+
+use crate::{colors::ColorScheme, controller::CharacterDimensions, manifest::AssetManifest};
+
+/// Gives replicated entities their meshes, materials, models, and lights, which headless
+/// servers have no use for. Only built with the `client-visuals` feature.
+pub struct VisualsPlugin;
+
+impl Plugin for VisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DoodadMeshes>()
+            .add_observer(init_player_scene)
+            .add_observer(init_doodad_mesh)
+            .add_observer(init_lamp)
+            .add_observer(init_spawn_point_mesh)
+            .add_observer(init_static_geometry_mesh);
+    }
+}
+
+fn init_player_scene(
+    add: On<Add, Player>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    manifest: Res<AssetManifest>,
+    models: Query<&CharacterModel>,
+    dimensions: Query<&CharacterDimensions>,
+) {
+    let dimensions = dimensions.get(add.entity).copied().unwrap_or_default();
+    let model = manifest.character_model(models.get(add.entity).ok());
+    let scene: Handle<Scene> = asset_server.load(format!("{}#Scene0", model.scene_path()));
+    commands.entity(add.entity).with_children(|commands| {
+        commands.spawn((
+            SceneRoot(scene),
+            Transform::from_translation(dimensions.scene_offset()),
+        ));
+    });
+}
+
+/// Meshes and the material shared by every doodad of a kind, so that identical doodads
+/// are drawn together in a single instanced batch.
+#[derive(Resource)]
+struct DoodadMeshes {
+    meshes: HashMap<Doodad, Handle<Mesh>>,
+    material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for DoodadMeshes {
+    fn from_world(world: &mut World) -> Self {
+        let color = world
+            .get_resource::<ColorScheme>()
+            .copied()
+            .unwrap_or_default()
+            .doodad;
+        let manifest = world
+            .get_resource::<AssetManifest>()
+            .cloned()
+            .unwrap_or_default();
+        let mut assets = world.resource_mut::<Assets<Mesh>>();
+        let meshes = [Doodad::Cube, Doodad::Sphere]
+            .into_iter()
+            .map(|doodad| (doodad, assets.add(manifest.doodad(doodad).mesh())))
+            .collect();
+        let material = world.resource_mut::<Assets<StandardMaterial>>().add(color);
+        Self { meshes, material }
+    }
+}
+
+fn init_doodad_mesh(
+    add: On<Add, Doodad>,
+    mut commands: Commands,
+    doodads: Query<&Doodad>,
+    assets: Res<DoodadMeshes>,
+) {
+    let Ok(doodad) = doodads.get(add.entity) else {
+        return;
+    };
+    let Some(mesh) = assets.meshes.get(doodad) else {
+        return;
+    };
+    commands.entity(add.entity).insert((
+        Mesh3d(mesh.clone()),
+        MeshMaterial3d(assets.material.clone()),
+    ));
+}
+
+/// Scenes and heightfields are left to the simulation, as their colliders come from them.
+fn init_static_geometry_mesh(
+    add: On<Add, StaticGeometry>,
+    mut commands: Commands,
+    geometries: Query<&StaticGeometry>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok(geometry) = geometries.get(add.entity) else {
+        return;
+    };
+    let mesh = match geometry {
+        StaticGeometry::Cylinder { radius, height } => meshes.add(Cylinder::new(*radius, *height)),
+        StaticGeometry::Cuboid { size } => meshes.add(Cuboid::from_size(*size)),
+        StaticGeometry::Scene(_) | StaticGeometry::Heightfield { .. } => return,
+    };
+    commands
+        .entity(add.entity)
+        .insert((Mesh3d(mesh), MeshMaterial3d(materials.add(Color::WHITE))));
+}
+
+fn init_lamp(add: On<Add, Lamp>, mut commands: Commands) {
+    commands.entity(add.entity).insert(PointLight {
+        shadows_enabled: true,
+        ..default()
+    });
+}
+
+fn init_spawn_point_mesh(
+    add: On<Add, SpawnPoint>,
+    mut commands: Commands,
+    scheme: Res<ColorScheme>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // A thin disc marking the spawn location, without a collider.
+    commands.entity(add.entity).insert((
+        Mesh3d(meshes.add(Cylinder::new(0.5, 0.02))),
+        MeshMaterial3d(materials.add(scheme.spawn_point)),
+    ));
+}
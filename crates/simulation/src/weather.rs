@@ -0,0 +1,87 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Friction;
+use merlo_model::{StaticGeometry, Weather, WeatherKind};
+
+use crate::controller::has_server_authority;
+
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, advance_weather.run_if(has_server_authority))
+            .add_systems(Update, wet_ground);
+    }
+}
+
+/// Seconds each weather lasts at full intensity.
+const WEATHER_DURATION: f32 = 120.0;
+/// Seconds for a weather to come or go.
+const FADE_DURATION: f32 = 10.0;
+/// Weathers following each other, starting over after the last one.
+const CYCLE: [WeatherKind; 4] = [
+    WeatherKind::Clear,
+    WeatherKind::Rain,
+    WeatherKind::Clear,
+    WeatherKind::Fog,
+];
+/// Friction of the ground when dry, and when soaked by heavy rain.
+const DRY_FRICTION: f32 = 0.5;
+const WET_FRICTION: f32 = 0.1;
+
+/// Progress of the server through the [`CYCLE`] of weathers.
+#[derive(Default)]
+struct WeatherCycle {
+    /// Seconds the current weather has lasted at full intensity.
+    elapsed: f32,
+    /// Whether the current weather is going away.
+    fading: bool,
+}
+
+/// Fades weathers in, keeps them for a while, then fades them out for the next one.
+fn advance_weather(
+    time: Res<Time>,
+    mut cycle: Local<WeatherCycle>,
+    mut weathers: Query<&mut Weather>,
+) {
+    let dt = time.delta_secs();
+    for mut weather in &mut weathers {
+        if cycle.fading {
+            weather.intensity -= dt / FADE_DURATION;
+            if weather.intensity <= 0.0 {
+                let index = CYCLE.iter().position(|&kind| kind == weather.kind);
+                let next = index.map_or(0, |index| index + 1) % CYCLE.len();
+                *weather = Weather {
+                    kind: CYCLE[next],
+                    intensity: 0.0,
+                };
+                *cycle = WeatherCycle::default();
+                debug!("Weather changing to {:?}", weather.kind);
+            }
+        } else if weather.intensity < 1.0 {
+            weather.intensity = (weather.intensity + dt / FADE_DURATION).min(1.0);
+        } else {
+            cycle.elapsed += dt;
+            cycle.fading = cycle.elapsed >= WEATHER_DURATION;
+        }
+    }
+}
+
+/// Makes the ground slippery under the rain, so that doodads slide farther.
+fn wet_ground(
+    weathers: Query<&Weather, Changed<Weather>>,
+    mut commands: Commands,
+    geometries: Query<Entity, With<StaticGeometry>>,
+) {
+    let Some(weather) = weathers.iter().next() else {
+        return;
+    };
+    let rain = weather.intensity_of(WeatherKind::Rain);
+    let friction = Friction::coefficient(DRY_FRICTION.lerp(WET_FRICTION, rain));
+    for geometry in &geometries {
+        commands.entity(geometry).insert(friction);
+    }
+}
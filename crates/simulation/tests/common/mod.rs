@@ -0,0 +1,176 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use bevy::prelude::*;
+use bevy_replicon::{prelude::*, shared::server_entity_map::ServerEntityMap};
+use bevy_replicon_renet::renet::{RenetClient, RenetServer};
+use merlo_model::{Doodad, Health, Player, StaticGeometry};
+use merlo_simulation::{
+    DOODAD_HEALTH, PLAYER_HEALTH, SimulationPlugin, WorldSpawner,
+    controller::{CharacterController, CharacterDimensions, MovementAction},
+    network::{Cli, DEFAULT_PORT},
+    physics::PhysicsArgs,
+};
+
+/// Network id of the client connected to the server.
+const CLIENT_ID: u64 = 1;
+/// Frames given to the client to connect and receive the world.
+const CONNECT_FRAMES: usize = 120;
+/// Side of the ground of the test world.
+const GROUND_SIZE: f32 = 40.0;
+
+/// Spawns a flat ground with a player standing at the origin, and a doodad in front of it.
+pub fn flat_world(commands: &mut Commands, _cli: &Cli) {
+    commands.spawn((
+        Replicated,
+        Transform::from_xyz(0.0, -0.5, 0.0),
+        StaticGeometry::Cuboid {
+            size: Vec3::new(GROUND_SIZE, 1.0, GROUND_SIZE),
+        },
+    ));
+    commands.spawn((
+        Replicated,
+        Transform::from_xyz(0.0, CharacterDimensions::DEFAULT.origin_to_foot(), 0.0),
+        Player::default(),
+        Health::new(PLAYER_HEALTH),
+    ));
+    commands.spawn((
+        Replicated,
+        Transform::from_xyz(0.0, 0.5, 3.0),
+        Doodad::Cube,
+        Health::new(DOODAD_HEALTH),
+    ));
+}
+
+/// A server and a client app of the simulation in the same process, exchanging packets in
+/// memory, both stepping physics by a fixed timestep once per frame.
+pub struct Loopback {
+    pub server: App,
+    pub client: App,
+}
+
+impl Loopback {
+    /// Starts both apps with the world of `spawner`, and connects the client.
+    pub fn new(spawner: WorldSpawner) -> Self {
+        let simulation = SimulationPlugin::default()
+            .with_demo_scene(false)
+            .with_spawner(spawner);
+        let mut loopback = Self {
+            server: app(Cli::server(DEFAULT_PORT), simulation),
+            client: app(
+                Cli::client((Ipv4Addr::LOCALHOST, DEFAULT_PORT).into()),
+                simulation,
+            ),
+        };
+        loopback.server.update();
+        loopback.client.update();
+        loopback
+            .server
+            .world_mut()
+            .resource_mut::<RenetServer>()
+            .add_connection(CLIENT_ID);
+        loopback.run(CONNECT_FRAMES);
+        assert_eq!(
+            *loopback.client.world().resource::<State<ClientState>>(),
+            ClientState::Connected,
+            "client should be connected"
+        );
+        loopback
+    }
+
+    /// Updates the server, then the client, delivering the packets of each to the other.
+    pub fn step(&mut self) {
+        self.server.update();
+        self.exchange();
+        self.client.update();
+        self.exchange();
+    }
+
+    pub fn run(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// Steps until `condition` holds, for at most `frames` frames, returning whether it did.
+    pub fn run_until(
+        &mut self,
+        frames: usize,
+        mut condition: impl FnMut(&mut Self) -> bool,
+    ) -> bool {
+        for _ in 0..frames {
+            self.step();
+            if condition(self) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn exchange(&mut self) {
+        let mut client = self.client.world_mut().resource_mut::<RenetClient>();
+        let mut server = self.server.world_mut().resource_mut::<RenetServer>();
+        if let Err(err) = server.process_local_client(CLIENT_ID, &mut client) {
+            panic!("cannot exchange packets with the client: {err}");
+        }
+    }
+
+    /// The only entity of the server with `C`.
+    pub fn server_single<C: Component>(&mut self) -> Entity {
+        let world = self.server.world_mut();
+        let mut query = world.query_filtered::<Entity, With<C>>();
+        match query.single(world) {
+            Ok(entity) => entity,
+            Err(err) => panic!("server should have a single {}: {err}", type_name::<C>()),
+        }
+    }
+
+    /// The entity of the client replicating `server_entity`.
+    pub fn client_entity(&self, server_entity: Entity) -> Entity {
+        let entity_map = self.client.world().resource::<ServerEntityMap>();
+        match entity_map.to_client().get(&server_entity) {
+            Some(&entity) => entity,
+            None => panic!("client should replicate {server_entity}"),
+        }
+    }
+
+    /// Lets the client control its replica of the character `server_entity`, as the camera
+    /// of the presentation does.
+    pub fn control(&mut self, server_entity: Entity) -> Entity {
+        let character = self.client_entity(server_entity);
+        self.client
+            .world_mut()
+            .spawn((CharacterController, ChildOf(character)));
+        character
+    }
+
+    /// Writes a movement input on the client, which sends it to the server.
+    pub fn send(&mut self, action: MovementAction) {
+        self.client.world_mut().write_message(action);
+    }
+
+    pub fn server_get<C: Component + Clone>(&self, entity: Entity) -> C {
+        match self.server.world().get::<C>(entity) {
+            Some(component) => component.clone(),
+            None => panic!("server entity {entity} should have {}", type_name::<C>()),
+        }
+    }
+
+    pub fn client_get<C: Component + Clone>(&self, entity: Entity) -> C {
+        match self.client.world().get::<C>(entity) {
+            Some(component) => component.clone(),
+            None => panic!("client entity {entity} should have {}", type_name::<C>()),
+        }
+    }
+}
+
+fn type_name<T>() -> &'static str {
+    std::any::type_name::<T>()
+}
+
+fn app(cli: Cli, simulation: SimulationPlugin) -> App {
+    merlo_simulation::stepped_app(cli.loopback(), PhysicsArgs::default(), simulation)
+}
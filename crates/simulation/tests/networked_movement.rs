@@ -0,0 +1,129 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use merlo_model::{Doodad, Player};
+use merlo_simulation::{
+    controller::{CharacterDimensions, CharacterMovementState, MovementAction},
+    prediction::Predicted,
+};
+
+use common::Loopback;
+
+/// Meters the replicated transform on the client may be off from the one of the server.
+const TOLERANCE: f32 = 0.05;
+/// Frames to wait for something to happen before failing.
+const TIMEOUT: usize = 300;
+
+#[test]
+fn client_converges_to_server_movement() {
+    let mut loopback = Loopback::new(common::flat_world);
+    let player = loopback.server_single::<Player>();
+    let character = loopback.control(player);
+    let start = loopback.server_get::<Transform>(player).translation;
+
+    loopback.send(MovementAction::SetMove(character, Vec3::X));
+    loopback.run(60);
+    loopback.send(MovementAction::SetMove(character, Vec3::ZERO));
+    loopback.run(60);
+
+    let server = loopback.server_get::<Transform>(player).translation;
+    let client = loopback.client_get::<Transform>(character).translation;
+    assert!(
+        server.xz().distance(start.xz()) > 1.0,
+        "character should have moved from {start}, is at {server}"
+    );
+    assert!(
+        client.distance(server) < TOLERANCE,
+        "client should see the character at {server}, sees it at {client}"
+    );
+}
+
+#[test]
+fn characters_stand_grounded_on_the_floor() {
+    let mut loopback = Loopback::new(common::flat_world);
+    let player = loopback.server_single::<Player>();
+    let character = loopback.control(player);
+
+    let height = CharacterDimensions::DEFAULT.origin_to_foot();
+    let server = loopback.server_get::<Transform>(player).translation;
+    assert!(
+        (server.y - height).abs() < TOLERANCE,
+        "character should stand on the floor at {height}, is at {server}"
+    );
+    assert!(
+        loopback
+            .server_get::<CharacterMovementState>(player)
+            .grounded
+    );
+    assert!(
+        loopback
+            .client_get::<CharacterMovementState>(character)
+            .grounded,
+        "client should see the character grounded"
+    );
+}
+
+#[test]
+fn jumping_leaves_the_ground_and_lands() {
+    let mut loopback = Loopback::new(common::flat_world);
+    let player = loopback.server_single::<Player>();
+    let character = loopback.control(player);
+    let start = loopback.client_get::<Transform>(character).translation;
+
+    loopback.send(MovementAction::SetJump(character, true));
+    let airborne = loopback.run_until(TIMEOUT, |loopback| {
+        let state = loopback.client_get::<CharacterMovementState>(character);
+        let translation = loopback.client_get::<Transform>(character).translation;
+        !state.grounded && translation.y > start.y + 0.5
+    });
+    assert!(airborne, "client should see the character in the air");
+    loopback.send(MovementAction::SetJump(character, false));
+
+    let landed = loopback.run_until(TIMEOUT, |loopback| {
+        let state = loopback.client_get::<CharacterMovementState>(character);
+        let velocity = loopback.server_get::<Velocity>(player);
+        state.grounded && velocity.linvel.y.abs() < 0.01
+    });
+    assert!(landed, "client should see the character land again");
+    let end = loopback.client_get::<Transform>(character).translation;
+    assert!(
+        (end.y - start.y).abs() < TOLERANCE,
+        "character should land back at {}, is at {end}",
+        start.y
+    );
+}
+
+#[test]
+fn pushed_doodads_are_predicted_then_corrected() {
+    let mut loopback = Loopback::new(common::flat_world);
+    let player = loopback.server_single::<Player>();
+    let character = loopback.control(player);
+    let server_doodad = loopback.server_single::<Doodad>();
+    let doodad = loopback.client_entity(server_doodad);
+
+    loopback.send(MovementAction::SetMove(character, Vec3::Z));
+    let predicted = loopback.run_until(TIMEOUT, |loopback| {
+        loopback.client.world().get::<Predicted>(doodad).is_some()
+    });
+    assert!(predicted, "client should predict the doodad it pushes");
+    loopback.send(MovementAction::SetMove(character, Vec3::NEG_Z));
+
+    let released = loopback.run_until(TIMEOUT, |loopback| {
+        loopback.client.world().get::<Predicted>(doodad).is_none()
+    });
+    assert!(released, "client should hand the doodad back to the server");
+    loopback.send(MovementAction::SetMove(character, Vec3::ZERO));
+    loopback.run(60);
+
+    let server = loopback.server_get::<Transform>(server_doodad).translation;
+    let client = loopback.client_get::<Transform>(doodad).translation;
+    assert!(
+        client.distance(server) < TOLERANCE,
+        "client should see the doodad at {server}, sees it at {client}"
+    );
+}
@@ -0,0 +1,17 @@
+// Copyright © 2026
+// Author: Antonio Caggiano <info@antoniocaggiano.eu>
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use merlo_simulation::replay;
+
+/// A character walking forward on the default level, turning right, and jumping, blessed
+/// with `replay --bless`.
+const WALK: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/recordings/walk.ron");
+
+#[test]
+fn walk_matches_blessed_snapshot() {
+    let result = replay::run(Path::new(WALK), false);
+    assert!(result.is_ok(), "replay diverged: {result:?}");
+}